@@ -0,0 +1,217 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git am` command created by calling `Git::am()`. This
+/// applies a series of patches from mailboxes, making a commit for each one.
+/// If no mailboxes are given they are read from stdin.
+///
+/// `abort`, `continue_`, `quit`, and `skip` control an am session that is
+/// already in progress (e.g. one that stopped because a patch didn't apply)
+/// and are meant to be used on their own without `mboxes` or any of the other
+/// options.
+#[derive(Clone)]
+pub struct GitAmBuilder {
+  git: GitBuilder,
+  mboxes: Vec<PathBuf>,
+  signoff: bool,
+  utf8: Option<bool>,
+  keep: bool,
+  three_way: bool,
+  reject: bool,
+  patch_format: Option<PatchFormat>,
+  sequencer: Option<Sequencer>,
+}
+
+/// The subcommands for an am session that is in progress
+#[derive(Clone)]
+enum Sequencer {
+  Abort,
+  Continue,
+  Quit,
+  Skip,
+}
+
+impl GitAmBuilder {
+  /// Internal function used by `Git`. `Git::am()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitAmBuilder {
+      git,
+      mboxes: Vec::new(),
+      signoff: false,
+      utf8: None,
+      keep: false,
+      three_way: false,
+      reject: false,
+      patch_format: None,
+      sequencer: None,
+    }
+  }
+
+  /// The mailbox files or Maildir directories to read patches from, e.g. the
+  /// output of `git format-patch`. This can be called multiple times to add
+  /// more mailboxes.
+  pub fn mboxes(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.mboxes.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// Add a `Signed-off-by` trailer to the commit messages
+  pub fn signoff(mut self) -> Self {
+    self.signoff = true;
+    self
+  }
+
+  /// Re-encode the commit messages to UTF-8. This is the default. This and
+  /// `no_utf8` override each other, so whichever one is called last is used,
+  /// just like git does with the flags.
+  pub fn utf8(mut self) -> Self {
+    self.utf8 = Some(true);
+    self
+  }
+
+  /// Keep the commit messages in the encoding they were sent in. This and
+  /// `utf8` override each other, so whichever one is called last is used,
+  /// just like git does with the flags.
+  pub fn no_utf8(mut self) -> Self {
+    self.utf8 = Some(false);
+    self
+  }
+
+  /// Keep the subject line as is instead of stripping everything up to and
+  /// including a `[PATCH]` style prefix
+  pub fn keep(mut self) -> Self {
+    self.keep = true;
+    self
+  }
+
+  /// Fall back to a three way merge if a patch doesn't apply cleanly, using
+  /// the blobs the patch records if they are in the repository
+  pub fn three_way(mut self) -> Self {
+    self.three_way = true;
+    self
+  }
+
+  /// Apply the parts of a patch that apply cleanly and leave the rejected
+  /// hunks in `.rej` files instead of failing outright
+  pub fn reject(mut self) -> Self {
+    self.reject = true;
+    self
+  }
+
+  /// The format the patches are in. By default git tries to detect it.
+  pub fn patch_format(mut self, format: PatchFormat) -> Self {
+    self.patch_format = Some(format);
+    self
+  }
+
+  /// Cancel the session and restore the branch to where it was before it
+  /// started
+  pub fn abort(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Abort);
+    self
+  }
+
+  /// Continue the session after resolving a patch that didn't apply and
+  /// adding the changes to the index
+  pub fn continue_(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Continue);
+    self
+  }
+
+  /// Stop the session, keeping the branch and the working tree as they are
+  pub fn quit(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Quit);
+    self
+  }
+
+  /// Skip the current patch and continue with the rest of the mailbox
+  pub fn skip(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Skip);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("am");
+
+    if let Some(sequencer) = self.sequencer {
+      let arg = match sequencer {
+        Sequencer::Abort => "--abort",
+        Sequencer::Continue => "--continue",
+        Sequencer::Quit => "--quit",
+        Sequencer::Skip => "--skip",
+      };
+      args.arg(arg);
+    }
+    if self.signoff {
+      args.arg("--signoff");
+    }
+    match self.utf8 {
+      Some(true) => {
+        args.arg("--utf8");
+      }
+      Some(false) => {
+        args.arg("--no-utf8");
+      }
+      None => {}
+    }
+    if self.keep {
+      args.arg("--keep");
+    }
+    if self.three_way {
+      args.arg("--3way");
+    }
+    if self.reject {
+      args.arg("--reject");
+    }
+    if let Some(format) = self.patch_format {
+      let format = match format {
+        PatchFormat::Mbox => "mbox",
+        PatchFormat::MboxRd => "mboxrd",
+        PatchFormat::Stgit => "stgit",
+        PatchFormat::StgitSeries => "stgit-series",
+        PatchFormat::Hg => "hg",
+      };
+      args.arg(format!("--patch-format={}", format));
+    }
+    args.args(self.mboxes);
+    args
+  }
+}
+
+impl_builder!(GitAmBuilder);
+
+/// Options for the `patch_format` function
+#[derive(Clone)]
+pub enum PatchFormat {
+  /// A mailbox with one patch per email, like `git format-patch` writes
+  Mbox,
+  /// A mailbox where lines starting with `From ` in the messages are escaped
+  /// by adding more `>` characters, like `git format-patch --pretty=mboxrd`
+  /// writes
+  MboxRd,
+  /// A patch exported by StGit
+  Stgit,
+  /// A StGit series file listing the patches to apply
+  StgitSeries,
+  /// A patch exported by Mercurial
+  Hg,
+}
@@ -0,0 +1,208 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git apply` command created by calling `Git::apply()`.
+/// If no patches are given the patch is read from stdin.
+#[derive(Clone)]
+pub struct GitApplyBuilder {
+  git: GitBuilder,
+  patches: Vec<PathBuf>,
+  stat: bool,
+  numstat: bool,
+  check: bool,
+  index: bool,
+  cached: bool,
+  reject: bool,
+  reverse: bool,
+  whitespace: Option<WhitespaceMode>,
+  ignore_space_change: bool,
+  three_way: bool,
+  verbose: bool,
+}
+
+impl GitApplyBuilder {
+  /// Internal function used by `Git`. `Git::apply()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitApplyBuilder {
+      git,
+      patches: Vec::new(),
+      stat: false,
+      numstat: false,
+      check: false,
+      index: false,
+      cached: false,
+      reject: false,
+      reverse: false,
+      whitespace: None,
+      ignore_space_change: false,
+      three_way: false,
+      verbose: false,
+    }
+  }
+
+  /// The files to read the patches from. This can be called multiple times to
+  /// add more patches.
+  pub fn patches(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.patches.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// Instead of applying the patch, output a diffstat for the input
+  pub fn stat(mut self) -> Self {
+    self.stat = true;
+    self
+  }
+
+  /// Like `stat` but shows the number of added and deleted lines in decimal
+  /// notation and the pathname without abbreviation, to make it more machine
+  /// friendly
+  pub fn numstat(mut self) -> Self {
+    self.numstat = true;
+    self
+  }
+
+  /// Instead of applying the patch, see if the patch is applicable to the
+  /// current working tree and/or the index file and detect errors
+  pub fn check(mut self) -> Self {
+    self.check = true;
+    self
+  }
+
+  /// Apply the patch to both the index and the working tree
+  pub fn index(mut self) -> Self {
+    self.index = true;
+    self
+  }
+
+  /// Apply the patch to just the index, without touching the working tree
+  pub fn cached(mut self) -> Self {
+    self.cached = true;
+    self
+  }
+
+  /// Apply the parts of the patch that are applicable, and leave the rejected
+  /// hunks in corresponding `*.rej` files instead of failing atomically
+  pub fn reject(mut self) -> Self {
+    self.reject = true;
+    self
+  }
+
+  /// Apply the patch in reverse
+  pub fn reverse(mut self) -> Self {
+    self.reverse = true;
+    self
+  }
+
+  /// How to handle whitespace errors such as trailing whitespace in the
+  /// patch. See `WhitespaceMode` for the options.
+  pub fn whitespace(mut self, mode: WhitespaceMode) -> Self {
+    self.whitespace = Some(mode);
+    self
+  }
+
+  /// Ignore changes in amount of whitespace in context lines when applying
+  /// the patch
+  pub fn ignore_space_change(mut self) -> Self {
+    self.ignore_space_change = true;
+    self
+  }
+
+  /// When the patch does not apply cleanly, fall back on a 3-way merge if the
+  /// patch records the identity of blobs it is supposed to apply to and those
+  /// blobs are available locally. Implies `index`.
+  pub fn three_way(mut self) -> Self {
+    self.three_way = true;
+    self
+  }
+
+  /// Report progress to stderr
+  pub fn verbose(mut self) -> Self {
+    self.verbose = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("apply");
+
+    if self.stat {
+      args.arg("--stat");
+    }
+    if self.numstat {
+      args.arg("--numstat");
+    }
+    if self.check {
+      args.arg("--check");
+    }
+    if self.index {
+      args.arg("--index");
+    }
+    if self.cached {
+      args.arg("--cached");
+    }
+    if self.reject {
+      args.arg("--reject");
+    }
+    if self.reverse {
+      args.arg("--reverse");
+    }
+    if let Some(mode) = self.whitespace {
+      let arg = match mode {
+        WhitespaceMode::Nowarn => "nowarn",
+        WhitespaceMode::Warn => "warn",
+        WhitespaceMode::Fix => "fix",
+        WhitespaceMode::Error => "error",
+        WhitespaceMode::ErrorAll => "error-all",
+      };
+      args.arg(format!("--whitespace={}", arg));
+    }
+    if self.ignore_space_change {
+      args.arg("--ignore-space-change");
+    }
+    if self.three_way {
+      args.arg("--3way");
+    }
+    if self.verbose {
+      args.arg("--verbose");
+    }
+    args.args(self.patches);
+    args
+  }
+}
+
+impl_builder!(GitApplyBuilder);
+
+/// Options for the `whitespace` function
+#[derive(Clone)]
+pub enum WhitespaceMode {
+  /// Turn off the trailing whitespace warning
+  Nowarn,
+  /// Output warnings for a few such errors, but apply the patch as-is. This is
+  /// the default.
+  Warn,
+  /// Output warnings for a few such errors, and apply the patch after fixing
+  /// them
+  Fix,
+  /// Output warnings for a few such errors, and refuse to apply the patch
+  Error,
+  /// Like `Error`, but shows all errors
+  ErrorAll,
+}
@@ -0,0 +1,161 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git archive` command created by calling
+/// `Git::archive()`
+#[derive(Clone)]
+pub struct GitArchiveBuilder {
+  git: GitBuilder,
+  format: Option<ArchiveFormat>,
+  prefix: Option<String>,
+  output: Option<PathBuf>,
+  remote: Option<String>,
+  exec: Option<String>,
+  tree_ish: Option<String>,
+  pathspecs: Vec<PathBuf>,
+  worktree_attributes: bool,
+}
+
+impl GitArchiveBuilder {
+  /// Internal function used by `Git`. `Git::archive()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitArchiveBuilder {
+      git,
+      format: None,
+      prefix: None,
+      output: None,
+      remote: None,
+      exec: None,
+      tree_ish: None,
+      pathspecs: Vec::new(),
+      worktree_attributes: false,
+    }
+  }
+
+  /// The format of the resulting archive. If this isn't set git infers it from
+  /// the file name given to `output`, falling back to `tar`.
+  pub fn format(mut self, format: ArchiveFormat) -> Self {
+    self.format = Some(format);
+    self
+  }
+
+  /// Prepend the given prefix to each filename in the archive. This usually
+  /// ends in a `/` e.g. `project-1.0/`
+  pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+    self.prefix = Some(prefix.into());
+    self
+  }
+
+  /// Write the archive to the given file instead of stdout
+  pub fn output(mut self, path: impl Into<PathBuf>) -> Self {
+    self.output = Some(path.into());
+    self
+  }
+
+  /// Retrieve a tar archive from a remote repository instead of the local one
+  pub fn remote(mut self, repo: impl Into<String>) -> Self {
+    self.remote = Some(repo.into());
+    self
+  }
+
+  /// Used with `remote` to specify the path to `git-upload-archive` on the
+  /// remote side
+  pub fn exec(mut self, path: impl Into<String>) -> Self {
+    self.exec = Some(path.into());
+    self
+  }
+
+  /// The tree or commit to produce an archive for
+  pub fn tree_ish(mut self, tree_ish: impl Into<String>) -> Self {
+    self.tree_ish = Some(tree_ish.into());
+    self
+  }
+
+  /// Only include the given paths in the archive. This can be called multiple
+  /// times to add more paths.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// Look for attributes in `.gitattributes` files in the working tree as well
+  pub fn worktree_attributes(mut self) -> Self {
+    self.worktree_attributes = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("archive");
+
+    if let Some(format) = self.format {
+      let arg = match format {
+        ArchiveFormat::Tar => "tar".into(),
+        ArchiveFormat::TarGz => "tar.gz".into(),
+        ArchiveFormat::Tgz => "tgz".into(),
+        ArchiveFormat::Zip => "zip".into(),
+        ArchiveFormat::Custom(format) => format,
+      };
+      args.arg(format!("--format={}", arg));
+    }
+    if let Some(prefix) = self.prefix {
+      args.arg(format!("--prefix={}", prefix));
+    }
+    if let Some(path) = self.output {
+      args.arg("--output");
+      args.arg(path);
+    }
+    if let Some(repo) = self.remote {
+      args.arg(format!("--remote={}", repo));
+    }
+    if let Some(path) = self.exec {
+      args.arg(format!("--exec={}", path));
+    }
+    if self.worktree_attributes {
+      args.arg("--worktree-attributes");
+    }
+    if let Some(tree_ish) = self.tree_ish {
+      args.arg(tree_ish);
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitArchiveBuilder);
+
+/// Options for the `format` function
+#[derive(Clone)]
+pub enum ArchiveFormat {
+  /// An uncompressed tarball
+  Tar,
+  /// A gzipped tarball
+  TarGz,
+  /// Equivalent to `TarGz`
+  Tgz,
+  /// A zip file
+  Zip,
+  /// Any other format configured with `tar.<format>.command`
+  Custom(String),
+}
@@ -0,0 +1,278 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git bisect` command created by calling `Git::bisect()`.
+/// An operation must be chosen with `op` before calling `make_cmd`.
+#[derive(Clone)]
+pub struct GitBisectBuilder {
+  git: GitBuilder,
+  op: Option<BisectOp>,
+}
+
+impl GitBisectBuilder {
+  /// Internal function used by `Git`. `Git::bisect()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitBisectBuilder { git, op: None }
+  }
+
+  /// Choose which `git bisect` subcommand to run. See `BisectOp` for what each
+  /// of them do. Calling this again replaces the previously set operation.
+  pub fn op(mut self, op: BisectOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// Run the given command on each commit the bisect session checks out to
+  /// decide whether it is good or bad, until the first bad commit is found.
+  /// This is a shorthand for `op(BisectOp::Run { .. })`, so it replaces any
+  /// previously set operation. The session must already have been started
+  /// with a good and a bad commit.
+  ///
+  /// The command and its arguments are passed to git as separate arguments
+  /// rather than through a shell. Its exit code tells git what to do:
+  ///
+  /// - 0 means the commit is good
+  /// - 1 to 127, except 125, means the commit is bad
+  /// - 125 means the commit can't be tested and should be skipped
+  /// - 128 and above abort the bisect session
+  pub fn run_cmd(
+    self,
+    cmd: impl Into<OsString>,
+    args: impl IntoIterator<Item = impl Into<OsString>>,
+  ) -> Self {
+    self.op(BisectOp::Run {
+      cmd: cmd.into(),
+      args: args.into_iter().map(Into::into).collect(),
+    })
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`, if `BisectOp::Start`
+  /// has `good` revisions but no `bad` one, or if `BisectOp::Terms` is given
+  /// terms git doesn't accept
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`, if `BisectOp::Start`
+  /// has `good` revisions but no `bad` one, or if `BisectOp::Terms` is given
+  /// terms git doesn't accept
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("bisect");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      BisectOp::Start {
+        bad,
+        good,
+        no_checkout,
+        term_new,
+        term_old,
+      } => {
+        args.arg("start");
+        if no_checkout {
+          args.arg("--no-checkout");
+        }
+        if let Some(term) = term_new {
+          args.arg(format!("--term-new={}", term));
+        }
+        if let Some(term) = term_old {
+          args.arg(format!("--term-old={}", term));
+        }
+        match bad {
+          Some(bad) => {
+            args.arg(bad);
+            args.args(good);
+          }
+          None if !good.is_empty() => {
+            return Err(args.incomplete(
+              "<bad?>",
+              "`good` revisions can only be passed to `BisectOp::Start` along with a `bad` one",
+            ));
+          }
+          None => {}
+        }
+        args.arg("--");
+      }
+      BisectOp::Good(revs) => {
+        args.arg("good");
+        args.args(revs);
+      }
+      BisectOp::Bad(revs) => {
+        args.arg("bad");
+        args.args(revs);
+      }
+      BisectOp::Mark { term, revs } => {
+        args.arg(term);
+        args.args(revs);
+      }
+      BisectOp::Skip(revs) => {
+        args.arg("skip");
+        args.args(revs);
+      }
+      BisectOp::Reset(commit) => {
+        args.arg("reset");
+        if let Some(commit) = commit {
+          args.arg(commit);
+        }
+      }
+      BisectOp::Log => {
+        args.arg("log");
+      }
+      BisectOp::Replay(path) => {
+        args.arg("replay");
+        args.arg(path);
+      }
+      BisectOp::Visualize(options) => {
+        args.arg("visualize");
+        args.args(options);
+      }
+      BisectOp::Run {
+        cmd,
+        args: cmd_args,
+      } => {
+        args.arg("run");
+        args.arg(cmd);
+        args.args(cmd_args);
+      }
+      BisectOp::Terms {
+        term_bad,
+        term_good,
+      } => {
+        args.arg("terms");
+        let flag = match (term_bad.as_deref(), term_good.as_deref()) {
+          (None, None) => None,
+          (Some("bad"), None) => Some("--term-bad"),
+          (Some("new"), None) => Some("--term-new"),
+          (None, Some("good")) => Some("--term-good"),
+          (None, Some("old")) => Some("--term-old"),
+          (Some(_), Some(_)) => {
+            return Err(args.incomplete(
+              "<term?>",
+              "only one of `term_bad` and `term_good` can be passed to `BisectOp::Terms`",
+            ))
+          }
+          _ => {
+            return Err(args.incomplete(
+              "<term?>",
+              "`BisectOp::Terms` only accepts `bad` or `new` for `term_bad` and `good` or `old` \
+               for `term_good`",
+            ))
+          }
+        };
+        if let Some(flag) = flag {
+          args.arg(flag);
+        }
+      }
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitBisectBuilder,
+  panics = "This will panic if no operation was set with `op`, if `BisectOp::Start` has `good` \
+            revisions but no `bad` one, or if `BisectOp::Terms` is given terms git doesn't accept"
+);
+
+/// The operations that can be passed to `GitBisectBuilder::op`. Where a list
+/// of revisions is optional an empty list means the current `HEAD`.
+#[derive(Clone)]
+pub enum BisectOp {
+  /// Start a bisect session
+  Start {
+    /// A commit known to have the bug
+    bad: Option<String>,
+    /// Commits known not to have the bug. These can only be given along with
+    /// `bad`, otherwise mark them with `Good` once the session has started.
+    good: Vec<String>,
+    /// Don't check out the new working tree at each iteration of the
+    /// bisection process. Instead just update the `BISECT_HEAD` ref.
+    no_checkout: bool,
+    /// A custom term to use instead of `bad` for commits after the change
+    /// being looked for, e.g. `fixed` when looking for the commit that fixed a
+    /// bug. Commits are then marked with `Mark` instead of `Bad`.
+    term_new: Option<String>,
+    /// A custom term to use instead of `good` for commits before the change
+    /// being looked for, e.g. `broken`. Commits are then marked with `Mark`
+    /// instead of `Good`.
+    term_old: Option<String>,
+  },
+  /// Mark the given revisions as not having the bug
+  Good(Vec<String>),
+  /// Mark the given revisions as having the bug
+  Bad(Vec<String>),
+  /// Mark the given revisions with one of the custom terms the session was
+  /// started with, see `term_new` and `term_old` of `Start`
+  Mark {
+    /// The term to mark the revisions with
+    term: String,
+    /// The revisions to mark
+    revs: Vec<String>,
+  },
+  /// Skip the given revisions because they can't be tested
+  Skip(Vec<String>),
+  /// Finish the bisect session and go back to the given commit, or to the one
+  /// that was checked out before the session was started
+  Reset(Option<String>),
+  /// Show what has been done so far in the session
+  Log,
+  /// Replay a session from a file made with the output of `Log`
+  Replay(PathBuf),
+  /// Show the remaining suspects using gitk, or `git log` if no display is
+  /// available. The options given are passed on to the viewer.
+  Visualize(Vec<String>),
+  /// Decide whether each commit is good or bad by running a command, see
+  /// `GitBisectBuilder::run_cmd` for how its exit code is used
+  Run {
+    /// The program to run, e.g. a test script
+    cmd: OsString,
+    /// The arguments to pass to the program
+    args: Vec<OsString>,
+  },
+  /// Show the terms used to mark commits for the current session. With one of
+  /// the fields set only that term is printed. Git names the terms either
+  /// `bad` and `good` or `new` and `old`, so these are passed as
+  /// `--term-<name>`. Setting both fields or any other name panics.
+  Terms {
+    /// Only show the term used for bad commits, named `bad` or `new`
+    term_bad: Option<String>,
+    /// Only show the term used for good commits, named `good` or `old`
+    term_good: Option<String>,
+  },
+}
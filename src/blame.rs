@@ -0,0 +1,298 @@
+use crate::{impl_builder, Args, GitBuilder, ParseError};
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git blame` command created by calling `Git::blame()`
+#[derive(Clone)]
+pub struct GitBlameBuilder {
+  git: GitBuilder,
+  file: Option<PathBuf>,
+  line_ranges: Vec<String>,
+  porcelain: bool,
+  line_porcelain: bool,
+  show_stats: bool,
+  root: bool,
+  reverse: Option<String>,
+  since: Option<String>,
+  contents: Option<PathBuf>,
+}
+
+impl GitBlameBuilder {
+  /// Internal function used by `Git`. `Git::blame()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitBlameBuilder {
+      git,
+      file: None,
+      line_ranges: Vec::new(),
+      porcelain: false,
+      line_porcelain: false,
+      show_stats: false,
+      root: false,
+      reverse: None,
+      since: None,
+      contents: None,
+    }
+  }
+
+  /// The file to annotate
+  pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+    self.file = Some(path.into());
+    self
+  }
+
+  /// Annotate only the lines from `start` to `end` inclusive, counting from 1.
+  /// This can be called multiple times, as can `function_range`, to annotate
+  /// more ranges.
+  pub fn line_range(mut self, start: u32, end: u32) -> Self {
+    self.line_ranges.push(format!("{},{}", start, end));
+    self
+  }
+
+  /// Annotate only the lines of the function whose name matches the given
+  /// regex. This can be called multiple times, as can `line_range`, to
+  /// annotate more ranges.
+  pub fn function_range(mut self, name: impl Into<String>) -> Self {
+    self.line_ranges.push(format!(":{}", name.into()));
+    self
+  }
+
+  /// Show in a format designed for machine consumption. The commit
+  /// information is only shown the first time a commit appears.
+  pub fn porcelain(mut self) -> Self {
+    self.porcelain = true;
+    self
+  }
+
+  /// Show in a format designed for machine consumption like `porcelain`, but
+  /// output the commit information for every line. The output can be fed to
+  /// `GitBlameEntry::parse_line_porcelain`.
+  pub fn line_porcelain(mut self) -> Self {
+    self.line_porcelain = true;
+    self
+  }
+
+  /// Include additional statistics at the end of the blame output
+  pub fn show_stats(mut self) -> Self {
+    self.show_stats = true;
+    self
+  }
+
+  /// Do not treat root commits as boundaries
+  pub fn root(mut self) -> Self {
+    self.root = true;
+    self
+  }
+
+  /// Walk history forward instead of backward. Instead of showing the revision
+  /// in which a line appeared, this shows the last revision in which a line
+  /// existed. The range is given like `START..END` or just `START`.
+  pub fn reverse(mut self, range: impl Into<String>) -> Self {
+    self.reverse = Some(range.into());
+    self
+  }
+
+  /// Don't look at commits older than the given date such as `"3 weeks ago"`.
+  /// Lines from before then are attributed to the boundary commit.
+  pub fn since(mut self, date: impl Into<String>) -> Self {
+    self.since = Some(date.into());
+    self
+  }
+
+  /// Annotate using the contents of the given file as the final image instead
+  /// of the working tree copy of the file being blamed
+  pub fn contents(mut self, path: impl Into<PathBuf>) -> Self {
+    self.contents = Some(path.into());
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("blame");
+
+    for range in self.line_ranges {
+      args.arg("-L");
+      args.arg(range);
+    }
+    if self.porcelain {
+      args.arg("--porcelain");
+    }
+    if self.line_porcelain {
+      args.arg("--line-porcelain");
+    }
+    if self.show_stats {
+      args.arg("--show-stats");
+    }
+    if self.root {
+      args.arg("--root");
+    }
+    if let Some(range) = self.reverse {
+      args.arg("--reverse");
+      args.arg(range);
+    }
+    if let Some(date) = self.since {
+      args.arg(format!("--since={}", date));
+    }
+    if let Some(path) = self.contents {
+      args.arg("--contents");
+      args.arg(path);
+    }
+    if let Some(path) = self.file {
+      args.arg("--");
+      args.arg(path);
+    }
+    args
+  }
+}
+
+impl_builder!(GitBlameBuilder);
+
+/// A single line of a file annotated with the commit that last changed it,
+/// parsed from the output of `git blame --line-porcelain` or `--porcelain`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitBlameEntry {
+  /// The full hash of the commit the line came from
+  pub commit_hash: String,
+  /// The name of the author of the commit
+  pub author: String,
+  /// The email of the author of the commit, including the angle brackets
+  pub author_mail: String,
+  /// When the commit was authored, in seconds since the unix epoch
+  pub author_time: i64,
+  /// The name of the committer of the commit
+  pub committer: String,
+  /// When the commit was committed, in seconds since the unix epoch
+  pub committer_time: i64,
+  /// The first line of the commit message
+  pub summary: String,
+  /// The name of the file in the commit the line came from, which differs
+  /// from the blamed file if it was renamed since
+  pub filename: PathBuf,
+  /// The line number in the file in the commit the line came from, counting
+  /// from 1
+  pub original_line: u32,
+  /// The line number in the blamed file, counting from 1
+  pub final_line: u32,
+  /// The contents of the line without the trailing newline. A `\r` from CRLF
+  /// line endings is kept, and if the file isn't valid utf-8 the invalid bytes
+  /// are replaced with `U+FFFD`.
+  pub content: String,
+}
+
+/// The commit information git prints in the headers of blame output
+#[derive(Clone, Default)]
+struct CommitInfo {
+  author: String,
+  author_mail: String,
+  author_time: i64,
+  committer: String,
+  committer_time: i64,
+  summary: String,
+  filename: PathBuf,
+}
+
+impl GitBlameEntry {
+  /// Parse the output of `git blame --line-porcelain` into one entry per line,
+  /// in the order git listed them. The output of `git blame --porcelain` is
+  /// understood too: it only shows the commit information the first time a
+  /// commit appears, so it is remembered and reused for later lines from the
+  /// same commit.
+  pub fn parse_line_porcelain(bytes: &[u8]) -> Result<Vec<Self>, ParseError> {
+    let mut commits: HashMap<&str, CommitInfo> = HashMap::new();
+    let mut entries = Vec::new();
+    // Only split on `\n`, so that a `\r` at the end of a line in a file with
+    // CRLF line endings stays part of its contents
+    let mut lines = bytes.split(|&b| b == b'\n');
+    while let Some(header) = lines.next() {
+      if header.is_empty() {
+        continue;
+      }
+      let header = parse_header(header)?;
+      let mut fields = header.split(' ');
+      let (hash, original_line, final_line) = match (fields.next(), fields.next(), fields.next()) {
+        (Some(hash), Some(original_line), Some(final_line)) => (
+          hash,
+          parse_number(original_line, header)?,
+          parse_number(final_line, header)?,
+        ),
+        _ => return Err(ParseError::new(format!("invalid blame header: {}", header))),
+      };
+      let info = commits.entry(hash).or_default();
+
+      let content = loop {
+        let line = lines.next().ok_or_else(|| {
+          ParseError::new(format!("missing the contents of the line for {}", hash))
+        })?;
+        // The blamed file doesn't have to be utf-8, so its contents are
+        // decoded lossily instead of failing the whole parse
+        if let Some(content) = line.strip_prefix(b"\t") {
+          break String::from_utf8_lossy(content).into_owned();
+        }
+        let line = parse_header(line)?;
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        match key {
+          "author" => info.author = value.into(),
+          "author-mail" => info.author_mail = value.into(),
+          "author-time" => info.author_time = parse_number(value, line)?,
+          "committer" => info.committer = value.into(),
+          "committer-time" => info.committer_time = parse_number(value, line)?,
+          "summary" => info.summary = value.into(),
+          "filename" => info.filename = value.into(),
+          // The time zones, committer email, `previous` and `boundary` aren't
+          // part of the entry
+          _ => {}
+        }
+      };
+
+      let info = info.clone();
+      entries.push(GitBlameEntry {
+        commit_hash: hash.into(),
+        author: info.author,
+        author_mail: info.author_mail,
+        author_time: info.author_time,
+        committer: info.committer,
+        committer_time: info.committer_time,
+        summary: info.summary,
+        filename: info.filename,
+        original_line,
+        final_line,
+        content,
+      });
+    }
+    Ok(entries)
+  }
+}
+
+/// Decode a header line of blame output, which unlike the blamed lines
+/// themselves has to be valid utf-8
+fn parse_header(line: &[u8]) -> Result<&str, ParseError> {
+  std::str::from_utf8(line)
+    .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))
+}
+
+/// Parse a number in a blame header
+fn parse_number<T: std::str::FromStr>(number: &str, line: &str) -> Result<T, ParseError>
+where
+  T::Err: std::fmt::Display,
+{
+  number
+    .parse()
+    .map_err(|e| ParseError::new(format!("invalid number {} in line {}: {}", number, line, e)))
+}
@@ -0,0 +1,94 @@
+use crate::ParseError;
+
+/// A single branch parsed from the output of `git branch` run with
+/// `--format` set to `GitBranchEntry::FORMAT`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitBranchEntry {
+  /// The short name of the branch e.g. `main`
+  pub name: String,
+  /// Whether this is the branch `HEAD` points at
+  pub is_current: bool,
+  /// The short name of the upstream branch e.g. `origin/main`, if one is set
+  pub upstream: Option<String>,
+  /// How many commits the branch has that its upstream doesn't. This is
+  /// `None` if there is no upstream or the upstream branch no longer exists.
+  pub ahead: Option<u32>,
+  /// How many commits the upstream has that the branch doesn't. This is
+  /// `None` if there is no upstream or the upstream branch no longer exists.
+  pub behind: Option<u32>,
+  /// The full hash of the commit at the tip of the branch
+  pub commit_hash: String,
+  /// The first line of the commit message at the tip of the branch
+  pub subject: String,
+}
+
+impl GitBranchEntry {
+  /// The format to pass to `git branch --format` to get output that
+  /// `parse_branch_output` understands. The fields are separated by NUL bytes
+  /// so that no branch name or subject can be confused with the next field.
+  pub const FORMAT: &'static str = "%(HEAD)%00%(refname:short)%00%(upstream:short)%00%(upstream:track,nobracket)%00%(objectname)%00%(contents:subject)";
+
+  /// The number of fields in `FORMAT`
+  const FIELDS: usize = 6;
+
+  /// Parse the output of `git branch --format=<GitBranchEntry::FORMAT>` into
+  /// one entry per branch, in the order git listed them
+  pub fn parse_branch_output(bytes: &[u8]) -> Result<Vec<Self>, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    output
+      .lines()
+      .filter(|line| !line.is_empty())
+      .map(|line| {
+        let fields: Vec<&str> = line.split('\0').collect();
+        if fields.len() != Self::FIELDS {
+          return Err(ParseError::new(format!(
+            "expected {} fields but got {} in line: {}",
+            Self::FIELDS,
+            fields.len(),
+            line.replace('\0', " ")
+          )));
+        }
+        let upstream = Some(fields[2]).filter(|upstream| !upstream.is_empty());
+        let (ahead, behind) = match upstream {
+          Some(_) => parse_track(fields[3])?,
+          None => (None, None),
+        };
+        Ok(GitBranchEntry {
+          name: fields[1].into(),
+          is_current: fields[0] == "*",
+          upstream: upstream.map(Into::into),
+          ahead,
+          behind,
+          commit_hash: fields[4].into(),
+          subject: fields[5].into(),
+        })
+      })
+      .collect()
+  }
+}
+
+/// Parse the output of `%(upstream:track,nobracket)` such as `ahead 1, behind
+/// 2` into the ahead and behind counts. An empty string means the branch is
+/// up to date with its upstream.
+fn parse_track(track: &str) -> Result<(Option<u32>, Option<u32>), ParseError> {
+  if track == "gone" {
+    return Ok((None, None));
+  }
+  let (mut ahead, mut behind) = (0, 0);
+  for part in track.split(", ").filter(|part| !part.is_empty()) {
+    let (kind, count) = part
+      .split_once(' ')
+      .ok_or_else(|| ParseError::new(format!("invalid tracking info: {}", track)))?;
+    let count = count
+      .parse::<u32>()
+      .map_err(|e| ParseError::new(format!("invalid tracking info {}: {}", track, e)))?;
+    match kind {
+      "ahead" => ahead = count,
+      "behind" => behind = count,
+      _ => return Err(ParseError::new(format!("invalid tracking info: {}", track))),
+    }
+  }
+  Ok((Some(ahead), Some(behind)))
+}
@@ -0,0 +1,584 @@
+use crate::*;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// A set of options that apply to every git command rather than a single
+/// subcommand, created by calling `Git::builder()`. Every builder created from
+/// it passes the options to git before the subcommand. For example:
+///
+/// ```
+/// # use git_cmd::Git;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let dir = std::env::temp_dir();
+/// let git = Git::builder().working_dir(dir);
+/// // This is equivalent to calling `git -C <dir> log` and `git -C <dir> diff`
+/// let log = git.log().make_cmd();
+/// let diff = git.diff().make_cmd();
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GitBuilder {
+  working_dir: Option<PathBuf>,
+  git_dir: Option<PathBuf>,
+  work_tree: Option<PathBuf>,
+  config_overrides: Vec<(String, String)>,
+}
+
+impl GitBuilder {
+  /// Create a `GitBuilder` with no options set. The builders it creates behave
+  /// exactly like the ones from the static methods on `Git`.
+  pub fn new() -> Self {
+    GitBuilder {
+      working_dir: None,
+      git_dir: None,
+      work_tree: None,
+      config_overrides: Vec::new(),
+    }
+  }
+
+  /// Run git as if it was started in the given directory instead of the
+  /// current one. This is passed to git as `-C <dir>`, so unlike
+  /// `Command::current_dir` it also applies to commands that don't otherwise
+  /// take a directory.
+  pub fn working_dir(mut self, path: impl Into<PathBuf>) -> Self {
+    self.working_dir = Some(path.into());
+    self
+  }
+
+  /// Use the repository at the given path instead of searching for a `.git`
+  /// directory. Relative paths are resolved after `working_dir` is applied.
+  pub fn git_dir(mut self, path: impl Into<PathBuf>) -> Self {
+    self.git_dir = Some(path.into());
+    self
+  }
+
+  /// Use the given path as the root of the working tree. This is mostly useful
+  /// together with `git_dir` when the repository doesn't live inside the
+  /// working tree.
+  pub fn work_tree(mut self, path: impl Into<PathBuf>) -> Self {
+    self.work_tree = Some(path.into());
+    self
+  }
+
+  /// Set a config variable for the commands only, as if it was in the
+  /// repository config, e.g. `config_override("user.name", "CI Bot")`. This is
+  /// passed to git as `-c key=value` and can be called multiple times to set
+  /// more variables.
+  pub fn config_override(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+    self.config_overrides.push((key.into(), value.into()));
+    self
+  }
+
+  /// Like `config_override`, but sets all of the given variables in order
+  pub fn config_overrides<K, V>(mut self, overrides: impl IntoIterator<Item = (K, V)>) -> Self
+  where
+    K: Into<String>,
+    V: Into<String>,
+  {
+    self.config_overrides.extend(
+      overrides
+        .into_iter()
+        .map(|(key, value)| (key.into(), value.into())),
+    );
+    self
+  }
+
+  /// Creates a builder for the `git init` subcommand
+  pub fn init(&self) -> GitInitBuilder {
+    GitInitBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git log` subcommand
+  pub fn log(&self) -> GitLogBuilder {
+    GitLogBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git diff` subcommand
+  pub fn diff(&self) -> GitDiffBuilder {
+    GitDiffBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git remote` subcommand
+  pub fn remote(&self) -> GitRemoteBuilder {
+    GitRemoteBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git tag` subcommand
+  pub fn tag(&self) -> GitTagBuilder {
+    GitTagBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git stash` subcommand
+  pub fn stash(&self) -> GitStashBuilder {
+    GitStashBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git reset` subcommand
+  pub fn reset(&self) -> GitResetBuilder {
+    GitResetBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git revert` subcommand
+  pub fn revert(&self) -> GitRevertBuilder {
+    GitRevertBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git cherry-pick` subcommand
+  pub fn cherry_pick(&self) -> GitCherryPickBuilder {
+    GitCherryPickBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git submodule` subcommand
+  pub fn submodule(&self) -> GitSubmoduleBuilder {
+    GitSubmoduleBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git config` subcommand
+  pub fn config(&self) -> GitConfigBuilder {
+    GitConfigBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git rm` subcommand
+  pub fn rm(&self) -> GitRmBuilder {
+    GitRmBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git mv` subcommand
+  pub fn mv(&self) -> GitMvBuilder {
+    GitMvBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git show` subcommand
+  pub fn show(&self) -> GitShowBuilder {
+    GitShowBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git blame` subcommand
+  pub fn blame(&self) -> GitBlameBuilder {
+    GitBlameBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git bisect` subcommand
+  pub fn bisect(&self) -> GitBisectBuilder {
+    GitBisectBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git worktree` subcommand
+  pub fn worktree(&self) -> GitWorktreeBuilder {
+    GitWorktreeBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git clean` subcommand
+  pub fn clean(&self) -> GitCleanBuilder {
+    GitCleanBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git gc` subcommand
+  pub fn gc(&self) -> GitGcBuilder {
+    GitGcBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git archive` subcommand
+  pub fn archive(&self) -> GitArchiveBuilder {
+    GitArchiveBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git apply` subcommand
+  pub fn apply(&self) -> GitApplyBuilder {
+    GitApplyBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git format-patch` subcommand
+  pub fn format_patch(&self) -> GitFormatPatchBuilder {
+    GitFormatPatchBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git shortlog` subcommand
+  pub fn shortlog(&self) -> GitShortlogBuilder {
+    GitShortlogBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git describe` subcommand
+  pub fn describe(&self) -> GitDescribeBuilder {
+    GitDescribeBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git rev-parse` subcommand
+  pub fn rev_parse(&self) -> GitRevParseBuilder {
+    GitRevParseBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git ls-files` subcommand
+  pub fn ls_files(&self) -> GitLsFilesBuilder {
+    GitLsFilesBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git ls-tree` subcommand
+  pub fn ls_tree(&self) -> GitLsTreeBuilder {
+    GitLsTreeBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git cat-file` subcommand
+  pub fn cat_file(&self) -> GitCatFileBuilder {
+    GitCatFileBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git hash-object` subcommand
+  pub fn hash_object(&self) -> GitHashObjectBuilder {
+    GitHashObjectBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git reflog` subcommand
+  pub fn reflog(&self) -> GitReflogBuilder {
+    GitReflogBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git bundle` subcommand
+  pub fn bundle(&self) -> GitBundleBuilder {
+    GitBundleBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git notes` subcommand
+  pub fn notes(&self) -> GitNotesBuilder {
+    GitNotesBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git sparse-checkout` subcommand
+  pub fn sparse_checkout(&self) -> GitSparseCheckoutBuilder {
+    GitSparseCheckoutBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git ls-remote` subcommand
+  pub fn ls_remote(&self) -> GitLsRemoteBuilder {
+    GitLsRemoteBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git fsck` subcommand
+  pub fn fsck(&self) -> GitFsckBuilder {
+    GitFsckBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git prune` subcommand
+  pub fn prune(&self) -> GitPruneBuilder {
+    GitPruneBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git pack-refs` subcommand
+  pub fn pack_refs(&self) -> GitPackRefsBuilder {
+    GitPackRefsBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git count-objects` subcommand
+  pub fn count_objects(&self) -> GitCountObjectsBuilder {
+    GitCountObjectsBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git am` subcommand
+  pub fn am(&self) -> GitAmBuilder {
+    GitAmBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git range-diff` subcommand
+  pub fn range_diff(&self) -> GitRangeDiffBuilder {
+    GitRangeDiffBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git mergetool` subcommand
+  pub fn mergetool(&self) -> GitMergetoolBuilder {
+    GitMergetoolBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git difftool` subcommand
+  pub fn difftool(&self) -> GitDifftoolBuilder {
+    GitDifftoolBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git maintenance` subcommand
+  pub fn maintenance(&self) -> GitMaintenanceBuilder {
+    GitMaintenanceBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git update-ref` subcommand
+  pub fn update_ref(&self) -> GitUpdateRefBuilder {
+    GitUpdateRefBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git symbolic-ref` subcommand
+  pub fn symbolic_ref(&self) -> GitSymbolicRefBuilder {
+    GitSymbolicRefBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git verify-pack` subcommand
+  pub fn verify_pack(&self) -> GitVerifyPackBuilder {
+    GitVerifyPackBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git lfs` subcommand
+  pub fn lfs(&self) -> GitLfsBuilder {
+    GitLfsBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git commit-tree` subcommand
+  pub fn commit_tree(&self) -> GitCommitTreeBuilder {
+    GitCommitTreeBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git read-tree` subcommand
+  pub fn read_tree(&self) -> GitReadTreeBuilder {
+    GitReadTreeBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git write-tree` subcommand
+  pub fn write_tree(&self) -> GitWriteTreeBuilder {
+    GitWriteTreeBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git rev-list` subcommand
+  pub fn rev_list(&self) -> GitRevListBuilder {
+    GitRevListBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git for-each-ref` subcommand
+  pub fn for_each_ref(&self) -> GitForEachRefBuilder {
+    GitForEachRefBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git check-ignore` subcommand
+  pub fn check_ignore(&self) -> GitCheckIgnoreBuilder {
+    GitCheckIgnoreBuilder::new(self.clone())
+  }
+
+  /// Creates a builder for the `git credential` subcommand
+  pub fn credential(&self) -> GitCredentialBuilder {
+    GitCredentialBuilder::new(self.clone())
+  }
+
+  /// Collect the global options, ready for a builder to add its subcommand
+  /// and arguments to
+  pub(crate) fn args(&self) -> Args {
+    let mut args = Args::default();
+    if let Some(path) = &self.working_dir {
+      args.arg("-C");
+      args.arg(path);
+    }
+    for (key, value) in &self.config_overrides {
+      args.arg("-c");
+      args.arg(format!("{}={}", key, value));
+    }
+    if let Some(path) = &self.git_dir {
+      args.arg(path_arg("--git-dir=", path));
+    }
+    if let Some(path) = &self.work_tree {
+      args.arg(path_arg("--work-tree=", path));
+    }
+    args
+  }
+}
+
+/// The arguments passed to `git` by a builder. These are added with `arg` and
+/// `args` just like on a `Command` so that the same code can back both
+/// `make_cmd` and `to_args`.
+#[derive(Debug, Default)]
+pub(crate) struct Args(Vec<OsString>);
+
+impl Args {
+  /// Add a single argument
+  pub(crate) fn arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self {
+    self.0.push(arg.as_ref().to_owned());
+    self
+  }
+
+  /// Add multiple arguments in order
+  pub(crate) fn args(&mut self, args: impl IntoIterator<Item = impl AsRef<OsStr>>) -> &mut Self {
+    self
+      .0
+      .extend(args.into_iter().map(|arg| arg.as_ref().to_owned()));
+    self
+  }
+
+  /// Create the `git` command with the arguments
+  pub(crate) fn command(self) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.args(self.0);
+    cmd
+  }
+}
+
+/// The arguments a builder collected before it found out that they are
+/// incomplete or invalid, along with why. This lets `Display` still show the
+/// command with a placeholder for the missing part instead of failing.
+pub(crate) struct IncompleteArgs<E = &'static str> {
+  args: Args,
+  placeholder: &'static str,
+  /// Why the arguments are incomplete, which `make_cmd` panics with
+  pub(crate) error: E,
+}
+
+impl Args {
+  /// Stop collecting arguments because they are incomplete, keeping the ones
+  /// so far so that they can be shown with `placeholder` after them
+  pub(crate) fn incomplete<E>(self, placeholder: &'static str, error: E) -> IncompleteArgs<E> {
+    IncompleteArgs {
+      args: self,
+      placeholder,
+      error,
+    }
+  }
+}
+
+impl<E> IncompleteArgs<E> {
+  /// Write the arguments collected so far like `write_shell_command`,
+  /// followed by the placeholder for the missing part
+  pub(crate) fn write_shell_command(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write_shell_command(f, &self.args.0)?;
+    write!(f, " {}", self.placeholder)
+  }
+}
+
+impl From<Args> for Vec<OsString> {
+  fn from(args: Args) -> Self {
+    args.0
+  }
+}
+
+/// Joins a flag and a path into a single argument without requiring the path to
+/// be valid UTF-8
+fn path_arg(flag: &str, path: &Path) -> OsString {
+  let mut arg = OsString::from(flag);
+  arg.push(path);
+  arg
+}
+
+/// Write the arguments as the equivalent shell command, e.g. `git init --bare
+/// '/tmp/my repo'`. Arguments with anything other than letters, digits, and a
+/// few punctuation characters are wrapped in single quotes so that they can be
+/// pasted into a POSIX shell. Arguments that aren't valid UTF-8 are shown
+/// lossily.
+pub(crate) fn write_shell_command(f: &mut fmt::Formatter<'_>, args: &[OsString]) -> fmt::Result {
+  f.write_str("git")?;
+  for arg in args {
+    let arg = arg.to_string_lossy();
+    let safe = !arg.is_empty()
+      && arg
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-_./=:,@%+^~".contains(c));
+    if safe {
+      write!(f, " {}", arg)?;
+    } else {
+      write!(f, " '{}'", arg.replace('\'', r"'\''"))?;
+    }
+  }
+  Ok(())
+}
+
+/// The hash a plumbing command like `git commit-tree` printed on stdout,
+/// without the trailing newline
+pub(crate) fn stdout_hash(output: &Output) -> String {
+  String::from_utf8_lossy(&output.stdout).trim().into()
+}
+
+/// Implements the methods that are the same for every builder on top of its
+/// own `make_cmd` and `to_args`: `execute`, `execute_async`, `make_child` and
+/// `make_child_with_input`, along with `Display` and `IntoMakeCmd`.
+///
+/// `panics` adds a `# Panics` section with the given text to the docs of the
+/// methods for builders whose `make_cmd` can panic. These builders also need a
+/// `try_args` that returns `IncompleteArgs` instead of panicking, which
+/// `Display` uses to show incomplete builders with a placeholder like `git
+/// stash <op?>`. Builders whose `execute`
+/// does more than return the output, like parsing a hash out of it, pass
+/// `without_execute` and write `execute` and `execute_async` themselves.
+macro_rules! impl_builder {
+  ($builder:ident, $(panics = $panics:literal,)? without_execute) => {
+    $crate::builder::impl_builder!(@spawn $builder $(, $panics)?);
+    $crate::builder::impl_builder!(@traits $builder $(, $panics)?);
+  };
+  ($builder:ident $(, panics = $panics:literal)?) => {
+    $crate::builder::impl_builder!(@execute $builder $(, $panics)?);
+    $crate::builder::impl_builder!(@spawn $builder $(, $panics)?);
+    $crate::builder::impl_builder!(@traits $builder $(, $panics)?);
+  };
+  (@execute $builder:ident $(, $panics:literal)?) => {
+    impl $builder {
+      /// Run the command, waiting for it to finish and collecting its output.
+      /// This returns an error if git couldn't be run or didn't exit
+      /// successfully, see `GitError` for the details.
+      $(#[doc = ""] #[doc = "# Panics"] #[doc = $panics])?
+      pub fn execute(self) -> Result<::std::process::Output, $crate::GitError> {
+        $crate::GitError::execute(self.make_cmd())
+      }
+
+      /// Like `execute`, but runs git with `tokio` so that waiting for it
+      /// doesn't block the async runtime. This requires the `async` feature.
+      $(#[doc = ""] #[doc = "# Panics"] #[doc = $panics])?
+      #[cfg(feature = "async")]
+      pub async fn execute_async(self) -> Result<::std::process::Output, $crate::GitError> {
+        $crate::GitError::execute_async(self.make_cmd()).await
+      }
+    }
+  };
+  (@spawn $builder:ident $(, $panics:literal)?) => {
+    impl $builder {
+      /// Spawn the command with its stdout piped instead of waiting for it to
+      /// finish, so that large outputs can be read as git writes them, e.g.
+      /// line by line with `std::io::BufReader`. Dropping the `Child` closes
+      /// the pipe, which makes git exit the next time it writes, but doesn't
+      /// wait for it.
+      $(#[doc = ""] #[doc = "# Panics"] #[doc = $panics])?
+      pub fn make_child(self) -> ::std::io::Result<::std::process::Child> {
+        self
+          .make_cmd()
+          .stdout(::std::process::Stdio::piped())
+          .spawn()
+      }
+
+      /// Like `make_child`, but also pipes stdin for commands that read their
+      /// input from it, like `git cat-file --batch`. Drop the child's `stdin`
+      /// once all the input is written so that git sees the end of it.
+      $(#[doc = ""] #[doc = "# Panics"] #[doc = $panics])?
+      pub fn make_child_with_input(self) -> ::std::io::Result<::std::process::Child> {
+        self
+          .make_cmd()
+          .stdin(::std::process::Stdio::piped())
+          .stdout(::std::process::Stdio::piped())
+          .spawn()
+      }
+    }
+  };
+  (@traits $builder:ident) => {
+    impl ::std::fmt::Display for $builder {
+      fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        $crate::builder::write_shell_command(f, &self.to_args())
+      }
+    }
+
+    $crate::builder::impl_builder!(@into_make_cmd $builder);
+  };
+  // Builders that can panic have a `try_args` that returns the arguments
+  // collected so far instead, which are shown with a placeholder for the
+  // missing part so that formatting never panics
+  (@traits $builder:ident, $panics:literal) => {
+    impl ::std::fmt::Display for $builder {
+      fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self.clone().try_args() {
+          Ok(args) => $crate::builder::write_shell_command(f, &Vec::from(args)),
+          Err(incomplete) => incomplete.write_shell_command(f),
+        }
+      }
+    }
+
+    $crate::builder::impl_builder!(@into_make_cmd $builder);
+  };
+  (@into_make_cmd $builder:ident) => {
+    impl $crate::IntoMakeCmd for $builder {
+      fn make_cmd(self) -> ::std::process::Command {
+        $builder::make_cmd(self)
+      }
+    }
+  };
+}
+
+pub(crate) use impl_builder;
@@ -0,0 +1,152 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git bundle` command created by calling
+/// `Git::bundle()`. An operation must be chosen with `op` before calling
+/// `make_cmd`.
+#[derive(Clone)]
+pub struct GitBundleBuilder {
+  git: GitBuilder,
+  op: Option<BundleOp>,
+}
+
+impl GitBundleBuilder {
+  /// Internal function used by `Git`. `Git::bundle()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitBundleBuilder { git, op: None }
+  }
+
+  /// Choose which `git bundle` subcommand to run. See `BundleOp` for what each
+  /// of them do. Calling this again replaces the previously set operation.
+  pub fn op(mut self, op: BundleOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("bundle");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      BundleOp::Create {
+        file,
+        prerequisites,
+        refs,
+        all,
+      } => {
+        args.arg("create");
+        args.arg(file);
+        if all {
+          args.arg("--all");
+        }
+        args.args(refs);
+        args.args(prerequisites);
+      }
+      BundleOp::Verify { file, quiet } => {
+        args.arg("verify");
+        if quiet {
+          args.arg("--quiet");
+        }
+        args.arg(file);
+      }
+      BundleOp::ListHeads { file } => {
+        args.arg("list-heads");
+        args.arg(file);
+      }
+      BundleOp::Unbundle { file, refs } => {
+        args.arg("unbundle");
+        args.arg(file);
+        args.args(refs);
+      }
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitBundleBuilder,
+  panics = "This will panic if no operation was set with `op`"
+);
+
+/// The operations that can be passed to `GitBundleBuilder::op`
+#[derive(Clone)]
+pub enum BundleOp {
+  /// Create a bundle file containing the given refs and the history leading
+  /// up to them
+  Create {
+    /// Where to write the bundle
+    file: PathBuf,
+    /// Commits the receiving side is expected to already have, written with
+    /// negative ref syntax such as `"^old-commit"`. Their history is left out
+    /// of the bundle.
+    prerequisites: Vec<String>,
+    /// The refs to include in the bundle such as `"main"`
+    refs: Vec<String>,
+    /// Include every ref in the repository
+    all: bool,
+  },
+  /// Check that a bundle file is valid and applies cleanly to the current
+  /// repository
+  Verify {
+    /// The bundle to check
+    file: PathBuf,
+    /// Only report errors, not the details of the bundle
+    quiet: bool,
+  },
+  /// List the refs defined in a bundle
+  ListHeads {
+    /// The bundle to list the refs of
+    file: PathBuf,
+  },
+  /// Store the objects in a bundle in the repository and print the refs it
+  /// defines. This doesn't update any refs; `git fetch` from the bundle does
+  /// that.
+  Unbundle {
+    /// The bundle to unpack
+    file: PathBuf,
+    /// Only print the refs matching these names
+    refs: Vec<String>,
+  },
+}
@@ -0,0 +1,133 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git cat-file` command created by calling
+/// `Git::cat_file()`. Either a `mode` and an `object` should be set, or one of
+/// `batch` and `batch_check` to read object names from stdin.
+#[derive(Clone)]
+pub struct GitCatFileBuilder {
+  git: GitBuilder,
+  mode: Option<CatFileMode>,
+  object: Option<String>,
+  allow_unknown_type: bool,
+  batch: Option<String>,
+  batch_check: Option<String>,
+}
+
+impl GitCatFileBuilder {
+  /// Internal function used by `Git`. `Git::cat_file()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitCatFileBuilder {
+      git,
+      mode: None,
+      object: None,
+      allow_unknown_type: false,
+      batch: None,
+      batch_check: None,
+    }
+  }
+
+  /// What to output about the object. See `CatFileMode` for the options.
+  pub fn mode(mut self, mode: CatFileMode) -> Self {
+    self.mode = Some(mode);
+    self
+  }
+
+  /// The object to show such as a hash or `"HEAD:src/lib.rs"`
+  pub fn object(mut self, object: impl Into<String>) -> Self {
+    self.object = Some(object.into());
+    self
+  }
+
+  /// Allow `CatFileMode::Type` and `CatFileMode::Size` to query broken or
+  /// corrupt objects of unknown type
+  pub fn allow_unknown_type(mut self) -> Self {
+    self.allow_unknown_type = true;
+    self
+  }
+
+  /// Print the information and contents of each object named on stdin, using
+  /// the given format for the header line e.g. `"%(objectname) %(objecttype)"`.
+  /// Pass an empty string to use the default format.
+  pub fn batch(mut self, format: impl Into<String>) -> Self {
+    self.batch = Some(format.into());
+    self
+  }
+
+  /// Like `batch`, but only print the information and not the contents of each
+  /// object
+  pub fn batch_check(mut self, format: impl Into<String>) -> Self {
+    self.batch_check = Some(format.into());
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("cat-file");
+
+    if let Some(mode) = self.mode {
+      args.arg(match mode {
+        CatFileMode::Type => "-t",
+        CatFileMode::Size => "-s",
+        CatFileMode::Exists => "-e",
+        CatFileMode::PrettyPrint => "-p",
+      });
+    }
+    if self.allow_unknown_type {
+      args.arg("--allow-unknown-type");
+    }
+    if let Some(format) = self.batch {
+      args.arg(batch_arg("--batch", format));
+    }
+    if let Some(format) = self.batch_check {
+      args.arg(batch_arg("--batch-check", format));
+    }
+    if let Some(object) = self.object {
+      args.arg(object);
+    }
+    args
+  }
+}
+
+impl_builder!(GitCatFileBuilder);
+
+/// Only adds the format to the flag if there is one, since git would otherwise
+/// print an empty header line instead of the default one
+fn batch_arg(flag: &str, format: String) -> String {
+  if format.is_empty() {
+    flag.into()
+  } else {
+    format!("{}={}", flag, format)
+  }
+}
+
+/// Options for the `mode` function
+#[derive(Clone)]
+pub enum CatFileMode {
+  /// Show the type of the object e.g. `blob`
+  Type,
+  /// Show the size of the object in bytes
+  Size,
+  /// Exit with a zero status if the object exists and is valid, printing
+  /// nothing
+  Exists,
+  /// Pretty-print the contents of the object based on its type
+  PrettyPrint,
+}
@@ -0,0 +1,195 @@
+use crate::{impl_builder, Args, GitBuilder, ParseError};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git check-ignore` command created by calling
+/// `Git::check_ignore()`. This prints the given paths that are ignored by a
+/// `.gitignore` file or any of the other exclude files. Git exits with status 1
+/// when none of the paths are ignored, so `execute` returns
+/// `GitError::NonZeroExit` with a `code` of `Some(1)` in that case.
+///
+/// The output of `verbose` can be fed to
+/// `GitCheckIgnoreEntry::parse_verbose_output`.
+#[derive(Clone)]
+pub struct GitCheckIgnoreBuilder {
+  git: GitBuilder,
+  pathspecs: Vec<PathBuf>,
+  verbose: bool,
+  stdin: bool,
+  non_matching: bool,
+  no_index: bool,
+  quiet: bool,
+  stdin_null: bool,
+}
+
+impl GitCheckIgnoreBuilder {
+  /// Internal function used by `Git`. `Git::check_ignore()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitCheckIgnoreBuilder {
+      git,
+      pathspecs: Vec::new(),
+      verbose: false,
+      stdin: false,
+      non_matching: false,
+      no_index: false,
+      quiet: false,
+      stdin_null: false,
+    }
+  }
+
+  /// The paths to check. This can be called multiple times to add more paths.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// Also print the exclude file, line number and pattern that matched each
+  /// path
+  pub fn verbose(mut self) -> Self {
+    self.verbose = true;
+    self
+  }
+
+  /// Read the paths to check from stdin, one per line, instead of from
+  /// `pathspecs`
+  pub fn stdin(mut self) -> Self {
+    self.stdin = true;
+    self
+  }
+
+  /// Also print the paths that aren't ignored, with an empty source, line
+  /// number and pattern. This only has an effect along with `verbose`.
+  pub fn non_matching(mut self) -> Self {
+    self.non_matching = true;
+    self
+  }
+
+  /// Don't look in the index when checking the paths, so that tracked files
+  /// are reported if they match an ignore pattern too
+  pub fn no_index(mut self) -> Self {
+    self.no_index = true;
+    self
+  }
+
+  /// Don't print anything, only set the exit status. This can't be combined
+  /// with `verbose`.
+  pub fn quiet(mut self) -> Self {
+    self.quiet = true;
+    self
+  }
+
+  /// Separate the paths read with `stdin` and the output by NUL bytes instead
+  /// of newlines
+  pub fn stdin_null(mut self) -> Self {
+    self.stdin_null = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("check-ignore");
+
+    if self.verbose {
+      args.arg("--verbose");
+    }
+    if self.stdin {
+      args.arg("--stdin");
+    }
+    if self.stdin_null {
+      args.arg("-z");
+    }
+    if self.non_matching {
+      args.arg("--non-matching");
+    }
+    if self.no_index {
+      args.arg("--no-index");
+    }
+    if self.quiet {
+      args.arg("--quiet");
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitCheckIgnoreBuilder);
+
+/// A single path printed by `git check-ignore --verbose`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitCheckIgnoreEntry {
+  /// The pattern that matched the path, or an empty string if nothing did
+  pub pattern: String,
+  /// The exclude file the pattern is from e.g. `.gitignore`, or `None` if
+  /// nothing matched the path
+  pub source_file: Option<PathBuf>,
+  /// The line of `source_file` the pattern is on, or `None` if nothing matched
+  /// the path
+  pub line_number: Option<u32>,
+  /// The path that was checked
+  pub path: PathBuf,
+}
+
+impl GitCheckIgnoreEntry {
+  /// Parse the output of `git check-ignore --verbose` into one entry per path,
+  /// in the order git listed them. Paths that didn't match anything are only
+  /// printed with `non_matching`. This doesn't understand the NUL separated
+  /// output of `stdin_null`, and unusual paths are left quoted the way git
+  /// prints them.
+  pub fn parse_verbose_output(bytes: &[u8]) -> Result<Vec<Self>, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    output
+      .lines()
+      .filter(|line| !line.is_empty())
+      .map(|line| {
+        let (source, path) = line
+          .split_once('\t')
+          .ok_or_else(|| ParseError::new(format!("missing path in line: {}", line)))?;
+        let fields: Vec<&str> = source.splitn(3, ':').collect();
+        if fields.len() != 3 {
+          return Err(ParseError::new(format!(
+            "missing pattern in line: {}",
+            line
+          )));
+        }
+        let line_number = match fields[1] {
+          "" => None,
+          number => Some(
+            number
+              .parse::<u32>()
+              .map_err(|e| ParseError::new(format!("invalid line number {}: {}", number, e)))?,
+          ),
+        };
+        Ok(GitCheckIgnoreEntry {
+          pattern: fields[2].into(),
+          source_file: Some(fields[0])
+            .filter(|source_file| !source_file.is_empty())
+            .map(Into::into),
+          line_number,
+          path: path.into(),
+        })
+      })
+      .collect()
+  }
+}
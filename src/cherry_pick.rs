@@ -0,0 +1,212 @@
+use crate::{impl_builder, Args, GitBuilder, MergeStrategy};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git cherry-pick` command created by calling
+/// `Git::cherry_pick()`.
+///
+/// `abort`, `continue_`, `quit`, and `skip` control a cherry-pick that is
+/// already in progress (e.g. one that stopped because of a conflict) and are
+/// meant to be used on their own without `commits` or any of the other
+/// options.
+#[derive(Clone)]
+pub struct GitCherryPickBuilder {
+  git: GitBuilder,
+  commits: Vec<String>,
+  no_commit: bool,
+  edit: bool,
+  signoff: bool,
+  mainline: Option<u32>,
+  allow_empty: bool,
+  allow_empty_message: bool,
+  keep_redundant_commits: bool,
+  strategy: Option<MergeStrategy>,
+  strategy_options: Vec<String>,
+  sequencer: Option<Sequencer>,
+}
+
+/// The subcommands for a cherry-pick that is in progress
+#[derive(Clone)]
+enum Sequencer {
+  Abort,
+  Continue,
+  Quit,
+  Skip,
+}
+
+impl GitCherryPickBuilder {
+  /// Internal function used by `Git`. `Git::cherry_pick()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitCherryPickBuilder {
+      git,
+      commits: Vec::new(),
+      no_commit: false,
+      edit: false,
+      signoff: false,
+      mainline: None,
+      allow_empty: false,
+      allow_empty_message: false,
+      keep_redundant_commits: false,
+      strategy: None,
+      strategy_options: Vec::new(),
+      sequencer: None,
+    }
+  }
+
+  /// The commits to cherry-pick. These can be single commits like a SHA or a
+  /// branch name, or ranges like `"A..B"`. This can be called multiple times
+  /// to add more commits.
+  pub fn commits(mut self, commits: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.commits.extend(commits.into_iter().map(Into::into));
+    self
+  }
+
+  /// Apply the changes of the commits to the working tree and the index, but
+  /// don't make any commits
+  pub fn no_commit(mut self) -> Self {
+    self.no_commit = true;
+    self
+  }
+
+  /// Let you edit the commit message prior to committing
+  pub fn edit(mut self) -> Self {
+    self.edit = true;
+    self
+  }
+
+  /// Add a `Signed-off-by` trailer at the end of the commit message
+  pub fn signoff(mut self) -> Self {
+    self.signoff = true;
+    self
+  }
+
+  /// Usually you cannot cherry-pick a merge because you do not know which side
+  /// of the merge should be considered the mainline. This option specifies
+  /// the parent number (starting from 1) of the mainline and allows
+  /// cherry-pick to replay the change relative to the specified parent.
+  pub fn mainline(mut self, parent: u32) -> Self {
+    self.mainline = Some(parent);
+    self
+  }
+
+  /// Allow commits that don't change anything to be cherry-picked
+  pub fn allow_empty(mut self) -> Self {
+    self.allow_empty = true;
+    self
+  }
+
+  /// Allow commits with empty messages to be cherry-picked
+  pub fn allow_empty_message(mut self) -> Self {
+    self.allow_empty_message = true;
+    self
+  }
+
+  /// Keep commits that become empty because their changes are already in the
+  /// current history instead of failing. Implies `allow_empty`.
+  pub fn keep_redundant_commits(mut self) -> Self {
+    self.keep_redundant_commits = true;
+    self
+  }
+
+  /// Use the given merge strategy. See `MergeStrategy` for the available
+  /// strategies.
+  pub fn strategy(mut self, strategy: MergeStrategy) -> Self {
+    self.strategy = Some(strategy);
+    self
+  }
+
+  /// Pass an option specific to the merge strategy, e.g. `"ours"` or
+  /// `"theirs"` to resolve conflicting hunks in favor of one side. This can be
+  /// called multiple times to pass more options.
+  pub fn strategy_option(mut self, option: impl Into<String>) -> Self {
+    self.strategy_options.push(option.into());
+    self
+  }
+
+  /// Cancel the operation and return to the pre-sequence state
+  pub fn abort(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Abort);
+    self
+  }
+
+  /// Continue the operation in progress after resolving a conflict
+  pub fn continue_(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Continue);
+    self
+  }
+
+  /// Forget about the current operation in progress. Can be used to clear the
+  /// sequencer state after a failed cherry-pick.
+  pub fn quit(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Quit);
+    self
+  }
+
+  /// Skip the current commit and continue with the rest of the sequence
+  pub fn skip(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Skip);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("cherry-pick");
+
+    if let Some(sequencer) = self.sequencer {
+      let arg = match sequencer {
+        Sequencer::Abort => "--abort",
+        Sequencer::Continue => "--continue",
+        Sequencer::Quit => "--quit",
+        Sequencer::Skip => "--skip",
+      };
+      args.arg(arg);
+    }
+    if self.no_commit {
+      args.arg("--no-commit");
+    }
+    if self.edit {
+      args.arg("--edit");
+    }
+    if self.signoff {
+      args.arg("--signoff");
+    }
+    if let Some(parent) = self.mainline {
+      args.arg(format!("--mainline={}", parent));
+    }
+    if self.allow_empty {
+      args.arg("--allow-empty");
+    }
+    if self.allow_empty_message {
+      args.arg("--allow-empty-message");
+    }
+    if self.keep_redundant_commits {
+      args.arg("--keep-redundant-commits");
+    }
+    if let Some(strategy) = self.strategy {
+      args.arg(format!("--strategy={}", strategy.to_flag_value()));
+    }
+    for option in self.strategy_options {
+      args.arg(format!("--strategy-option={}", option));
+    }
+    args.args(self.commits);
+    args
+  }
+}
+
+impl_builder!(GitCherryPickBuilder);
@@ -0,0 +1,130 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git clean` command created by calling `Git::clean()`.
+///
+/// Unless the `clean.requireForce` config variable is set to `false`, git
+/// refuses to delete anything unless `force`, `dry_run`, or `interactive` is
+/// used.
+#[derive(Clone)]
+pub struct GitCleanBuilder {
+  git: GitBuilder,
+  force: bool,
+  dry_run: bool,
+  directories: bool,
+  quiet: bool,
+  interactive: bool,
+  excludes: Vec<String>,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitCleanBuilder {
+  /// Internal function used by `Git`. `Git::clean()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitCleanBuilder {
+      git,
+      force: false,
+      dry_run: false,
+      directories: false,
+      quiet: false,
+      interactive: false,
+      excludes: Vec::new(),
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// Actually delete the untracked files
+  pub fn force(mut self) -> Self {
+    self.force = true;
+    self
+  }
+
+  /// Don't actually remove anything, just show what would be done
+  pub fn dry_run(mut self) -> Self {
+    self.dry_run = true;
+    self
+  }
+
+  /// Recurse into untracked directories as well as removing untracked files
+  pub fn directories(mut self) -> Self {
+    self.directories = true;
+    self
+  }
+
+  /// Only report errors, but not the files that are successfully removed
+  pub fn quiet(mut self) -> Self {
+    self.quiet = true;
+    self
+  }
+
+  /// Show what would be done and clean files interactively
+  pub fn interactive(mut self) -> Self {
+    self.interactive = true;
+    self
+  }
+
+  /// Don't remove files matching the given pattern, in addition to those
+  /// matched by the standard ignore rules. This can be called multiple times
+  /// to add more patterns.
+  pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+    self.excludes.push(pattern.into());
+    self
+  }
+
+  /// Only clean the given paths. This can be called multiple times to add more
+  /// paths. The paths are placed after a `--` so that they are never confused
+  /// with options.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("clean");
+
+    if self.force {
+      args.arg("--force");
+    }
+    if self.dry_run {
+      args.arg("--dry-run");
+    }
+    if self.directories {
+      args.arg("-d");
+    }
+    if self.quiet {
+      args.arg("--quiet");
+    }
+    if self.interactive {
+      args.arg("--interactive");
+    }
+    for pattern in self.excludes {
+      args.arg(format!("--exclude={}", pattern));
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitCleanBuilder);
@@ -0,0 +1,181 @@
+use crate::{impl_builder, stdout_hash, Args, GitBuilder, GitError, IncompleteArgs};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git commit-tree` command created by calling
+/// `Git::commit_tree()`. This creates a commit object for an existing tree
+/// without touching the index, the working tree, or any refs. A tree must be
+/// set with `tree` before calling `make_cmd`.
+#[derive(Clone)]
+pub struct GitCommitTreeBuilder {
+  git: GitBuilder,
+  tree: Option<String>,
+  parents: Vec<String>,
+  messages: Vec<String>,
+  message_files: Vec<PathBuf>,
+  gpg_sign: Option<GpgSign>,
+}
+
+/// Whether to sign the commit, as set by `gpg_sign` and `no_gpg_sign`
+#[derive(Clone)]
+enum GpgSign {
+  Sign(Option<String>),
+  NoSign,
+}
+
+impl GitCommitTreeBuilder {
+  /// Internal function used by `Git`. `Git::commit_tree()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitCommitTreeBuilder {
+      git,
+      tree: None,
+      parents: Vec::new(),
+      messages: Vec::new(),
+      message_files: Vec::new(),
+      gpg_sign: None,
+    }
+  }
+
+  /// The tree object the commit records, e.g. the output of `git write-tree`
+  pub fn tree(mut self, tree: impl Into<String>) -> Self {
+    self.tree = Some(tree.into());
+    self
+  }
+
+  /// Add a parent commit. Without any parents this creates a root commit, and
+  /// calling this more than once creates a merge commit with the parents in
+  /// the given order.
+  pub fn parent(mut self, commit: impl Into<String>) -> Self {
+    self.parents.push(commit.into());
+    self
+  }
+
+  /// The commit message. This can be called multiple times, and each message
+  /// becomes its own paragraph. Without a message or `message_file` git reads
+  /// the message from stdin.
+  pub fn message(mut self, message: impl Into<String>) -> Self {
+    self.messages.push(message.into());
+    self
+  }
+
+  /// Read the commit message from the given file, or from stdin with a path
+  /// of `-`. Like `message` this can be called multiple times.
+  pub fn message_file(mut self, path: impl Into<PathBuf>) -> Self {
+    self.message_files.push(path.into());
+    self
+  }
+
+  /// GPG-sign the commit with the given key, or with the committer's default
+  /// key if `None`
+  pub fn gpg_sign(mut self, key_id: Option<String>) -> Self {
+    self.gpg_sign = Some(GpgSign::Sign(key_id));
+    self
+  }
+
+  /// Don't GPG-sign the commit, even if `commit.gpgSign` is set
+  pub fn no_gpg_sign(mut self) -> Self {
+    self.gpg_sign = Some(GpgSign::NoSign);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no tree was set with `tree`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no tree was set with `tree`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("commit-tree");
+
+    let tree = match self.tree {
+      Some(tree) => tree,
+      None => {
+        return Err(args.incomplete(
+          "<tree?>",
+          "a tree must be set with `tree` before calling `make_cmd`",
+        ))
+      }
+    };
+    for parent in self.parents {
+      args.arg("-p");
+      args.arg(parent);
+    }
+    for message in self.messages {
+      args.arg("-m");
+      args.arg(message);
+    }
+    for path in self.message_files {
+      args.arg("-F");
+      args.arg(path);
+    }
+    match self.gpg_sign {
+      Some(GpgSign::Sign(Some(key_id))) => {
+        args.arg(format!("--gpg-sign={}", key_id));
+      }
+      Some(GpgSign::Sign(None)) => {
+        args.arg("--gpg-sign");
+      }
+      Some(GpgSign::NoSign) => {
+        args.arg("--no-gpg-sign");
+      }
+      None => {}
+    }
+    args.arg(tree);
+    Ok(args)
+  }
+
+  /// Run the command, waiting for it to finish and returning the hash of the
+  /// new commit. This returns an error if git couldn't be run or didn't exit
+  /// successfully, see `GitError` for the details.
+  ///
+  /// # Panics
+  /// This will panic if no tree was set with `tree`
+  pub fn execute(self) -> Result<String, GitError> {
+    let output = GitError::execute(self.make_cmd())?;
+    Ok(stdout_hash(&output))
+  }
+
+  /// Like `execute`, but runs git with `tokio` so that waiting for it doesn't
+  /// block the async runtime. This requires the `async` feature.
+  ///
+  /// # Panics
+  /// This will panic if no tree was set with `tree`
+  #[cfg(feature = "async")]
+  pub async fn execute_async(self) -> Result<String, GitError> {
+    let output = GitError::execute_async(self.make_cmd()).await?;
+    Ok(stdout_hash(&output))
+  }
+}
+
+impl_builder!(
+  GitCommitTreeBuilder,
+  panics = "This will panic if no tree was set with `tree`",
+  without_execute
+);
@@ -0,0 +1,344 @@
+use crate::{impl_builder, Args, GitBuilder, GitError, IncompleteArgs, ParseError};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git config` command created by calling `Git::config()`.
+/// An operation such as `get` or `set` must be chosen before calling
+/// `make_cmd`. If more than one is called the last one is used.
+#[derive(Clone)]
+pub struct GitConfigBuilder {
+  git: GitBuilder,
+  scope: Option<ConfigScope>,
+  value_type: Option<ConfigType>,
+  legacy_type: Option<&'static str>,
+  null_terminated: bool,
+  op: Option<ConfigOp>,
+}
+
+/// The operations `GitConfigBuilder` can run
+#[derive(Clone)]
+enum ConfigOp {
+  Get(String),
+  Set { key: String, value: String },
+  Unset(String),
+  UnsetAll(String),
+  Add { key: String, value: String },
+  List,
+  Edit,
+}
+
+impl GitConfigBuilder {
+  /// Internal function used by `Git`. `Git::config()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitConfigBuilder {
+      git,
+      scope: None,
+      value_type: None,
+      legacy_type: None,
+      null_terminated: false,
+      op: None,
+    }
+  }
+
+  /// Which config file to read from or write to. When not set, values are
+  /// read from all config files and written to the repository's own one. See
+  /// `ConfigScope` for the options.
+  pub fn scope(mut self, scope: ConfigScope) -> Self {
+    self.scope = Some(scope);
+    self
+  }
+
+  /// Make sure values being read or written are of the given type, and
+  /// normalize them into its canonical form. See `ConfigType` for the
+  /// options.
+  pub fn value_type(mut self, value_type: ConfigType) -> Self {
+    self.value_type = Some(value_type);
+    self.legacy_type = None;
+    self
+  }
+
+  /// Like `value_type` with `ConfigType::Bool`, but passes the older `--bool`
+  /// option which works with versions of git before 2.18 that don't support
+  /// `--type`
+  pub fn bool_type(self) -> Self {
+    self.legacy_type("--bool")
+  }
+
+  /// Like `value_type` with `ConfigType::Int`, but passes the older `--int`
+  /// option which works with versions of git before 2.18 that don't support
+  /// `--type`
+  pub fn int_type(self) -> Self {
+    self.legacy_type("--int")
+  }
+
+  /// Like `value_type` with `ConfigType::Path`, but passes the older `--path`
+  /// option which works with versions of git before 2.18 that don't support
+  /// `--type`
+  pub fn path_type(self) -> Self {
+    self.legacy_type("--path")
+  }
+
+  /// Use one of the options that came before `--type`, replacing any type set
+  /// with `value_type`
+  fn legacy_type(mut self, flag: &'static str) -> Self {
+    self.value_type = None;
+    self.legacy_type = Some(flag);
+    self
+  }
+
+  /// Get the value for the given key. Equivalent to `git config --get <key>`
+  pub fn get(mut self, key: impl Into<String>) -> Self {
+    self.op = Some(ConfigOp::Get(key.into()));
+    self
+  }
+
+  /// Set the given key to the given value, replacing any existing value.
+  /// Equivalent to `git config <key> <value>`
+  pub fn set(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+    self.op = Some(ConfigOp::Set {
+      key: key.into(),
+      value: value.into(),
+    });
+    self
+  }
+
+  /// Remove the given key. Equivalent to `git config --unset <key>`
+  pub fn unset(mut self, key: impl Into<String>) -> Self {
+    self.op = Some(ConfigOp::Unset(key.into()));
+    self
+  }
+
+  /// Remove all the values of a multi-valued key. Equivalent to `git config
+  /// --unset-all <key>`
+  pub fn unset_all(mut self, key: impl Into<String>) -> Self {
+    self.op = Some(ConfigOp::UnsetAll(key.into()));
+    self
+  }
+
+  /// Add a new value to the given key without replacing any existing ones.
+  /// Equivalent to `git config --add <key> <value>`
+  pub fn add(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+    self.op = Some(ConfigOp::Add {
+      key: key.into(),
+      value: value.into(),
+    });
+    self
+  }
+
+  /// List all variables set in the config file(s), along with their values.
+  /// Equivalent to `git config --list`
+  pub fn list(mut self) -> Self {
+    self.op = Some(ConfigOp::List);
+    self
+  }
+
+  /// Open an editor to modify the config file. Equivalent to `git config
+  /// --edit`
+  pub fn edit(mut self) -> Self {
+    self.op = Some(ConfigOp::Edit);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("config");
+
+    if let Some(scope) = self.scope {
+      match scope {
+        ConfigScope::Local => {
+          args.arg("--local");
+        }
+        ConfigScope::Global => {
+          args.arg("--global");
+        }
+        ConfigScope::System => {
+          args.arg("--system");
+        }
+        ConfigScope::Worktree => {
+          args.arg("--worktree");
+        }
+        ConfigScope::File(path) => {
+          args.arg("--file");
+          args.arg(path);
+        }
+      }
+    }
+    if let Some(value_type) = self.value_type {
+      let arg = match value_type {
+        ConfigType::Bool => "bool",
+        ConfigType::Int => "int",
+        ConfigType::Bool01 => "bool-or-int",
+        ConfigType::Path => "path",
+        ConfigType::ExpiryDate => "expiry-date",
+        ConfigType::Color => "color",
+      };
+      args.arg(format!("--type={}", arg));
+    }
+    if let Some(flag) = self.legacy_type {
+      args.arg(flag);
+    }
+    if self.null_terminated {
+      args.arg("--null");
+    }
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      ConfigOp::Get(key) => {
+        args.arg("--get");
+        args.arg(key);
+      }
+      ConfigOp::Set { key, value } => {
+        args.arg(key);
+        args.arg(value);
+      }
+      ConfigOp::Unset(key) => {
+        args.arg("--unset");
+        args.arg(key);
+      }
+      ConfigOp::UnsetAll(key) => {
+        args.arg("--unset-all");
+        args.arg(key);
+      }
+      ConfigOp::Add { key, value } => {
+        args.arg("--add");
+        args.arg(key);
+        args.arg(value);
+      }
+      ConfigOp::List => {
+        args.arg("--list");
+      }
+      ConfigOp::Edit => {
+        args.arg("--edit");
+      }
+    }
+    Ok(args)
+  }
+
+  /// List the variables and parse them into a `GitConfigEntry` each, in the
+  /// order git listed them. This always runs `git config --list --null`,
+  /// replacing any operation that was set, so values containing newlines are
+  /// kept intact.
+  pub fn entries(mut self) -> Result<Vec<GitConfigEntry>, GitError> {
+    self.op = Some(ConfigOp::List);
+    self.null_terminated = true;
+    let output = self.execute()?;
+    GitConfigEntry::parse_list_output_nul(&output.stdout).map_err(GitError::ParseError)
+  }
+}
+
+impl_builder!(
+  GitConfigBuilder,
+  panics = "This will panic if no operation was set"
+);
+
+/// Options for the `scope` function
+#[derive(Clone)]
+pub enum ConfigScope {
+  /// The repository's `.git/config` file
+  Local,
+  /// The user's `~/.gitconfig` file
+  Global,
+  /// The system wide `$(prefix)/etc/gitconfig` file
+  System,
+  /// The `.git/config.worktree` file if `extensions.worktreeConfig` is
+  /// enabled, otherwise the same as `Local`
+  Worktree,
+  /// Any other config file
+  File(PathBuf),
+}
+
+/// Options for the `value_type` function
+#[derive(Clone)]
+pub enum ConfigType {
+  /// The value is normalized to either `true` or `false`
+  Bool,
+  /// The value is a decimal number with an optional suffix of `k`, `m`, or `g`
+  /// that multiplies it by 1024, 1024^2, or 1024^3
+  Int,
+  /// The value is either a `Bool` or an `Int` and is normalized accordingly.
+  /// This is `--type=bool-or-int`.
+  Bool01,
+  /// The value is a path and a leading `~` is expanded to the user's home
+  /// directory
+  Path,
+  /// The value is a fixed or relative date string which is converted to a
+  /// timestamp
+  ExpiryDate,
+  /// The value is a color which is converted to an ANSI color escape sequence
+  Color,
+}
+
+/// A single variable listed by `git config --list`. Multi-valued keys have an
+/// entry for each of their values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitConfigEntry {
+  /// The name of the variable e.g. `user.name`. Git lowercases the section
+  /// and the variable name, but not the subsection.
+  pub key: String,
+  /// The value of the variable. This is empty for a key set without a value,
+  /// which git treats as `true`.
+  pub value: String,
+}
+
+impl GitConfigEntry {
+  /// Parse the output of `git config --list --null`, where each entry is the
+  /// key, a newline, and the value, ended by a NUL byte
+  pub fn parse_list_output_nul(bytes: &[u8]) -> Result<Vec<Self>, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    output
+      .split_terminator('\0')
+      .map(|entry| {
+        let (key, value) = entry.split_once('\n').unwrap_or((entry, ""));
+        if key.is_empty() {
+          return Err(ParseError::new(format!("missing key in entry: {}", entry)));
+        }
+        Ok(GitConfigEntry {
+          key: key.into(),
+          value: value.into(),
+        })
+      })
+      .collect()
+  }
+}
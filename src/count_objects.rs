@@ -0,0 +1,130 @@
+use crate::{impl_builder, Args, GitBuilder, ParseError};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git count-objects` command created by calling
+/// `Git::count_objects()`. This reports how many loose objects there are and
+/// how much disk space they use. The output of `verbose` can be fed to
+/// `GitCountObjectsOutput::parse`.
+#[derive(Clone)]
+pub struct GitCountObjectsBuilder {
+  git: GitBuilder,
+  verbose: bool,
+  human_readable: bool,
+}
+
+impl GitCountObjectsBuilder {
+  /// Internal function used by `Git`. `Git::count_objects()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitCountObjectsBuilder {
+      git,
+      verbose: false,
+      human_readable: false,
+    }
+  }
+
+  /// Also report the objects in packs, the number of packs, and any garbage
+  /// files in the object directory, one `key: value` pair per line
+  pub fn verbose(mut self) -> Self {
+    self.verbose = true;
+    self
+  }
+
+  /// Print sizes in human readable units such as `KiB` instead of kilobytes
+  pub fn human_readable(mut self) -> Self {
+    self.human_readable = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("count-objects");
+
+    if self.verbose {
+      args.arg("-v");
+    }
+    if self.human_readable {
+      args.arg("-H");
+    }
+    args
+  }
+}
+
+impl_builder!(GitCountObjectsBuilder);
+
+/// The statistics printed by `git count-objects -v`. Sizes are in kilobytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitCountObjectsOutput {
+  /// The number of loose objects
+  pub count: u64,
+  /// The disk space used by loose objects
+  pub size_kb: u64,
+  /// The number of objects in packs
+  pub in_pack: u64,
+  /// The number of packs
+  pub packs: u64,
+  /// The disk space used by packs
+  pub size_pack_kb: u64,
+  /// The number of loose objects that are also in packs and could be removed
+  /// by `git prune-packed`
+  pub prune_packable: u64,
+  /// The number of files in the object directory that are neither objects
+  /// nor packs
+  pub garbage: u64,
+  /// The disk space used by garbage files
+  pub size_garbage_kb: u64,
+}
+
+impl GitCountObjectsOutput {
+  /// Parse the output of `git count-objects -v`. The sizes can't be parsed
+  /// when `human_readable` is set, since git rounds them and adds units.
+  pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    let mut values = Vec::new();
+    for line in output.lines().filter(|line| !line.is_empty()) {
+      let (key, value) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::new(format!("invalid line: {}", line)))?;
+      let value = value
+        .parse::<u64>()
+        .map_err(|e| ParseError::new(format!("invalid value in line {}: {}", line, e)))?;
+      values.push((key, value));
+    }
+    let get = |key| {
+      values
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, value)| *value)
+        .ok_or_else(|| ParseError::new(format!("missing {} in output", key)))
+    };
+
+    Ok(GitCountObjectsOutput {
+      count: get("count")?,
+      size_kb: get("size")?,
+      in_pack: get("in-pack")?,
+      packs: get("packs")?,
+      size_pack_kb: get("size-pack")?,
+      prune_packable: get("prune-packable")?,
+      garbage: get("garbage")?,
+      size_garbage_kb: get("size-garbage")?,
+    })
+  }
+}
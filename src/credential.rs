@@ -0,0 +1,234 @@
+use crate::{impl_builder, Args, GitBuilder, GitError, IncompleteArgs, ParseError};
+use std::ffi::OsString;
+use std::process::{Command, Output};
+
+/// The builder for the `git credential` command created by calling
+/// `Git::credential()`, which asks the configured credential helpers for a
+/// credential or tells them whether one worked. An operation must be chosen
+/// with `op` before calling `make_cmd`.
+///
+/// Git reads the credential from stdin, which `execute` writes for you from
+/// the values set on the builder. The commands created by `make_cmd` and
+/// `make_child` don't get any input, so `Credential::to_input_format` has to
+/// be written to their stdin by hand.
+#[derive(Clone)]
+pub struct GitCredentialBuilder {
+  git: GitBuilder,
+  op: Option<CredentialOp>,
+  credential: Credential,
+}
+
+impl GitCredentialBuilder {
+  /// Internal function used by `Git`. `Git::credential()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitCredentialBuilder {
+      git,
+      op: None,
+      credential: Credential::default(),
+    }
+  }
+
+  /// Choose which `git credential` subcommand to run. See `CredentialOp` for
+  /// what each of them do. Calling this again replaces the previously set
+  /// operation.
+  pub fn op(mut self, op: CredentialOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// Replace all of the values passed to git with the given credential, e.g.
+  /// one returned by `CredentialOp::Fill` to approve or reject it afterwards
+  pub fn credential(mut self, credential: Credential) -> Self {
+    self.credential = credential;
+    self
+  }
+
+  /// The protocol the credential is for, e.g. `https`
+  pub fn protocol(mut self, protocol: impl Into<String>) -> Self {
+    self.credential.protocol = Some(protocol.into());
+    self
+  }
+
+  /// The host the credential is for, including the port if there is one
+  pub fn host(mut self, host: impl Into<String>) -> Self {
+    self.credential.host = Some(host.into());
+    self
+  }
+
+  /// The username of the credential
+  pub fn username(mut self, username: impl Into<String>) -> Self {
+    self.credential.username = Some(username.into());
+    self
+  }
+
+  /// The password of the credential
+  pub fn password(mut self, password: impl Into<String>) -> Self {
+    self.credential.password = Some(password.into());
+    self
+  }
+
+  /// The path on the host the credential is for. Most helpers ignore this
+  /// unless `credential.useHttpPath` is set.
+  pub fn path(mut self, path: impl Into<String>) -> Self {
+    self.credential.path = Some(path.into());
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("credential");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    args.arg(match op {
+      CredentialOp::Fill => "fill",
+      CredentialOp::Approve => "approve",
+      CredentialOp::Reject => "reject",
+    });
+    Ok(args)
+  }
+
+  /// Run the command with the credential written to its stdin, waiting for it
+  /// to finish and collecting its output. This returns an error if git
+  /// couldn't be run or didn't exit successfully, see `GitError` for the
+  /// details.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn execute(self) -> Result<Output, GitError> {
+    let input = self.credential.to_input_format();
+    GitError::execute_with_input(self.make_cmd(), input.as_bytes())
+  }
+
+  /// Like `execute`, but runs git with `tokio` so that waiting for it doesn't
+  /// block the async runtime. This requires the `async` feature.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  #[cfg(feature = "async")]
+  pub async fn execute_async(self) -> Result<Output, GitError> {
+    let input = self.credential.to_input_format();
+    GitError::execute_async_with_input(self.make_cmd(), input.as_bytes()).await
+  }
+}
+
+impl_builder!(
+  GitCredentialBuilder,
+  panics = "This will panic if no operation was set with `op`",
+  without_execute
+);
+
+/// The operations that can be passed to `GitCredentialBuilder::op`
+#[derive(Clone)]
+pub enum CredentialOp {
+  /// Ask the credential helpers for the missing parts of the credential, which
+  /// are printed to stdout and can be parsed with `Credential::from_output`.
+  /// Git prompts for them in the terminal if no helper has them.
+  Fill,
+  /// Tell the credential helpers that the credential worked, so that they can
+  /// store it
+  Approve,
+  /// Tell the credential helpers that the credential didn't work, so that they
+  /// can forget it
+  Reject,
+}
+
+/// A credential as passed to and printed by `git credential`. Any values that
+/// are `None` are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Credential {
+  /// The protocol the credential is for, e.g. `https`
+  pub protocol: Option<String>,
+  /// The host the credential is for, including the port if there is one
+  pub host: Option<String>,
+  /// The path on the host the credential is for
+  pub path: Option<String>,
+  /// The username of the credential
+  pub username: Option<String>,
+  /// The password of the credential
+  pub password: Option<String>,
+}
+
+impl Credential {
+  /// Format the credential the way `git credential` reads it from stdin, with
+  /// one `key=value` line per value that is set. Git refuses values that
+  /// contain a newline.
+  pub fn to_input_format(&self) -> String {
+    let values = [
+      ("protocol", &self.protocol),
+      ("host", &self.host),
+      ("path", &self.path),
+      ("username", &self.username),
+      ("password", &self.password),
+    ];
+    let mut input = String::new();
+    for (key, value) in values.iter() {
+      if let Some(value) = value {
+        input.push_str(&format!("{}={}\n", key, value));
+      }
+    }
+    input
+  }
+
+  /// Parse the output of `git credential fill`. Keys this crate doesn't know
+  /// about, like `password_expiry_utc`, are skipped.
+  pub fn from_output(bytes: &[u8]) -> Result<Credential, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    let mut credential = Credential::default();
+    for line in output.lines().filter(|line| !line.is_empty()) {
+      let (key, value) = line
+        .split_once('=')
+        .ok_or_else(|| ParseError::new(format!("missing value in line: {}", line)))?;
+      let field = match key {
+        "protocol" => &mut credential.protocol,
+        "host" => &mut credential.host,
+        "path" => &mut credential.path,
+        "username" => &mut credential.username,
+        "password" => &mut credential.password,
+        _ => continue,
+      };
+      *field = Some(value.into());
+    }
+    Ok(credential)
+  }
+}
@@ -0,0 +1,157 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git describe` command created by calling
+/// `Git::describe()`. By default this finds the most recent annotated tag
+/// reachable from `HEAD`.
+#[derive(Clone)]
+pub struct GitDescribeBuilder {
+  git: GitBuilder,
+  tags: bool,
+  all: bool,
+  long: bool,
+  abbrev: Option<u32>,
+  match_patterns: Vec<String>,
+  excludes: Vec<String>,
+  always: bool,
+  dirty: Option<Option<String>>,
+  commit_ish: Option<String>,
+}
+
+impl GitDescribeBuilder {
+  /// Internal function used by `Git`. `Git::describe()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitDescribeBuilder {
+      git,
+      tags: false,
+      all: false,
+      long: false,
+      abbrev: None,
+      match_patterns: Vec::new(),
+      excludes: Vec::new(),
+      always: false,
+      dirty: None,
+      commit_ish: None,
+    }
+  }
+
+  /// Use any tag, including lightweight ones, instead of only annotated tags
+  pub fn tags(mut self) -> Self {
+    self.tags = true;
+    self
+  }
+
+  /// Use any ref, such as branches and remote-tracking branches, instead of
+  /// only annotated tags
+  pub fn all(mut self) -> Self {
+    self.all = true;
+    self
+  }
+
+  /// Always output the long format `<tag>-<count>-g<hash>`, even when the
+  /// commit matches a tag exactly
+  pub fn long(mut self) -> Self {
+    self.long = true;
+    self
+  }
+
+  /// Use this many hex digits for the abbreviated object name instead of the
+  /// default. Passing 0 suppresses the long format and only shows the tag.
+  pub fn abbrev(mut self, length: u32) -> Self {
+    self.abbrev = Some(length);
+    self
+  }
+
+  /// Only consider tags matching the given glob pattern. This can be called
+  /// multiple times to add more patterns.
+  pub fn match_pattern(mut self, pattern: impl Into<String>) -> Self {
+    self.match_patterns.push(pattern.into());
+    self
+  }
+
+  /// Don't consider tags matching the given glob pattern. This can be called
+  /// multiple times to add more patterns.
+  pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+    self.excludes.push(pattern.into());
+    self
+  }
+
+  /// Show the abbreviated commit object name as a fallback when no tag can be
+  /// found instead of failing
+  pub fn always(mut self) -> Self {
+    self.always = true;
+    self
+  }
+
+  /// Describe the state of the working tree. If it has local modifications the
+  /// given mark is appended, or `-dirty` if `None` is passed.
+  pub fn dirty(mut self, mark: Option<impl Into<String>>) -> Self {
+    self.dirty = Some(mark.map(Into::into));
+    self
+  }
+
+  /// The commit to describe instead of `HEAD`. Can't be used with `dirty`.
+  pub fn commit_ish(mut self, commit_ish: impl Into<String>) -> Self {
+    self.commit_ish = Some(commit_ish.into());
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("describe");
+
+    if self.tags {
+      args.arg("--tags");
+    }
+    if self.all {
+      args.arg("--all");
+    }
+    if self.long {
+      args.arg("--long");
+    }
+    if let Some(length) = self.abbrev {
+      args.arg(format!("--abbrev={}", length));
+    }
+    for pattern in self.match_patterns {
+      args.arg(format!("--match={}", pattern));
+    }
+    for pattern in self.excludes {
+      args.arg(format!("--exclude={}", pattern));
+    }
+    if self.always {
+      args.arg("--always");
+    }
+    match self.dirty {
+      Some(Some(mark)) => {
+        args.arg(format!("--dirty={}", mark));
+      }
+      Some(None) => {
+        args.arg("--dirty");
+      }
+      None => {}
+    }
+    if let Some(commit_ish) = self.commit_ish {
+      args.arg(commit_ish);
+    }
+    args
+  }
+}
+
+impl_builder!(GitDescribeBuilder);
@@ -0,0 +1,331 @@
+use crate::{impl_builder, Args, GitBuilder, GitError, ParseError};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git diff` command created by calling `Git::diff()`
+#[derive(Clone)]
+pub struct GitDiffBuilder {
+  git: GitBuilder,
+  cached: bool,
+  name_only: bool,
+  name_status: bool,
+  stat: bool,
+  numstat: bool,
+  shortstat: bool,
+  unified: Option<u32>,
+  word_diff: Option<WordDiffMode>,
+  word_diff_regex: Option<String>,
+  output_file: Option<PathBuf>,
+  commits: Option<(String, String)>,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitDiffBuilder {
+  /// Internal function used by `Git`. `Git::diff()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitDiffBuilder {
+      git,
+      cached: false,
+      name_only: false,
+      name_status: false,
+      stat: false,
+      numstat: false,
+      shortstat: false,
+      unified: None,
+      word_diff: None,
+      word_diff_regex: None,
+      output_file: None,
+      commits: None,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// Show the changes staged in the index relative to `HEAD` instead of the
+  /// changes in the working tree relative to the index
+  pub fn cached(mut self) -> Self {
+    self.cached = true;
+    self
+  }
+
+  /// Equivalent to `cached`
+  pub fn staged(self) -> Self {
+    self.cached()
+  }
+
+  /// Show only the names of the changed files
+  pub fn name_only(mut self) -> Self {
+    self.name_only = true;
+    self
+  }
+
+  /// Show only the names and the status (added, modified, deleted etc.) of the
+  /// changed files
+  pub fn name_status(mut self) -> Self {
+    self.name_status = true;
+    self
+  }
+
+  /// Generate a diffstat instead of a patch
+  pub fn stat(mut self) -> Self {
+    self.stat = true;
+    self
+  }
+
+  /// Like `stat`, but show the exact number of added and deleted lines of each
+  /// file separated by tabs, without abbreviating the path names
+  pub fn numstat(mut self) -> Self {
+    self.numstat = true;
+    self
+  }
+
+  /// Output only the last line of the diffstat which contains the total number
+  /// of modified files, as well as the number of added and deleted lines
+  pub fn shortstat(mut self) -> Self {
+    self.shortstat = true;
+    self
+  }
+
+  /// Generate diffs with the given number of lines of context instead of the
+  /// usual three
+  pub fn unified(mut self, lines: u32) -> Self {
+    self.unified = Some(lines);
+    self
+  }
+
+  /// Show a word diff, which marks the changed words within each line instead
+  /// of whole changed lines. See `WordDiffMode` for how the changes are shown.
+  pub fn word_diff(mut self, mode: WordDiffMode) -> Self {
+    self.word_diff = Some(mode);
+    self
+  }
+
+  /// A regex matching what counts as a word for `word_diff`, e.g.
+  /// `"[^[:space:]]"` to compare single characters. Git turns on a plain word
+  /// diff when this is set, unless `word_diff` chooses another mode.
+  pub fn word_diff_regex(mut self, pattern: impl Into<String>) -> Self {
+    self.word_diff_regex = Some(pattern.into());
+    self
+  }
+
+  /// Write the diff to the given file instead of stdout
+  pub fn output_file(mut self, path: impl Into<PathBuf>) -> Self {
+    self.output_file = Some(path.into());
+    self
+  }
+
+  /// Show the changes between two arbitrary commits. Using this is equivalent
+  /// to `git diff HEAD~1 HEAD` where `HEAD~1` and `HEAD` are the arguments
+  /// passed to this function.
+  pub fn commits(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+    self.commits = Some((from.into(), to.into()));
+    self
+  }
+
+  /// Limit the diff to the given paths. This can be called multiple times to
+  /// add more paths. The paths are placed after a `--` so that they are never
+  /// confused with ref names.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("diff");
+
+    if self.cached {
+      args.arg("--cached");
+    }
+    if self.name_only {
+      args.arg("--name-only");
+    }
+    if self.name_status {
+      args.arg("--name-status");
+    }
+    if self.stat {
+      args.arg("--stat");
+    }
+    if self.numstat {
+      args.arg("--numstat");
+    }
+    if self.shortstat {
+      args.arg("--shortstat");
+    }
+    if let Some(lines) = self.unified {
+      args.arg(format!("-U{}", lines));
+    }
+    if let Some(mode) = self.word_diff {
+      let mode = match mode {
+        WordDiffMode::Color => "color",
+        WordDiffMode::Plain => "plain",
+        WordDiffMode::Porcelain => "porcelain",
+        WordDiffMode::None => "none",
+      };
+      args.arg(format!("--word-diff={}", mode));
+    }
+    if let Some(pattern) = self.word_diff_regex {
+      args.arg(format!("--word-diff-regex={}", pattern));
+    }
+    if let Some(path) = self.output_file {
+      args.arg("--output");
+      args.arg(path);
+    }
+    if let Some((from, to)) = self.commits {
+      args.arg(from);
+      args.arg(to);
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+
+  /// Run the diff and parse the per-file and total number of added and
+  /// deleted lines out of it. This sets `numstat` and `shortstat`, since the
+  /// graph printed by `stat` is scaled down for large changes and can't be
+  /// turned back into exact counts. Any other output options like `stat`,
+  /// `name_only` or `output_file` are ignored.
+  pub fn stat_entries(mut self) -> Result<GitDiffStat, GitError> {
+    self.stat = false;
+    self.name_only = false;
+    self.name_status = false;
+    self.output_file = None;
+    let output = self.numstat().shortstat().execute()?;
+    GitDiffStat::parse_numstat_output(&output.stdout).map_err(GitError::ParseError)
+  }
+}
+
+impl_builder!(GitDiffBuilder);
+
+/// Options for the `word_diff` function
+#[derive(Clone)]
+pub enum WordDiffMode {
+  /// Highlight the changed words using only colors. This needs colors to be
+  /// enabled, e.g. with `color.diff` or `-c color.ui=always`.
+  Color,
+  /// Show removed words as `[-removed-]` and added words as `{+added+}`
+  Plain,
+  /// A line based format meant for scripts. Each line starts with ` ` for
+  /// unchanged text, `-` for removed words or `+` for added words, and a line
+  /// containing just `~` marks a newline in the input.
+  Porcelain,
+  /// Turn off the word diff again, e.g. when it is enabled in the config
+  None,
+}
+
+/// The number of lines changed by a diff, parsed from the output of `git diff
+/// --numstat --shortstat`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitDiffStat {
+  /// The changes to each file, in the order git listed them
+  pub files: Vec<GitDiffStatEntry>,
+  /// The number of lines added across all files
+  pub total_insertions: u32,
+  /// The number of lines deleted across all files
+  pub total_deletions: u32,
+}
+
+/// The number of lines changed in a single file of a diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitDiffStatEntry {
+  /// The path of the file. Renames are shown the way git prints them, e.g.
+  /// `old => new` or `dir/{old => new}`.
+  pub path: PathBuf,
+  /// The number of lines added. This is 0 for binary files.
+  pub insertions: u32,
+  /// The number of lines deleted. This is 0 for binary files.
+  pub deletions: u32,
+}
+
+impl GitDiffStat {
+  /// Parse the output of `git diff --numstat --shortstat`, which has one line
+  /// per file followed by a summary line such as `2 files changed, 3
+  /// insertions(+), 1 deletion(-)`. Empty output means nothing changed.
+  ///
+  /// The output of plain `--stat` is rejected, since its `+`/`-` graph is
+  /// scaled down for large changes and can't be turned back into exact counts.
+  pub fn parse_numstat_output(bytes: &[u8]) -> Result<Self, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    let mut stat = GitDiffStat {
+      files: Vec::new(),
+      total_insertions: 0,
+      total_deletions: 0,
+    };
+    let mut summary = None;
+    for line in output.lines().filter(|line| !line.is_empty()) {
+      if summary.is_some() {
+        return Err(ParseError::new(format!(
+          "unexpected line after the summary: {}",
+          line
+        )));
+      }
+      if line.starts_with(' ') {
+        summary = Some(line);
+        continue;
+      }
+      let mut fields = line.splitn(3, '\t');
+      let (insertions, deletions, path) = match (fields.next(), fields.next(), fields.next()) {
+        (Some(insertions), Some(deletions), Some(path)) => (insertions, deletions, path),
+        _ => return Err(ParseError::new(format!("invalid numstat line: {}", line))),
+      };
+      stat.files.push(GitDiffStatEntry {
+        path: path.into(),
+        insertions: parse_count(insertions, line)?,
+        deletions: parse_count(deletions, line)?,
+      });
+    }
+
+    if let Some(summary) = summary {
+      for part in summary.trim().split(", ") {
+        let (count, kind) = part
+          .split_once(' ')
+          .ok_or_else(|| ParseError::new(format!("invalid summary line: {}", summary)))?;
+        let count = parse_count(count, summary)?;
+        if kind.starts_with("insertion") {
+          stat.total_insertions = count;
+        } else if kind.starts_with("deletion") {
+          stat.total_deletions = count;
+        } else if !kind.starts_with("file") {
+          return Err(ParseError::new(format!(
+            "invalid summary line: {}",
+            summary
+          )));
+        }
+      }
+    } else if !stat.files.is_empty() {
+      return Err(ParseError::new("missing the summary line"));
+    }
+    Ok(stat)
+  }
+}
+
+/// Parse a line count from `git diff --numstat`, where binary files have a
+/// count of `-`
+fn parse_count(count: &str, line: &str) -> Result<u32, ParseError> {
+  if count == "-" {
+    return Ok(0);
+  }
+  count
+    .parse()
+    .map_err(|e| ParseError::new(format!("invalid count {} in line {}: {}", count, line, e)))
+}
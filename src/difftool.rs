@@ -0,0 +1,159 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git difftool` command created by calling
+/// `Git::difftool()`. This shows the same changes as `git diff`, but opens
+/// each changed file in a diff tool instead of printing a patch.
+#[derive(Clone)]
+pub struct GitDifftoolBuilder {
+  git: GitBuilder,
+  tool: Option<String>,
+  no_prompt: bool,
+  dir_diff: bool,
+  gui: Option<bool>,
+  extcmd: Option<String>,
+  trust_exit_code: bool,
+  commit_range: Option<String>,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitDifftoolBuilder {
+  /// Internal function used by `Git`. `Git::difftool()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitDifftoolBuilder {
+      git,
+      tool: None,
+      no_prompt: false,
+      dir_diff: false,
+      gui: None,
+      extcmd: None,
+      trust_exit_code: false,
+      commit_range: None,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// The diff tool to run, e.g. `"meld"` or the name of a tool configured
+  /// with `difftool.<tool>.cmd`. Defaults to the `diff.tool` config value.
+  pub fn tool(mut self, tool: impl Into<String>) -> Self {
+    self.tool = Some(tool.into());
+    self
+  }
+
+  /// Don't ask before running the diff tool on each file
+  pub fn no_prompt(mut self) -> Self {
+    self.no_prompt = true;
+    self
+  }
+
+  /// Copy the old and new versions of all the changed files into two
+  /// temporary directories and run the diff tool on those once, instead of
+  /// once per file
+  pub fn dir_diff(mut self) -> Self {
+    self.dir_diff = true;
+    self
+  }
+
+  /// Default to the `diff.guitool` config value when `tool` isn't set. This
+  /// and `no_gui` override each other, so whichever one is called last is
+  /// used, just like git does with the flags.
+  pub fn gui(mut self) -> Self {
+    self.gui = Some(true);
+    self
+  }
+
+  /// Default to the `diff.tool` config value when `tool` isn't set, even if
+  /// `difftool.guiDefault` is set. This and `gui` override each other, so
+  /// whichever one is called last is used, just like git does with the flags.
+  pub fn no_gui(mut self) -> Self {
+    self.gui = Some(false);
+    self
+  }
+
+  /// Run the given command on each file instead of a configured tool. It is
+  /// called with the paths of the old and new versions as its arguments.
+  pub fn extcmd(mut self, command: impl Into<String>) -> Self {
+    self.extcmd = Some(command.into());
+    self
+  }
+
+  /// Stop and exit with the diff tool's exit code if it fails, instead of
+  /// carrying on with the next file
+  pub fn trust_exit_code(mut self) -> Self {
+    self.trust_exit_code = true;
+    self
+  }
+
+  /// The commits to compare, e.g. `"HEAD~1"` or `"main..topic"`. By default
+  /// the working tree is compared to the index.
+  pub fn commit_range(mut self, range: impl Into<String>) -> Self {
+    self.commit_range = Some(range.into());
+    self
+  }
+
+  /// Limit the diff to the given paths. This can be called multiple times to
+  /// add more paths. The paths are placed after a `--` so that they are never
+  /// confused with ref names.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("difftool");
+
+    if let Some(tool) = self.tool {
+      args.arg(format!("--tool={}", tool));
+    }
+    if self.no_prompt {
+      args.arg("--no-prompt");
+    }
+    if self.dir_diff {
+      args.arg("--dir-diff");
+    }
+    match self.gui {
+      Some(true) => {
+        args.arg("--gui");
+      }
+      Some(false) => {
+        args.arg("--no-gui");
+      }
+      None => {}
+    }
+    if let Some(command) = self.extcmd {
+      args.arg(format!("--extcmd={}", command));
+    }
+    if self.trust_exit_code {
+      args.arg("--trust-exit-code");
+    }
+    if let Some(range) = self.commit_range {
+      args.arg(range);
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitDifftoolBuilder);
@@ -0,0 +1,197 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// The error returned when the output of a git command could not be parsed
+/// into the types this crate provides
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+  msg: String,
+}
+
+impl ParseError {
+  /// Internal function used by the parsers to create an error with a message
+  /// describing what went wrong
+  pub(crate) fn new(msg: impl Into<String>) -> Self {
+    ParseError { msg: msg.into() }
+  }
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "unable to parse git output: {}", self.msg)
+  }
+}
+
+impl Error for ParseError {}
+
+/// The error returned by `Shared::try_octal` when the permissions are outside
+/// of the range git accepts, 0o000 to 0o777
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidOctal {
+  perm: u16,
+}
+
+impl InvalidOctal {
+  /// Internal function used by `Shared::try_octal` to create an error for the
+  /// given permissions
+  pub(crate) fn new(perm: u16) -> Self {
+    InvalidOctal { perm }
+  }
+
+  /// The permissions that were rejected
+  pub fn perm(&self) -> u16 {
+    self.perm
+  }
+}
+
+impl fmt::Display for InvalidOctal {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "invalid shared permissions 0o{:o}, they must be between 0o000 and 0o777",
+      self.perm
+    )
+  }
+}
+
+impl Error for InvalidOctal {}
+
+/// The error returned by the `execute` function of the builders when git could
+/// not be run or didn't finish successfully
+#[derive(Debug)]
+pub enum GitError {
+  /// Running git failed for a reason other than it not being installed
+  IoError(io::Error),
+  /// Git ran but exited with a non-zero exit code
+  NonZeroExit {
+    /// The exit code of git
+    code: Option<i32>,
+    /// Everything git wrote to stderr, which usually explains what went wrong
+    stderr: Vec<u8>,
+  },
+  /// Git was terminated by a signal before it could exit
+  KilledBySignal {
+    /// Everything git wrote to stderr before it was terminated
+    stderr: Vec<u8>,
+  },
+  /// The `git` executable could not be found on the `PATH`
+  GitNotFound,
+  /// Git succeeded, but its output could not be parsed
+  ParseError(ParseError),
+  /// The permissions given to `GitInitBuilder::shared` are invalid, so git
+  /// wasn't run
+  InvalidOctal(InvalidOctal),
+}
+
+impl GitError {
+  /// Internal function used by the builders to run a command and turn its
+  /// failures into a `GitError`
+  pub(crate) fn execute(mut cmd: Command) -> Result<Output, GitError> {
+    GitError::check(cmd.output())
+  }
+
+  /// Internal function used by the builders to run a command with `tokio` and
+  /// turn its failures into a `GitError`
+  #[cfg(feature = "async")]
+  pub(crate) async fn execute_async(cmd: Command) -> Result<Output, GitError> {
+    GitError::check(tokio::process::Command::from(cmd).output().await)
+  }
+
+  /// Internal function used by the builders to run a command with `input`
+  /// written to its stdin and turn its failures into a `GitError`. The input is
+  /// written before any output is read, so it should be small.
+  pub(crate) fn execute_with_input(mut cmd: Command, input: &[u8]) -> Result<Output, GitError> {
+    let result = cmd
+      .stdin(Stdio::piped())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped())
+      .spawn()
+      .and_then(|mut child| {
+        // Dropping stdin once the input is written closes it, so git sees the
+        // end of the input
+        child.stdin.take().unwrap().write_all(input)?;
+        child.wait_with_output()
+      });
+    GitError::check(result)
+  }
+
+  /// Like `execute_with_input`, but runs the command with `tokio`
+  #[cfg(feature = "async")]
+  pub(crate) async fn execute_async_with_input(
+    cmd: Command,
+    input: &[u8],
+  ) -> Result<Output, GitError> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut cmd = tokio::process::Command::from(cmd);
+    cmd
+      .stdin(Stdio::piped())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped());
+    let result = async {
+      let mut child = cmd.spawn()?;
+      child.stdin.take().unwrap().write_all(input).await?;
+      child.wait_with_output().await
+    }
+    .await;
+    GitError::check(result)
+  }
+
+  /// Turns the result of running git into a `GitError` if it couldn't be run
+  /// or didn't exit successfully
+  fn check(result: io::Result<Output>) -> Result<Output, GitError> {
+    let output = result.map_err(|err| match err.kind() {
+      io::ErrorKind::NotFound => GitError::GitNotFound,
+      _ => GitError::IoError(err),
+    })?;
+    if output.status.success() {
+      return Ok(output);
+    }
+    match output.status.code() {
+      Some(code) => Err(GitError::NonZeroExit {
+        code: Some(code),
+        stderr: output.stderr,
+      }),
+      None => Err(GitError::KilledBySignal {
+        stderr: output.stderr,
+      }),
+    }
+  }
+}
+
+impl fmt::Display for GitError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      GitError::IoError(err) => write!(f, "unable to run git: {}", err),
+      GitError::NonZeroExit { code, stderr } => {
+        match code {
+          Some(code) => write!(f, "git exited with code {}", code)?,
+          None => write!(f, "git exited unsuccessfully")?,
+        }
+        let stderr = String::from_utf8_lossy(stderr);
+        if !stderr.trim().is_empty() {
+          write!(f, ": {}", stderr.trim())?;
+        }
+        Ok(())
+      }
+      GitError::KilledBySignal { .. } => write!(f, "git was killed by a signal"),
+      GitError::GitNotFound => write!(f, "git could not be found on the PATH"),
+      GitError::ParseError(err) => err.fmt(f),
+      GitError::InvalidOctal(err) => err.fmt(f),
+    }
+  }
+}
+
+impl Error for GitError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      GitError::IoError(err) => Some(err),
+      GitError::ParseError(err) => Some(err),
+      GitError::InvalidOctal(err) => Some(err),
+      _ => None,
+    }
+  }
+}
@@ -0,0 +1,63 @@
+/// A filter for partial clones and fetches, which leave some objects out and
+/// only download them when they are needed. Use `to_filter_spec` to get the
+/// value for git's `--filter=<spec>` option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectFilter {
+  /// Leave out all blobs, `blob:none`
+  BlobNone,
+  /// Leave out blobs of at least the given size, e.g. `"1m"` or `"512k"`,
+  /// `blob:limit=<size>`
+  BlobLimit(String),
+  /// Leave out trees and blobs that are deeper than the given depth below the
+  /// root tree, `tree:<depth>`. A depth of 0 leaves out every tree and blob.
+  TreeDepth(u32),
+  /// Only keep the objects of the given type, one of `"blob"`, `"tree"`,
+  /// `"commit"`, or `"tag"`, `object:type=<type>`
+  Object(String),
+  /// Only keep the blobs a sparse checkout with the patterns in the given blob
+  /// would need, e.g. `"main:.sparse"`, `sparse:oid=<blob-ish>`
+  Sparse(String),
+  /// A filter spec passed to git as is, for filters this enum doesn't cover
+  Custom(String),
+  /// Only keep the objects that every one of the given filters keeps,
+  /// `combine:<spec>+<spec>...`
+  Combine(Vec<ObjectFilter>),
+}
+
+impl ObjectFilter {
+  /// The filter spec to pass to git as `--filter=<spec>`
+  pub fn to_filter_spec(&self) -> String {
+    match self {
+      ObjectFilter::BlobNone => "blob:none".into(),
+      ObjectFilter::BlobLimit(size) => format!("blob:limit={}", size),
+      ObjectFilter::TreeDepth(depth) => format!("tree:{}", depth),
+      ObjectFilter::Object(kind) => format!("object:type={}", kind),
+      ObjectFilter::Sparse(oid) => format!("sparse:oid={}", oid),
+      ObjectFilter::Custom(spec) => spec.clone(),
+      ObjectFilter::Combine(filters) => {
+        let specs = filters
+          .iter()
+          .map(|filter| encode_sub_spec(&filter.to_filter_spec()))
+          .collect::<Vec<_>>();
+        format!("combine:{}", specs.join("+"))
+      }
+    }
+  }
+}
+
+/// Percent-encode the characters git reserves in the filters of a `combine:`
+/// spec, so that e.g. `+` in a custom filter isn't taken as a separator
+fn encode_sub_spec(spec: &str) -> String {
+  let mut encoded = String::with_capacity(spec.len());
+  for c in spec.chars() {
+    if c.is_ascii_graphic() && !r#"~`!@#$^&*()[]{}\;'",<>?+%"#.contains(c) {
+      encoded.push(c);
+    } else {
+      let mut buf = [0; 4];
+      for byte in c.encode_utf8(&mut buf).bytes() {
+        encoded.push_str(&format!("%{:02x}", byte));
+      }
+    }
+  }
+  encoded
+}
@@ -0,0 +1,149 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git for-each-ref` command created by calling
+/// `Git::for_each_ref()`. This lists the refs of the repository, or the ones
+/// matching `patterns`, in the format given with `format`.
+#[derive(Clone)]
+pub struct GitForEachRefBuilder {
+  git: GitBuilder,
+  patterns: Vec<String>,
+  format: Option<String>,
+  sort: Vec<String>,
+  count: Option<u32>,
+  points_at: Option<String>,
+  merged: Option<String>,
+  no_merged: Option<String>,
+  contains: Option<String>,
+  no_contains: Option<String>,
+}
+
+impl GitForEachRefBuilder {
+  /// Internal function used by `Git`. `Git::for_each_ref()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitForEachRefBuilder {
+      git,
+      patterns: Vec::new(),
+      format: None,
+      sort: Vec::new(),
+      count: None,
+      points_at: None,
+      merged: None,
+      no_merged: None,
+      contains: None,
+      no_contains: None,
+    }
+  }
+
+  /// Only list the refs matching one of the given patterns, either a prefix
+  /// like `"refs/tags/"` or a glob like `"refs/heads/release-*"`. This can be
+  /// called multiple times to add more patterns.
+  pub fn patterns(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.patterns.extend(patterns.into_iter().map(Into::into));
+    self
+  }
+
+  /// The format to print each ref in, e.g. `"%(refname) %(creatordate)"`. See
+  /// the FIELD NAMES section of `git help for-each-ref` for the fields you can
+  /// use. Defaults to `"%(objectname) %(objecttype)\t%(refname)"`.
+  pub fn format(mut self, format: impl Into<String>) -> Self {
+    self.format = Some(format.into());
+    self
+  }
+
+  /// Sort the refs on the given keys such as `refname` or `-creatordate`.
+  /// Prefix a key with `-` to sort in descending order. Like git, the last key
+  /// is the most significant and the earlier ones break ties. This can be
+  /// called multiple times to add more keys.
+  pub fn sort(mut self, keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.sort.extend(keys.into_iter().map(Into::into));
+    self
+  }
+
+  /// Stop after listing the given number of refs
+  pub fn count(mut self, count: u32) -> Self {
+    self.count = Some(count);
+    self
+  }
+
+  /// Only list refs which point at the given object
+  pub fn points_at(mut self, commit: impl Into<String>) -> Self {
+    self.points_at = Some(commit.into());
+    self
+  }
+
+  /// Only list refs whose tips are reachable from the given commit
+  pub fn merged(mut self, commit: impl Into<String>) -> Self {
+    self.merged = Some(commit.into());
+    self
+  }
+
+  /// Only list refs whose tips are not reachable from the given commit
+  pub fn no_merged(mut self, commit: impl Into<String>) -> Self {
+    self.no_merged = Some(commit.into());
+    self
+  }
+
+  /// Only list refs which contain the given commit
+  pub fn contains(mut self, commit: impl Into<String>) -> Self {
+    self.contains = Some(commit.into());
+    self
+  }
+
+  /// Only list refs which don't contain the given commit
+  pub fn no_contains(mut self, commit: impl Into<String>) -> Self {
+    self.no_contains = Some(commit.into());
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("for-each-ref");
+
+    if let Some(format) = self.format {
+      args.arg(format!("--format={}", format));
+    }
+    for key in self.sort {
+      args.arg(format!("--sort={}", key));
+    }
+    if let Some(count) = self.count {
+      args.arg(format!("--count={}", count));
+    }
+    if let Some(commit) = self.points_at {
+      args.arg(format!("--points-at={}", commit));
+    }
+    if let Some(commit) = self.merged {
+      args.arg(format!("--merged={}", commit));
+    }
+    if let Some(commit) = self.no_merged {
+      args.arg(format!("--no-merged={}", commit));
+    }
+    if let Some(commit) = self.contains {
+      args.arg(format!("--contains={}", commit));
+    }
+    if let Some(commit) = self.no_contains {
+      args.arg(format!("--no-contains={}", commit));
+    }
+    args.args(self.patterns);
+    args
+  }
+}
+
+impl_builder!(GitForEachRefBuilder);
@@ -0,0 +1,212 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git format-patch` command created by calling
+/// `Git::format_patch()`. Each commit in the range is written to its own
+/// `.patch` file, ready to be sent by email.
+#[derive(Clone)]
+pub struct GitFormatPatchBuilder {
+  git: GitBuilder,
+  range: Option<String>,
+  output_directory: Option<PathBuf>,
+  numbering: Option<Numbering>,
+  start_number: Option<u32>,
+  subject_prefix: Option<String>,
+  cover_letter: bool,
+  from: Option<String>,
+  to: Vec<String>,
+  cc: Vec<String>,
+  signoff: bool,
+  attach: bool,
+  base: Option<String>,
+}
+
+/// Whether `[PATCH n/m]` numbering is used in the subject
+#[derive(Clone)]
+enum Numbering {
+  Numbered,
+  NotNumbered,
+}
+
+impl GitFormatPatchBuilder {
+  /// Internal function used by `Git`. `Git::format_patch()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitFormatPatchBuilder {
+      git,
+      range: None,
+      output_directory: None,
+      numbering: None,
+      start_number: None,
+      subject_prefix: None,
+      cover_letter: false,
+      from: None,
+      to: Vec::new(),
+      cc: Vec::new(),
+      signoff: false,
+      attach: false,
+      base: None,
+    }
+  }
+
+  /// The commits to create patches for. A single commit such as `main` means
+  /// every commit since `main`, while a range such as `main..feature` works
+  /// like it does for `git log`.
+  pub fn range(mut self, range: impl Into<String>) -> Self {
+    self.range = Some(range.into());
+    self
+  }
+
+  /// Write the patch files into this directory instead of the current one
+  pub fn output_directory(mut self, path: impl Into<PathBuf>) -> Self {
+    self.output_directory = Some(path.into());
+    self
+  }
+
+  /// Name the output in `[PATCH n/m]` format, even with a single patch. This
+  /// and `no_numbered` override each other, so whichever one is called last is
+  /// used, just like git does with the flags.
+  pub fn numbered(mut self) -> Self {
+    self.numbering = Some(Numbering::Numbered);
+    self
+  }
+
+  /// Name the output in `[PATCH]` format. This and `numbered` override each
+  /// other, so whichever one is called last is used, just like git does with
+  /// the flags.
+  pub fn no_numbered(mut self) -> Self {
+    self.numbering = Some(Numbering::NotNumbered);
+    self
+  }
+
+  /// Start numbering the patches at the given number instead of 1
+  pub fn start_number(mut self, number: u32) -> Self {
+    self.start_number = Some(number);
+    self
+  }
+
+  /// Use the given prefix in the subject line instead of `PATCH` e.g.
+  /// `"PATCH v2"` or `"RFC PATCH"`
+  pub fn subject_prefix(mut self, prefix: impl Into<String>) -> Self {
+    self.subject_prefix = Some(prefix.into());
+    self
+  }
+
+  /// In addition to the patches, generate a cover letter file containing the
+  /// branch description, shortlog and the overall diffstat
+  pub fn cover_letter(mut self) -> Self {
+    self.cover_letter = true;
+    self
+  }
+
+  /// Use the given ident such as `"Name <email>"` in the `From:` header of
+  /// each patch instead of the commit author
+  pub fn from(mut self, ident: impl Into<String>) -> Self {
+    self.from = Some(ident.into());
+    self
+  }
+
+  /// Add `To:` headers to the emails. This can be called multiple times to add
+  /// more recipients.
+  pub fn to(mut self, recipients: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.to.extend(recipients.into_iter().map(Into::into));
+    self
+  }
+
+  /// Add `Cc:` headers to the emails. This can be called multiple times to add
+  /// more recipients.
+  pub fn cc(mut self, recipients: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.cc.extend(recipients.into_iter().map(Into::into));
+    self
+  }
+
+  /// Add a `Signed-off-by` trailer to the commit message, using the committer
+  /// identity of yourself
+  pub fn signoff(mut self) -> Self {
+    self.signoff = true;
+    self
+  }
+
+  /// Create multipart/mixed attachments, the first part of which is the commit
+  /// message and the patch itself in the second part
+  pub fn attach(mut self) -> Self {
+    self.attach = true;
+    self
+  }
+
+  /// Record the base tree information identifying the state the patch series
+  /// applies to. Pass `"auto"` to have git choose it based on the upstream.
+  pub fn base(mut self, commit: impl Into<String>) -> Self {
+    self.base = Some(commit.into());
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("format-patch");
+
+    if let Some(path) = self.output_directory {
+      args.arg("--output-directory");
+      args.arg(path);
+    }
+    match self.numbering {
+      Some(Numbering::Numbered) => {
+        args.arg("--numbered");
+      }
+      Some(Numbering::NotNumbered) => {
+        args.arg("--no-numbered");
+      }
+      None => {}
+    }
+    if let Some(number) = self.start_number {
+      args.arg(format!("--start-number={}", number));
+    }
+    if let Some(prefix) = self.subject_prefix {
+      args.arg(format!("--subject-prefix={}", prefix));
+    }
+    if self.cover_letter {
+      args.arg("--cover-letter");
+    }
+    if let Some(ident) = self.from {
+      args.arg(format!("--from={}", ident));
+    }
+    for recipient in self.to {
+      args.arg(format!("--to={}", recipient));
+    }
+    for recipient in self.cc {
+      args.arg(format!("--cc={}", recipient));
+    }
+    if self.signoff {
+      args.arg("--signoff");
+    }
+    if self.attach {
+      args.arg("--attach");
+    }
+    if let Some(commit) = self.base {
+      args.arg(format!("--base={}", commit));
+    }
+    if let Some(range) = self.range {
+      args.arg(range);
+    }
+    args
+  }
+}
+
+impl_builder!(GitFormatPatchBuilder);
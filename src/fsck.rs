@@ -0,0 +1,178 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git fsck` command created by calling `Git::fsck()`.
+/// This verifies the connectivity and validity of the objects in the
+/// repository, exiting with a non-zero status if it finds any problems.
+#[derive(Clone)]
+pub struct GitFsckBuilder {
+  git: GitBuilder,
+  objects: Vec<String>,
+  full: bool,
+  strict: bool,
+  verbose: bool,
+  lost_found: bool,
+  name_objects: bool,
+  no_reflogs: bool,
+  connectivity_only: bool,
+  dangling: Option<bool>,
+  root: bool,
+}
+
+impl GitFsckBuilder {
+  /// Internal function used by `Git`. `Git::fsck()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitFsckBuilder {
+      git,
+      objects: Vec::new(),
+      full: false,
+      strict: false,
+      verbose: false,
+      lost_found: false,
+      name_objects: false,
+      no_reflogs: false,
+      connectivity_only: false,
+      dangling: None,
+      root: false,
+    }
+  }
+
+  /// Treat the given objects as the heads of the reachability traces instead
+  /// of the index, the refs, and the reflogs. This can be called multiple
+  /// times to add more objects.
+  pub fn objects(mut self, objects: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.objects.extend(objects.into_iter().map(Into::into));
+    self
+  }
+
+  /// Check the objects in packs and alternate object stores too, not just the
+  /// loose objects. This is the default, but is passed explicitly if set.
+  pub fn full(mut self) -> Self {
+    self.full = true;
+    self
+  }
+
+  /// Enable more strict checking, e.g. catching file modes recorded with the
+  /// group write bit set by very old versions of git
+  pub fn strict(mut self) -> Self {
+    self.strict = true;
+    self
+  }
+
+  /// Be chatty
+  pub fn verbose(mut self) -> Self {
+    self.verbose = true;
+    self
+  }
+
+  /// Write dangling objects into `.git/lost-found/commit/` or
+  /// `.git/lost-found/other/` depending on their type. The contents of blobs
+  /// are written out instead of their names.
+  pub fn lost_found(mut self) -> Self {
+    self.lost_found = true;
+    self
+  }
+
+  /// When showing the names of reachable objects, also show how they are
+  /// reachable, e.g. `HEAD~2:src/lib.rs`
+  pub fn name_objects(mut self) -> Self {
+    self.name_objects = true;
+    self
+  }
+
+  /// Don't consider commits that are only referenced by a reflog to be
+  /// reachable
+  pub fn no_reflogs(mut self) -> Self {
+    self.no_reflogs = true;
+    self
+  }
+
+  /// Only check the connectivity of reachable objects, making sure that any
+  /// objects referenced by them exist. This is much faster than a full check
+  /// but doesn't look inside blobs or verify the checksums of objects.
+  pub fn connectivity_only(mut self) -> Self {
+    self.connectivity_only = true;
+    self
+  }
+
+  /// Print objects that exist but aren't reachable from any ref. This is the
+  /// default. This and `no_dangling` override each other, so whichever one is
+  /// called last is used, just like git does with the flags.
+  pub fn dangling(mut self) -> Self {
+    self.dangling = Some(true);
+    self
+  }
+
+  /// Don't print dangling objects. This and `dangling` override each other, so
+  /// whichever one is called last is used, just like git does with the flags.
+  pub fn no_dangling(mut self) -> Self {
+    self.dangling = Some(false);
+    self
+  }
+
+  /// Report root nodes, i.e. commits without any parents
+  pub fn root(mut self) -> Self {
+    self.root = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("fsck");
+
+    if self.full {
+      args.arg("--full");
+    }
+    if self.strict {
+      args.arg("--strict");
+    }
+    if self.verbose {
+      args.arg("--verbose");
+    }
+    if self.lost_found {
+      args.arg("--lost-found");
+    }
+    if self.name_objects {
+      args.arg("--name-objects");
+    }
+    if self.no_reflogs {
+      args.arg("--no-reflogs");
+    }
+    if self.connectivity_only {
+      args.arg("--connectivity-only");
+    }
+    match self.dangling {
+      Some(true) => {
+        args.arg("--dangling");
+      }
+      Some(false) => {
+        args.arg("--no-dangling");
+      }
+      None => {}
+    }
+    if self.root {
+      args.arg("--root");
+    }
+    args.args(self.objects);
+    args
+  }
+}
+
+impl_builder!(GitFsckBuilder);
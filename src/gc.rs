@@ -0,0 +1,135 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git gc` command created by calling `Git::gc()`
+#[derive(Clone)]
+pub struct GitGcBuilder {
+  git: GitBuilder,
+  aggressive: bool,
+  auto: bool,
+  quiet: bool,
+  prune: Option<Prune>,
+  keep_largest_pack: bool,
+  force: bool,
+}
+
+/// Whether loose objects are pruned
+#[derive(Clone)]
+enum Prune {
+  Date(String),
+  Never,
+}
+
+impl GitGcBuilder {
+  /// Internal function used by `Git`. `Git::gc()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitGcBuilder {
+      git,
+      aggressive: false,
+      auto: false,
+      quiet: false,
+      prune: None,
+      keep_largest_pack: false,
+      force: false,
+    }
+  }
+
+  /// More aggressively optimize the repository at the expense of taking much
+  /// more time
+  pub fn aggressive(mut self) -> Self {
+    self.aggressive = true;
+    self
+  }
+
+  /// Check whether any housekeeping is required; if not, exit without
+  /// performing any work
+  pub fn auto(mut self) -> Self {
+    self.auto = true;
+    self
+  }
+
+  /// Suppress all progress reports
+  pub fn quiet(mut self) -> Self {
+    self.quiet = true;
+    self
+  }
+
+  /// Prune loose objects older than the given date such as `"now"` or
+  /// `"1.week.ago"`. The default is two weeks ago. This and `no_prune`
+  /// override each other, so whichever one is called last is used, just like
+  /// git does with the flags.
+  pub fn prune(mut self, date: impl Into<String>) -> Self {
+    self.prune = Some(Prune::Date(date.into()));
+    self
+  }
+
+  /// Do not prune any loose objects. This and `prune` override each other, so
+  /// whichever one is called last is used, just like git does with the flags.
+  pub fn no_prune(mut self) -> Self {
+    self.prune = Some(Prune::Never);
+    self
+  }
+
+  /// Repack all other packs except the largest pack into a single pack
+  pub fn keep_largest_pack(mut self) -> Self {
+    self.keep_largest_pack = true;
+    self
+  }
+
+  /// Force gc to run even if there may be another gc instance running on this
+  /// repository
+  pub fn force(mut self) -> Self {
+    self.force = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("gc");
+
+    if self.aggressive {
+      args.arg("--aggressive");
+    }
+    if self.auto {
+      args.arg("--auto");
+    }
+    if self.quiet {
+      args.arg("--quiet");
+    }
+    match self.prune {
+      Some(Prune::Date(date)) => {
+        args.arg(format!("--prune={}", date));
+      }
+      Some(Prune::Never) => {
+        args.arg("--no-prune");
+      }
+      None => {}
+    }
+    if self.keep_largest_pack {
+      args.arg("--keep-largest-pack");
+    }
+    if self.force {
+      args.arg("--force");
+    }
+    args
+  }
+}
+
+impl_builder!(GitGcBuilder);
@@ -0,0 +1,134 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git hash-object` command created by calling
+/// `Git::hash_object()`. By default this only computes the object names
+/// without writing anything to the object database.
+#[derive(Clone)]
+pub struct GitHashObjectBuilder {
+  git: GitBuilder,
+  files: Vec<PathBuf>,
+  stdin: bool,
+  object_type: Option<ObjectType>,
+  write: bool,
+  literally: bool,
+  stdin_paths: bool,
+}
+
+impl GitHashObjectBuilder {
+  /// Internal function used by `Git`. `Git::hash_object()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitHashObjectBuilder {
+      git,
+      files: Vec::new(),
+      stdin: false,
+      object_type: None,
+      write: false,
+      literally: false,
+      stdin_paths: false,
+    }
+  }
+
+  /// The files to hash. This can be called multiple times to add more files.
+  pub fn files(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.files.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// Read the object from stdin instead of from a file
+  pub fn stdin(mut self) -> Self {
+    self.stdin = true;
+    self
+  }
+
+  /// The type of object to create. The default is `ObjectType::Blob`.
+  pub fn object_type(mut self, object_type: ObjectType) -> Self {
+    self.object_type = Some(object_type);
+    self
+  }
+
+  /// Actually write the object into the object database
+  pub fn write(mut self) -> Self {
+    self.write = true;
+    self
+  }
+
+  /// Allow hashing objects that fail the usual validity checks for their type,
+  /// which is mostly useful for testing git itself
+  pub fn literally(mut self) -> Self {
+    self.literally = true;
+    self
+  }
+
+  /// Read the paths of the files to hash from stdin, one per line, instead of
+  /// from the command line
+  pub fn stdin_paths(mut self) -> Self {
+    self.stdin_paths = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("hash-object");
+
+    if let Some(object_type) = self.object_type {
+      args.arg("-t");
+      args.arg(match object_type {
+        ObjectType::Blob => "blob",
+        ObjectType::Tree => "tree",
+        ObjectType::Commit => "commit",
+        ObjectType::Tag => "tag",
+      });
+    }
+    if self.write {
+      args.arg("-w");
+    }
+    if self.literally {
+      args.arg("--literally");
+    }
+    if self.stdin {
+      args.arg("--stdin");
+    }
+    if self.stdin_paths {
+      args.arg("--stdin-paths");
+    }
+    if !self.files.is_empty() {
+      args.arg("--");
+      args.args(self.files);
+    }
+    args
+  }
+}
+
+impl_builder!(GitHashObjectBuilder);
+
+/// Options for the `object_type` function
+#[derive(Clone)]
+pub enum ObjectType {
+  /// File contents
+  Blob,
+  /// A directory listing
+  Tree,
+  /// A commit
+  Commit,
+  /// An annotated tag
+  Tag,
+}
@@ -1,8 +1,12 @@
+use crate::{impl_builder, Args, GitBuilder, GitError, IncompleteArgs, InvalidOctal};
+use std::ffi::OsString;
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Output};
 
 /// The builder for the `git init` command created by calling `Git::init()`
+#[derive(Clone)]
 pub struct GitInitBuilder {
+  git: GitBuilder,
   quiet: bool,
   bare: bool,
   template: Option<PathBuf>,
@@ -16,8 +20,9 @@ pub struct GitInitBuilder {
 impl GitInitBuilder {
   /// Internal function used by `Git`. `Git::init()` is just a wrapper around
   /// this function.
-  pub(crate) fn new() -> Self {
+  pub(crate) fn new(git: GitBuilder) -> Self {
     GitInitBuilder {
+      git,
       quiet: false,
       bare: false,
       template: None,
@@ -97,40 +102,77 @@ impl GitInitBuilder {
     self
   }
 
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if `shared` was given a `Shared::Octal` outside of 0o000
+  /// to 0o777
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
   /// Consume the builder and output an `std::process::Command` object you can
   /// add env vars to etc. The command will not execute till you tell it to. See
   /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if `shared` was given a `Shared::Octal` outside of 0o000
+  /// to 0o777, use `try_make_cmd` to get an error instead
   pub fn make_cmd(self) -> Command {
-    let mut cmd = Command::new("git");
-    cmd.arg("init");
+    self.args().command()
+  }
+
+  /// Like `make_cmd`, but returns an error instead of panicking if the options
+  /// are invalid
+  pub fn try_make_cmd(self) -> Result<Command, GitError> {
+    Ok(self.try_args().map_err(|err| err.error)?.command())
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are invalid
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments for `try_make_cmd`, or the ones collected so far
+  /// along with the error if they are invalid, so that `Display` can still
+  /// show them
+  fn try_args(self) -> Result<Args, IncompleteArgs<GitError>> {
+    let mut args = self.git.args();
+    args.arg("init");
 
     if self.quiet {
-      cmd.arg("--quiet");
+      args.arg("--quiet");
     }
 
     if self.bare {
-      cmd.arg("--bare");
+      args.arg("--bare");
     }
 
     if let Some(path) = self.template {
-      cmd.arg("--template");
-      cmd.arg(path);
+      args.arg("--template");
+      args.arg(path);
     }
     if let Some(path) = self.separate_git_dir {
-      cmd.arg("--separate-git-dir");
-      cmd.arg(path);
+      args.arg("--separate-git-dir");
+      args.arg(path);
     }
     if let Some(name) = self.initial_branch {
-      cmd.arg("--initial-branch");
-      cmd.arg(name);
+      args.arg("--initial-branch");
+      args.arg(name);
     }
     if let Some(obj) = self.object_format {
-      cmd.arg("--object-format");
+      args.arg("--object-format");
       let arg = match obj {
-        Hash::Sha1 => "sha1",
-        Hash::Sha256 => "sha256",
+        Hash::Sha1 => "sha1".into(),
+        Hash::Sha256 => "sha256".into(),
+        Hash::Custom(name) => name,
       };
-      cmd.arg(arg);
+      args.arg(arg);
     }
     if let Some(shared) = self.shared {
       let arg = match shared {
@@ -142,18 +184,44 @@ impl GitInitBuilder {
         Shared::World => "world".into(),
         Shared::Everybody => "everybody".into(),
         // Formats the octal to the correct form of 0XXX
-        Shared::Octal(perm) => format!("{:04o}", perm),
+        Shared::Octal(perm) => {
+          if let Err(err) = Shared::try_octal(perm) {
+            return Err(args.incomplete("--shared=<invalid octal>", GitError::InvalidOctal(err)));
+          }
+          format!("{:04o}", perm)
+        }
       };
-      cmd.arg(format!("--shared={}", arg));
+      args.arg(format!("--shared={}", arg));
     }
     if let Some(path) = self.directory {
-      cmd.arg(path);
+      args.arg(path);
     }
-    cmd
+    Ok(args)
+  }
+
+  /// Run the command, waiting for it to finish and collecting its output. This
+  /// returns an error if the options are invalid, git couldn't be run, or it
+  /// didn't exit successfully, see `GitError` for the details.
+  pub fn execute(self) -> Result<Output, GitError> {
+    GitError::execute(self.try_make_cmd()?)
+  }
+
+  /// Like `execute`, but runs git with `tokio` so that waiting for it doesn't
+  /// block the async runtime. This requires the `async` feature.
+  #[cfg(feature = "async")]
+  pub async fn execute_async(self) -> Result<Output, GitError> {
+    GitError::execute_async(self.try_make_cmd()?).await
   }
 }
 
+impl_builder!(
+  GitInitBuilder,
+  panics = "This will panic if `shared` was given a `Shared::Octal` outside of 0o000 to 0o777",
+  without_execute
+);
+
 /// Options for the `shared` function. Note the default is Umask.
+#[derive(Clone)]
 pub enum Shared {
   /// Use permissions reported by umask(2).
   Umask,
@@ -174,7 +242,8 @@ pub enum Shared {
   /// Equivalent to `All`
   Everybody,
   /// You need to make sure you use a valid mode between the numbers 0o000 and
-  /// 0o777 when using this otherwise you'll cause an error.
+  /// 0o777 when using this otherwise you'll cause an error. `Shared::try_octal`
+  /// checks this when creating the value.
   /// An `0ctal` is a number where each file will have mode 0oxxx. 0oxxx will
   /// override users' umask(2) value (and not only loosen permissions as group
   /// and all does). 0o640 will create a repository which is group-readable,
@@ -184,12 +253,28 @@ pub enum Shared {
   Octal(u16)
 }
 
+impl Shared {
+  /// Create a `Shared::Octal` with the given permissions, returning an error if
+  /// they aren't between 0o000 and 0o777
+  pub fn try_octal(perm: u16) -> Result<Shared, InvalidOctal> {
+    if perm <= 0o777 {
+      Ok(Shared::Octal(perm))
+    } else {
+      Err(InvalidOctal::new(perm))
+    }
+  }
+}
+
 /// Which hash you want the repo to use when calling `object_format`. `Sha1` is
 /// the default and `Sha256` might not be available if the cli tool was not built
 /// with the option.
+#[derive(Clone)]
 pub enum Hash {
   /// Ojects will use a sha1 hash
   Sha1,
   /// Ojects will use a sha256 hash
-  Sha256
+  Sha256,
+  /// Objects will use the hash with the given name. This is passed to git as
+  /// is, for hash algorithms added in newer versions of git.
+  Custom(String),
 }
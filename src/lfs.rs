@@ -0,0 +1,250 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git lfs` command created by calling `Git::lfs()`.
+/// This needs [Git LFS](https://git-lfs.com) to be installed, since it isn't
+/// part of git itself. An operation such as `track` or `pull` must be chosen
+/// before calling `make_cmd`. If more than one is called the last one is used.
+#[derive(Clone)]
+pub struct GitLfsBuilder {
+  git: GitBuilder,
+  op: Option<LfsOp>,
+}
+
+/// The operations `GitLfsBuilder` can run
+#[derive(Clone)]
+enum LfsOp {
+  Install {
+    local: bool,
+    system: bool,
+    skip_smudge: bool,
+  },
+  Track(Vec<String>),
+  Untrack(Vec<String>),
+  Push {
+    remote: String,
+    refs: Vec<String>,
+    all: bool,
+  },
+  Pull {
+    remote: String,
+    refs: Vec<String>,
+  },
+  Fetch {
+    remote: String,
+    refs: Vec<String>,
+  },
+  LsFiles,
+  Status,
+  Env,
+}
+
+impl GitLfsBuilder {
+  /// Internal function used by `Git`. `Git::lfs()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitLfsBuilder { git, op: None }
+  }
+
+  /// Set up the Git LFS filters in the global git config, or in the config
+  /// chosen with `local` or `system`. With `skip_smudge` set, checkouts leave
+  /// pointer files in place of the large files until `pull` is run.
+  /// Equivalent to `git lfs install`
+  pub fn install(mut self, local: bool, system: bool, skip_smudge: bool) -> Self {
+    self.op = Some(LfsOp::Install {
+      local,
+      system,
+      skip_smudge,
+    });
+    self
+  }
+
+  /// Store the files matching the given patterns e.g. `"*.psd"` in Git LFS
+  /// by adding them to `.gitattributes`. Equivalent to `git lfs track`
+  pub fn track(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.op = Some(LfsOp::Track(patterns.into_iter().map(Into::into).collect()));
+    self
+  }
+
+  /// Stop storing the files matching the given patterns in Git LFS by removing
+  /// them from `.gitattributes`. Equivalent to `git lfs untrack`
+  pub fn untrack(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.op = Some(LfsOp::Untrack(
+      patterns.into_iter().map(Into::into).collect(),
+    ));
+    self
+  }
+
+  /// Upload the large files referenced by the given refs to the remote, or
+  /// every large file that is referenced anywhere with `all`. A normal
+  /// `git push` does this by itself once `install` has been run. Equivalent
+  /// to `git lfs push`
+  pub fn push(
+    mut self,
+    remote: impl Into<String>,
+    refs: impl IntoIterator<Item = impl Into<String>>,
+    all: bool,
+  ) -> Self {
+    self.op = Some(LfsOp::Push {
+      remote: remote.into(),
+      refs: refs.into_iter().map(Into::into).collect(),
+      all,
+    });
+    self
+  }
+
+  /// Download the large files for the given refs, or the current one if none
+  /// are given, and replace their pointer files in the working tree.
+  /// Equivalent to `git lfs pull`
+  pub fn pull(
+    mut self,
+    remote: impl Into<String>,
+    refs: impl IntoIterator<Item = impl Into<String>>,
+  ) -> Self {
+    self.op = Some(LfsOp::Pull {
+      remote: remote.into(),
+      refs: refs.into_iter().map(Into::into).collect(),
+    });
+    self
+  }
+
+  /// Like `pull`, but only download the large files without touching the
+  /// working tree. Equivalent to `git lfs fetch`
+  pub fn fetch(
+    mut self,
+    remote: impl Into<String>,
+    refs: impl IntoIterator<Item = impl Into<String>>,
+  ) -> Self {
+    self.op = Some(LfsOp::Fetch {
+      remote: remote.into(),
+      refs: refs.into_iter().map(Into::into).collect(),
+    });
+    self
+  }
+
+  /// List the files in the current commit that are stored in Git LFS.
+  /// Equivalent to `git lfs ls-files`
+  pub fn ls_files(mut self) -> Self {
+    self.op = Some(LfsOp::LsFiles);
+    self
+  }
+
+  /// Show the large files that are staged or changed in the working tree.
+  /// Equivalent to `git lfs status`
+  pub fn status(mut self) -> Self {
+    self.op = Some(LfsOp::Status);
+    self
+  }
+
+  /// Show the Git LFS configuration, such as the endpoints and where the
+  /// large files are stored locally. Equivalent to `git lfs env`
+  pub fn env(mut self) -> Self {
+    self.op = Some(LfsOp::Env);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("lfs");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      LfsOp::Install {
+        local,
+        system,
+        skip_smudge,
+      } => {
+        args.arg("install");
+        if local {
+          args.arg("--local");
+        }
+        if system {
+          args.arg("--system");
+        }
+        if skip_smudge {
+          args.arg("--skip-smudge");
+        }
+      }
+      LfsOp::Track(patterns) => {
+        args.arg("track");
+        args.args(patterns);
+      }
+      LfsOp::Untrack(patterns) => {
+        args.arg("untrack");
+        args.args(patterns);
+      }
+      LfsOp::Push { remote, refs, all } => {
+        args.arg("push");
+        if all {
+          args.arg("--all");
+        }
+        args.arg(remote);
+        args.args(refs);
+      }
+      LfsOp::Pull { remote, refs } => {
+        args.arg("pull");
+        args.arg(remote);
+        args.args(refs);
+      }
+      LfsOp::Fetch { remote, refs } => {
+        args.arg("fetch");
+        args.arg(remote);
+        args.args(refs);
+      }
+      LfsOp::LsFiles => {
+        args.arg("ls-files");
+      }
+      LfsOp::Status => {
+        args.arg("status");
+      }
+      LfsOp::Env => {
+        args.arg("env");
+      }
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitLfsBuilder,
+  panics = "This will panic if no operation was set"
+);
@@ -8,9 +8,141 @@
 //! yourself and possibly messing up the arguments. The entry point to every
 //! command is with the `Git` struct. Take a look at the docs there to get an
 //! understanding of what the crate is currently capable of.
+//!
+//! If you're using `tokio`, enable the `async` feature to get an
+//! `execute_async()` function on every builder that doesn't block the runtime
+//! while git runs.
+//!
+//! For commands with a lot of output, `make_child()` spawns git with its stdout
+//! piped so it can be read as it is written instead of all at once, and
+//! `make_child_with_input()` pipes stdin as well.
+//!
+//! Every builder can also be printed with `{}` to show the equivalent shell
+//! command, which is handy for logging what is about to run. Unlike `to_args`,
+//! this never panics: builders that are missing a required part, like the
+//! operation of `Git::stash()`, show a placeholder for it instead, e.g. `git
+//! stash <op?>`.
 
+mod am;
+mod apply;
+mod archive;
+mod bisect;
+mod blame;
+mod branch;
+mod builder;
+mod bundle;
+mod cat_file;
+mod check_ignore;
+mod cherry_pick;
+mod clean;
+mod commit_tree;
+mod config;
+mod count_objects;
+mod credential;
+mod describe;
+mod diff;
+mod difftool;
+mod error;
+mod filter;
+mod for_each_ref;
+mod format_patch;
+mod fsck;
+mod gc;
+mod hash_object;
 mod init;
+mod lfs;
+mod log;
+mod ls_files;
+mod ls_remote;
+mod ls_tree;
+mod maintenance;
+mod mergetool;
+mod mv;
+mod notes;
+mod pack_refs;
+mod pipeline;
+mod prune;
+mod range_diff;
+mod read_tree;
+mod reflog;
+mod remote;
+mod reset;
+mod rev_list;
+mod rev_parse;
+mod revert;
+mod rm;
+mod shortlog;
+mod show;
+mod sparse_checkout;
+mod stash;
+mod strategy;
+mod submodule;
+mod symbolic_ref;
+mod tag;
+mod update_ref;
+mod verify_pack;
+mod worktree;
+mod write_tree;
+pub use crate::am::*;
+pub use crate::apply::*;
+pub use crate::archive::*;
+pub use crate::bisect::*;
+pub use crate::blame::*;
+pub use crate::branch::*;
+pub use crate::builder::*;
+pub use crate::bundle::*;
+pub use crate::cat_file::*;
+pub use crate::check_ignore::*;
+pub use crate::cherry_pick::*;
+pub use crate::clean::*;
+pub use crate::commit_tree::*;
+pub use crate::config::*;
+pub use crate::count_objects::*;
+pub use crate::credential::*;
+pub use crate::describe::*;
+pub use crate::diff::*;
+pub use crate::difftool::*;
+pub use crate::error::*;
+pub use crate::filter::*;
+pub use crate::for_each_ref::*;
+pub use crate::format_patch::*;
+pub use crate::fsck::*;
+pub use crate::gc::*;
+pub use crate::hash_object::*;
 pub use crate::init::*;
+pub use crate::lfs::*;
+pub use crate::log::*;
+pub use crate::ls_files::*;
+pub use crate::ls_remote::*;
+pub use crate::ls_tree::*;
+pub use crate::maintenance::*;
+pub use crate::mergetool::*;
+pub use crate::mv::*;
+pub use crate::notes::*;
+pub use crate::pack_refs::*;
+pub use crate::pipeline::*;
+pub use crate::prune::*;
+pub use crate::range_diff::*;
+pub use crate::read_tree::*;
+pub use crate::reflog::*;
+pub use crate::remote::*;
+pub use crate::reset::*;
+pub use crate::rev_list::*;
+pub use crate::rev_parse::*;
+pub use crate::revert::*;
+pub use crate::rm::*;
+pub use crate::shortlog::*;
+pub use crate::show::*;
+pub use crate::sparse_checkout::*;
+pub use crate::stash::*;
+pub use crate::strategy::*;
+pub use crate::submodule::*;
+pub use crate::symbolic_ref::*;
+pub use crate::tag::*;
+pub use crate::update_ref::*;
+pub use crate::verify_pack::*;
+pub use crate::worktree::*;
+pub use crate::write_tree::*;
 
 /// This type entry way to all the git commands. While you can just make the struct
 /// like so: `let git = Git;` it's recommended to instead use it as part of the
@@ -29,9 +161,280 @@ pub use crate::init::*;
 /// ```
 pub struct Git;
 impl Git {
+  /// Creates a `GitBuilder` to set options that apply to every git command,
+  /// such as the directory to run it in, before picking the subcommand
+  pub fn builder() -> GitBuilder {
+    GitBuilder::new()
+  }
+
   /// Creates a builder for the `git init` subcommand
   pub fn init() -> GitInitBuilder {
-    GitInitBuilder::new()
+    GitInitBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git log` subcommand
+  pub fn log() -> GitLogBuilder {
+    GitLogBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git diff` subcommand
+  pub fn diff() -> GitDiffBuilder {
+    GitDiffBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git remote` subcommand
+  pub fn remote() -> GitRemoteBuilder {
+    GitRemoteBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git tag` subcommand
+  pub fn tag() -> GitTagBuilder {
+    GitTagBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git stash` subcommand
+  pub fn stash() -> GitStashBuilder {
+    GitStashBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git reset` subcommand
+  pub fn reset() -> GitResetBuilder {
+    GitResetBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git revert` subcommand
+  pub fn revert() -> GitRevertBuilder {
+    GitRevertBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git cherry-pick` subcommand
+  pub fn cherry_pick() -> GitCherryPickBuilder {
+    GitCherryPickBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git submodule` subcommand
+  pub fn submodule() -> GitSubmoduleBuilder {
+    GitSubmoduleBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git config` subcommand
+  pub fn config() -> GitConfigBuilder {
+    GitConfigBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git rm` subcommand
+  pub fn rm() -> GitRmBuilder {
+    GitRmBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git mv` subcommand
+  pub fn mv() -> GitMvBuilder {
+    GitMvBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git show` subcommand
+  pub fn show() -> GitShowBuilder {
+    GitShowBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git blame` subcommand
+  pub fn blame() -> GitBlameBuilder {
+    GitBlameBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git bisect` subcommand
+  pub fn bisect() -> GitBisectBuilder {
+    GitBisectBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git worktree` subcommand
+  pub fn worktree() -> GitWorktreeBuilder {
+    GitWorktreeBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git clean` subcommand
+  pub fn clean() -> GitCleanBuilder {
+    GitCleanBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git gc` subcommand
+  pub fn gc() -> GitGcBuilder {
+    GitGcBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git archive` subcommand
+  pub fn archive() -> GitArchiveBuilder {
+    GitArchiveBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git apply` subcommand
+  pub fn apply() -> GitApplyBuilder {
+    GitApplyBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git format-patch` subcommand
+  pub fn format_patch() -> GitFormatPatchBuilder {
+    GitFormatPatchBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git shortlog` subcommand
+  pub fn shortlog() -> GitShortlogBuilder {
+    GitShortlogBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git describe` subcommand
+  pub fn describe() -> GitDescribeBuilder {
+    GitDescribeBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git rev-parse` subcommand
+  pub fn rev_parse() -> GitRevParseBuilder {
+    GitRevParseBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git ls-files` subcommand
+  pub fn ls_files() -> GitLsFilesBuilder {
+    GitLsFilesBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git ls-tree` subcommand
+  pub fn ls_tree() -> GitLsTreeBuilder {
+    GitLsTreeBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git cat-file` subcommand
+  pub fn cat_file() -> GitCatFileBuilder {
+    GitCatFileBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git hash-object` subcommand
+  pub fn hash_object() -> GitHashObjectBuilder {
+    GitHashObjectBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git reflog` subcommand
+  pub fn reflog() -> GitReflogBuilder {
+    GitReflogBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git bundle` subcommand
+  pub fn bundle() -> GitBundleBuilder {
+    GitBundleBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git notes` subcommand
+  pub fn notes() -> GitNotesBuilder {
+    GitNotesBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git sparse-checkout` subcommand
+  pub fn sparse_checkout() -> GitSparseCheckoutBuilder {
+    GitSparseCheckoutBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git ls-remote` subcommand
+  pub fn ls_remote() -> GitLsRemoteBuilder {
+    GitLsRemoteBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git fsck` subcommand
+  pub fn fsck() -> GitFsckBuilder {
+    GitFsckBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git prune` subcommand
+  pub fn prune() -> GitPruneBuilder {
+    GitPruneBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git pack-refs` subcommand
+  pub fn pack_refs() -> GitPackRefsBuilder {
+    GitPackRefsBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git count-objects` subcommand
+  pub fn count_objects() -> GitCountObjectsBuilder {
+    GitCountObjectsBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git am` subcommand
+  pub fn am() -> GitAmBuilder {
+    GitAmBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git range-diff` subcommand
+  pub fn range_diff() -> GitRangeDiffBuilder {
+    GitRangeDiffBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git mergetool` subcommand
+  pub fn mergetool() -> GitMergetoolBuilder {
+    GitMergetoolBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git difftool` subcommand
+  pub fn difftool() -> GitDifftoolBuilder {
+    GitDifftoolBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git maintenance` subcommand
+  pub fn maintenance() -> GitMaintenanceBuilder {
+    GitMaintenanceBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git update-ref` subcommand
+  pub fn update_ref() -> GitUpdateRefBuilder {
+    GitUpdateRefBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git symbolic-ref` subcommand
+  pub fn symbolic_ref() -> GitSymbolicRefBuilder {
+    GitSymbolicRefBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git verify-pack` subcommand
+  pub fn verify_pack() -> GitVerifyPackBuilder {
+    GitVerifyPackBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git lfs` subcommand
+  pub fn lfs() -> GitLfsBuilder {
+    GitLfsBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git commit-tree` subcommand
+  pub fn commit_tree() -> GitCommitTreeBuilder {
+    GitCommitTreeBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git read-tree` subcommand
+  pub fn read_tree() -> GitReadTreeBuilder {
+    GitReadTreeBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git write-tree` subcommand
+  pub fn write_tree() -> GitWriteTreeBuilder {
+    GitWriteTreeBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git rev-list` subcommand
+  pub fn rev_list() -> GitRevListBuilder {
+    GitRevListBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git for-each-ref` subcommand
+  pub fn for_each_ref() -> GitForEachRefBuilder {
+    GitForEachRefBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git check-ignore` subcommand
+  pub fn check_ignore() -> GitCheckIgnoreBuilder {
+    GitCheckIgnoreBuilder::new(GitBuilder::new())
+  }
+
+  /// Creates a builder for the `git credential` subcommand
+  pub fn credential() -> GitCredentialBuilder {
+    GitCredentialBuilder::new(GitBuilder::new())
   }
 }
 
@@ -0,0 +1,455 @@
+use crate::{impl_builder, Args, GitBuilder, ParseError};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git log` command created by calling `Git::log()`
+#[derive(Clone)]
+pub struct GitLogBuilder {
+  git: GitBuilder,
+  oneline: bool,
+  format: Option<LogFormat>,
+  graph: bool,
+  all: bool,
+  author: Option<String>,
+  since: Option<String>,
+  until: Option<String>,
+  grep: Option<String>,
+  max_count: Option<u32>,
+  skip: Option<u32>,
+  no_merges: bool,
+  stat: bool,
+  name_only: bool,
+  name_status: bool,
+  follow: bool,
+  patch: bool,
+  decorate: Option<DecorateMode>,
+  simplify_by_decoration: bool,
+  source: bool,
+  null_terminated: bool,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitLogBuilder {
+  /// Internal function used by `Git`. `Git::log()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitLogBuilder {
+      git,
+      oneline: false,
+      format: None,
+      graph: false,
+      all: false,
+      author: None,
+      since: None,
+      until: None,
+      grep: None,
+      max_count: None,
+      skip: None,
+      no_merges: false,
+      stat: false,
+      name_only: false,
+      name_status: false,
+      follow: false,
+      patch: false,
+      decorate: None,
+      simplify_by_decoration: false,
+      source: false,
+      null_terminated: false,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// Show each commit on a single line made up of the abbreviated hash and the
+  /// title line of the commit message
+  pub fn oneline(mut self) -> Self {
+    self.oneline = true;
+    self
+  }
+
+  /// Pretty-print the contents of the commit logs in the given format. This can
+  /// be one of the named formats such as `medium` or `fuller`, or a format
+  /// string like `format:%H %s`. See the PRETTY FORMATS section of `git help
+  /// log` for the placeholders you can use.
+  pub fn format(mut self, format: impl Into<String>) -> Self {
+    self.format = Some(LogFormat::Format(format.into()));
+    self
+  }
+
+  /// Pretty-print the contents of the commit logs in the given format. See
+  /// `LogFormat` for the available formats. This and `format` override each
+  /// other, so whichever one is called last is used.
+  pub fn log_format(mut self, format: LogFormat) -> Self {
+    self.format = Some(format);
+    self
+  }
+
+  /// Draw a text-based graphical representation of the commit history on the
+  /// left hand side of the output
+  pub fn graph(mut self) -> Self {
+    self.graph = true;
+    self
+  }
+
+  /// Pretend as if all the refs in `refs/`, along with `HEAD`, are listed on
+  /// the command line as commits to show
+  pub fn all(mut self) -> Self {
+    self.all = true;
+    self
+  }
+
+  /// Limit the commits output to ones with author header lines that match the
+  /// given pattern (regular expression)
+  pub fn author(mut self, pattern: impl Into<String>) -> Self {
+    self.author = Some(pattern.into());
+    self
+  }
+
+  /// Show commits more recent than a specific date such as `"2 weeks ago"` or
+  /// `"2020-10-01"`
+  pub fn since(mut self, date: impl Into<String>) -> Self {
+    self.since = Some(date.into());
+    self
+  }
+
+  /// Show commits older than a specific date such as `"2 weeks ago"` or
+  /// `"2020-10-01"`
+  pub fn until(mut self, date: impl Into<String>) -> Self {
+    self.until = Some(date.into());
+    self
+  }
+
+  /// Limit the commits output to ones with a log message that matches the
+  /// given pattern (regular expression)
+  pub fn grep(mut self, pattern: impl Into<String>) -> Self {
+    self.grep = Some(pattern.into());
+    self
+  }
+
+  /// Limit the number of commits to output
+  pub fn max_count(mut self, count: u32) -> Self {
+    self.max_count = Some(count);
+    self
+  }
+
+  /// Skip the given number of commits before starting to show the commit
+  /// output
+  pub fn skip(mut self, count: u32) -> Self {
+    self.skip = Some(count);
+    self
+  }
+
+  /// Do not print commits with more than one parent
+  pub fn no_merges(mut self) -> Self {
+    self.no_merges = true;
+    self
+  }
+
+  /// Show a diffstat of the files changed in each commit
+  pub fn stat(mut self) -> Self {
+    self.stat = true;
+    self
+  }
+
+  /// Show only the names of the files changed in each commit
+  pub fn name_only(mut self) -> Self {
+    self.name_only = true;
+    self
+  }
+
+  /// Show only the names and the status (added, modified, deleted etc.) of the
+  /// files changed in each commit
+  pub fn name_status(mut self) -> Self {
+    self.name_status = true;
+    self
+  }
+
+  /// Continue listing the history of a file beyond renames. This only works
+  /// when a single file is given with `pathspecs`.
+  pub fn follow(mut self) -> Self {
+    self.follow = true;
+    self
+  }
+
+  /// Show the patch introduced by each commit
+  pub fn patch(mut self) -> Self {
+    self.patch = true;
+    self
+  }
+
+  /// Print out the ref names of any commits that are shown in the given style.
+  /// See `DecorateMode` for what each style does.
+  pub fn decorate(mut self, mode: DecorateMode) -> Self {
+    self.decorate = Some(mode);
+    self
+  }
+
+  /// Only show the commits that are referred to by some branch or tag, plus
+  /// the ones needed to keep the history connected when used with `graph`.
+  /// This gives an overview of how the refs relate, especially with `all`.
+  pub fn simplify_by_decoration(mut self) -> Self {
+    self.simplify_by_decoration = true;
+    self
+  }
+
+  /// Print out the name of the ref each commit was reached from e.g.
+  /// `refs/heads/main`, which is useful together with `all`. The name is
+  /// printed after the hash in the default formats and with `%S` in a custom
+  /// one.
+  pub fn source(mut self) -> Self {
+    self.source = true;
+    self
+  }
+
+  /// Output the commits in the format expected by
+  /// `GitLogEntry::parse_log_output`. This sets the format, so don't call
+  /// `format` or `oneline` as well, and anything else that adds to the output
+  /// of each commit like `stat` or `patch` will also break the parser.
+  pub fn structured(mut self) -> Self {
+    self.format = Some(LogFormat::Format(GitLogEntry::FORMAT.into()));
+    self.null_terminated = true;
+    self
+  }
+
+  /// Only show commits that touch the given paths. This can be called multiple
+  /// times to add more paths. The paths are placed after a `--` so that they
+  /// are never confused with ref names.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("log");
+
+    if self.oneline {
+      args.arg("--oneline");
+    }
+    if let Some(format) = self.format {
+      let arg = match format {
+        LogFormat::Oneline => "--oneline".into(),
+        LogFormat::Short => "--format=short".into(),
+        LogFormat::Medium => "--format=medium".into(),
+        LogFormat::Full => "--format=full".into(),
+        LogFormat::Fuller => "--format=fuller".into(),
+        LogFormat::Email => "--format=email".into(),
+        LogFormat::Raw => "--format=raw".into(),
+        LogFormat::Format(format) => format!("--format={}", format),
+        LogFormat::Pretty(format) => format!("--pretty={}", format),
+      };
+      args.arg(arg);
+    }
+    if self.graph {
+      args.arg("--graph");
+    }
+    if self.all {
+      args.arg("--all");
+    }
+    if let Some(author) = self.author {
+      args.arg(format!("--author={}", author));
+    }
+    if let Some(since) = self.since {
+      args.arg(format!("--since={}", since));
+    }
+    if let Some(until) = self.until {
+      args.arg(format!("--until={}", until));
+    }
+    if let Some(grep) = self.grep {
+      args.arg(format!("--grep={}", grep));
+    }
+    if let Some(count) = self.max_count {
+      args.arg(format!("--max-count={}", count));
+    }
+    if let Some(count) = self.skip {
+      args.arg(format!("--skip={}", count));
+    }
+    if self.no_merges {
+      args.arg("--no-merges");
+    }
+    if self.stat {
+      args.arg("--stat");
+    }
+    if self.name_only {
+      args.arg("--name-only");
+    }
+    if self.name_status {
+      args.arg("--name-status");
+    }
+    if self.follow {
+      args.arg("--follow");
+    }
+    if self.patch {
+      args.arg("--patch");
+    }
+    if let Some(mode) = self.decorate {
+      let arg = match mode {
+        DecorateMode::Short => "short",
+        DecorateMode::Full => "full",
+        DecorateMode::Auto => "auto",
+        DecorateMode::No => "no",
+      };
+      args.arg(format!("--decorate={}", arg));
+    }
+    if self.simplify_by_decoration {
+      args.arg("--simplify-by-decoration");
+    }
+    if self.source {
+      args.arg("--source");
+    }
+    if self.null_terminated {
+      args.arg("-z");
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitLogBuilder);
+
+/// Options for the `log_format` function
+#[derive(Clone)]
+pub enum LogFormat {
+  /// The abbreviated hash and the title line on a single line, `--oneline`
+  Oneline,
+  /// The hash, the author, and the title line
+  Short,
+  /// The hash, the author, the author date, and the full message. This is
+  /// the default.
+  Medium,
+  /// Like `Medium`, but with the committer instead of the author date
+  Full,
+  /// Like `Full`, but with both the author and the committer dates
+  Fuller,
+  /// The commit formatted like the email `git format-patch` writes
+  Email,
+  /// The entire commit object exactly as it is stored
+  Raw,
+  /// A format string such as `"%H %s"`, passed as `--format=<format>`. Git
+  /// treats strings with placeholders as `tformat:`, so each commit ends with
+  /// a newline. Named formats and `format:` strings work too.
+  Format(String),
+  /// A format passed as `--pretty=<format>`, which git treats the same as
+  /// `--format` apart from `--pretty` with no value meaning `medium`
+  Pretty(String),
+}
+
+/// Options for the `decorate` function
+#[derive(Clone)]
+pub enum DecorateMode {
+  /// Don't show the `refs/heads/`, `refs/tags/` and `refs/remotes/` prefixes of
+  /// ref names
+  Short,
+  /// Show the full ref names
+  Full,
+  /// Use `Short` if the output is going to a terminal and `No` otherwise
+  Auto,
+  /// Don't print out ref names at all
+  No,
+}
+
+/// A single commit parsed from the output of `git log` run with
+/// `GitLogBuilder::structured`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitLogEntry {
+  /// The full hash of the commit
+  pub hash: String,
+  /// The abbreviated hash of the commit
+  pub abbreviated_hash: String,
+  /// The name of the author
+  pub author_name: String,
+  /// The email of the author
+  pub author_email: String,
+  /// When the commit was authored, in seconds since the Unix epoch
+  pub author_timestamp: i64,
+  /// The name of the committer
+  pub committer_name: String,
+  /// The email of the committer
+  pub committer_email: String,
+  /// When the commit was committed, in seconds since the Unix epoch
+  pub committer_timestamp: i64,
+  /// The first line of the commit message
+  pub subject: String,
+  /// The rest of the commit message after the subject, without trailing
+  /// newlines. This is empty if the message is a single line.
+  pub body: String,
+  /// The full hashes of the parents of the commit. Merge commits have more
+  /// than one and the root commit has none.
+  pub parent_hashes: Vec<String>,
+}
+
+impl GitLogEntry {
+  /// The format `GitLogBuilder::structured` passes to git. The fields are
+  /// separated by NUL bytes and `-z` terminates each commit with one too, so
+  /// commit messages containing newlines can't be confused with the next field
+  /// or commit.
+  pub const FORMAT: &'static str =
+    "%H%x00%h%x00%an%x00%ae%x00%at%x00%cn%x00%ce%x00%ct%x00%s%x00%b%x00%P";
+
+  /// The number of fields in `FORMAT`
+  const FIELDS: usize = 11;
+
+  /// Parse the output of `git log` run with `GitLogBuilder::structured` into
+  /// one entry per commit, in the order git listed them
+  pub fn parse_log_output(bytes: &[u8]) -> Result<Vec<Self>, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+    if output.is_empty() {
+      return Ok(Vec::new());
+    }
+    let output = output
+      .strip_suffix('\0')
+      .ok_or_else(|| ParseError::new("output does not end with a NUL byte"))?;
+
+    let fields: Vec<&str> = output.split('\0').collect();
+    let commits = fields.chunks_exact(Self::FIELDS);
+    if !commits.remainder().is_empty() {
+      return Err(ParseError::new(format!(
+        "expected {} fields per commit but got {} fields in total",
+        Self::FIELDS,
+        fields.len()
+      )));
+    }
+
+    commits
+      .map(|fields| {
+        let timestamp = |field: &str| {
+          field
+            .parse::<i64>()
+            .map_err(|e| ParseError::new(format!("invalid timestamp {}: {}", field, e)))
+        };
+        Ok(GitLogEntry {
+          hash: fields[0].into(),
+          abbreviated_hash: fields[1].into(),
+          author_name: fields[2].into(),
+          author_email: fields[3].into(),
+          author_timestamp: timestamp(fields[4])?,
+          committer_name: fields[5].into(),
+          committer_email: fields[6].into(),
+          committer_timestamp: timestamp(fields[7])?,
+          subject: fields[8].into(),
+          body: fields[9].trim_end_matches('\n').into(),
+          parent_hashes: fields[10].split_whitespace().map(Into::into).collect(),
+        })
+      })
+      .collect()
+  }
+}
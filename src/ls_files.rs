@@ -0,0 +1,186 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git ls-files` command created by calling
+/// `Git::ls_files()`. With no filters set this lists the files in the index.
+#[derive(Clone)]
+pub struct GitLsFilesBuilder {
+  git: GitBuilder,
+  cached: bool,
+  deleted: bool,
+  modified: bool,
+  others: bool,
+  ignored: bool,
+  unmerged: bool,
+  excludes: Vec<String>,
+  exclude_standard: bool,
+  directory: bool,
+  full_name: bool,
+  eol: bool,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitLsFilesBuilder {
+  /// Internal function used by `Git`. `Git::ls_files()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitLsFilesBuilder {
+      git,
+      cached: false,
+      deleted: false,
+      modified: false,
+      others: false,
+      ignored: false,
+      unmerged: false,
+      excludes: Vec::new(),
+      exclude_standard: false,
+      directory: false,
+      full_name: false,
+      eol: false,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// Show all files cached in the index
+  pub fn cached(mut self) -> Self {
+    self.cached = true;
+    self
+  }
+
+  /// Show files with an unstaged deletion
+  pub fn deleted(mut self) -> Self {
+    self.deleted = true;
+    self
+  }
+
+  /// Show files with an unstaged modification. Deleted files count as
+  /// modified too.
+  pub fn modified(mut self) -> Self {
+    self.modified = true;
+    self
+  }
+
+  /// Show other, i.e. untracked, files
+  pub fn others(mut self) -> Self {
+    self.others = true;
+    self
+  }
+
+  /// Show only ignored files. This needs at least one of `exclude` or
+  /// `exclude_standard` to know what counts as ignored.
+  pub fn ignored(mut self) -> Self {
+    self.ignored = true;
+    self
+  }
+
+  /// Show information about unmerged files
+  pub fn unmerged(mut self) -> Self {
+    self.unmerged = true;
+    self
+  }
+
+  /// Skip untracked files matching the given pattern. This can be called
+  /// multiple times to add more patterns.
+  pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+    self.excludes.push(pattern.into());
+    self
+  }
+
+  /// Add the standard git exclusions: `.git/info/exclude`, `.gitignore` in
+  /// each directory, and the user's global exclusion file
+  pub fn exclude_standard(mut self) -> Self {
+    self.exclude_standard = true;
+    self
+  }
+
+  /// If a whole directory is classified as other, show just its name with a
+  /// trailing slash instead of its whole contents
+  pub fn directory(mut self) -> Self {
+    self.directory = true;
+    self
+  }
+
+  /// When run from a subdirectory, output paths relative to the top-level
+  /// directory of the project instead of the current directory
+  pub fn full_name(mut self) -> Self {
+    self.full_name = true;
+    self
+  }
+
+  /// Show the end of line info and the `eol` attribute of the files
+  pub fn eol(mut self) -> Self {
+    self.eol = true;
+    self
+  }
+
+  /// Only list files matching the given paths. This can be called multiple
+  /// times to add more paths. The paths are placed after a `--` so that they
+  /// are never confused with options.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("ls-files");
+
+    if self.cached {
+      args.arg("--cached");
+    }
+    if self.deleted {
+      args.arg("--deleted");
+    }
+    if self.modified {
+      args.arg("--modified");
+    }
+    if self.others {
+      args.arg("--others");
+    }
+    if self.ignored {
+      args.arg("--ignored");
+    }
+    if self.unmerged {
+      args.arg("--unmerged");
+    }
+    for pattern in self.excludes {
+      args.arg(format!("--exclude={}", pattern));
+    }
+    if self.exclude_standard {
+      args.arg("--exclude-standard");
+    }
+    if self.directory {
+      args.arg("--directory");
+    }
+    if self.full_name {
+      args.arg("--full-name");
+    }
+    if self.eol {
+      args.arg("--eol");
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitLsFilesBuilder);
@@ -0,0 +1,196 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs, ParseError};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git ls-remote` command created by calling
+/// `Git::ls_remote()`. This lists the refs of a remote repository without
+/// fetching anything from it. The output can be fed to
+/// `GitLsRemoteEntry::parse_output`.
+#[derive(Clone)]
+pub struct GitLsRemoteBuilder {
+  git: GitBuilder,
+  remote: Option<String>,
+  refs: Vec<String>,
+  heads: bool,
+  tags: bool,
+  refs_flag: bool,
+  quiet: bool,
+  upload_pack: Option<String>,
+  exit_code: bool,
+}
+
+impl GitLsRemoteBuilder {
+  /// Internal function used by `Git`. `Git::ls_remote()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitLsRemoteBuilder {
+      git,
+      remote: None,
+      refs: Vec::new(),
+      heads: false,
+      tags: false,
+      refs_flag: false,
+      quiet: false,
+      upload_pack: None,
+      exit_code: false,
+    }
+  }
+
+  /// The remote to list the refs of, either the name of a configured remote or
+  /// a url. Defaults to the upstream of the current branch or `origin`.
+  pub fn remote(mut self, remote: impl Into<String>) -> Self {
+    self.remote = Some(remote.into());
+    self
+  }
+
+  /// Only show refs whose names end with one of the given patterns, e.g.
+  /// `"main"` or `"refs/tags/v*"`. This can be called multiple times to add
+  /// more patterns. Git only accepts patterns after a remote, so `remote` has
+  /// to be set too.
+  pub fn refs(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.refs.extend(patterns.into_iter().map(Into::into));
+    self
+  }
+
+  /// Limit the output to `refs/heads`
+  pub fn heads(mut self) -> Self {
+    self.heads = true;
+    self
+  }
+
+  /// Limit the output to `refs/tags`
+  pub fn tags(mut self) -> Self {
+    self.tags = true;
+    self
+  }
+
+  /// Don't show peeled tags or pseudorefs like `HEAD` in the output
+  pub fn refs_flag(mut self) -> Self {
+    self.refs_flag = true;
+    self
+  }
+
+  /// Don't print the remote url to stderr
+  pub fn quiet(mut self) -> Self {
+    self.quiet = true;
+    self
+  }
+
+  /// The path to `git-upload-pack` on the remote side
+  pub fn upload_pack(mut self, path: impl Into<String>) -> Self {
+    self.upload_pack = Some(path.into());
+    self
+  }
+
+  /// Exit with status 2 when no matching refs are found in the remote
+  /// repository instead of 0
+  pub fn exit_code(mut self) -> Self {
+    self.exit_code = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if `refs` was set without `remote`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if `refs` was set without `remote`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("ls-remote");
+
+    if self.heads {
+      args.arg("--heads");
+    }
+    if self.tags {
+      args.arg("--tags");
+    }
+    if self.refs_flag {
+      args.arg("--refs");
+    }
+    if self.quiet {
+      args.arg("--quiet");
+    }
+    if let Some(path) = self.upload_pack {
+      args.arg(format!("--upload-pack={}", path));
+    }
+    if self.exit_code {
+      args.arg("--exit-code");
+    }
+    match self.remote {
+      Some(remote) => {
+        args.arg(remote);
+        args.args(self.refs);
+      }
+      None if !self.refs.is_empty() => {
+        return Err(args.incomplete(
+          "<remote?>",
+          "a remote must be set with `remote` when passing `refs`",
+        ))
+      }
+      None => {}
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitLsRemoteBuilder,
+  panics = "This will panic if `refs` was set without `remote`"
+);
+
+/// A single ref listed by `git ls-remote`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitLsRemoteEntry {
+  /// The object the ref points at
+  pub hash: String,
+  /// The full name of the ref e.g. `refs/heads/main`
+  pub refname: String,
+}
+
+impl GitLsRemoteEntry {
+  /// Parse the output of `git ls-remote` into one entry per ref, in the order
+  /// git listed them
+  pub fn parse_output(bytes: &[u8]) -> Result<Vec<Self>, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    output
+      .lines()
+      .filter(|line| !line.is_empty())
+      .map(|line| {
+        let (hash, refname) = line
+          .split_once('\t')
+          .ok_or_else(|| ParseError::new(format!("missing ref name in line: {}", line)))?;
+        Ok(GitLsRemoteEntry {
+          hash: hash.into(),
+          refname: refname.into(),
+        })
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,140 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git ls-tree` command created by calling
+/// `Git::ls_tree()`. A tree must be given with `tree_ish`, git fails without
+/// one.
+#[derive(Clone)]
+pub struct GitLsTreeBuilder {
+  git: GitBuilder,
+  tree_ish: Option<String>,
+  recursive: bool,
+  name_only: bool,
+  long: bool,
+  full_tree: bool,
+  full_name: bool,
+  abbrev: Option<Option<u32>>,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitLsTreeBuilder {
+  /// Internal function used by `Git`. `Git::ls_tree()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitLsTreeBuilder {
+      git,
+      tree_ish: None,
+      recursive: false,
+      name_only: false,
+      long: false,
+      full_tree: false,
+      full_name: false,
+      abbrev: None,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// The tree or commit to list the contents of such as `"HEAD"`
+  pub fn tree_ish(mut self, tree_ish: impl Into<String>) -> Self {
+    self.tree_ish = Some(tree_ish.into());
+    self
+  }
+
+  /// Recurse into subtrees instead of only listing the top level
+  pub fn recursive(mut self) -> Self {
+    self.recursive = true;
+    self
+  }
+
+  /// List only the file names, one per line
+  pub fn name_only(mut self) -> Self {
+    self.name_only = true;
+    self
+  }
+
+  /// Show the object size of blob entries
+  pub fn long(mut self) -> Self {
+    self.long = true;
+    self
+  }
+
+  /// List the full tree regardless of the current working directory
+  pub fn full_tree(mut self) -> Self {
+    self.full_tree = true;
+    self
+  }
+
+  /// Show full path names instead of paths relative to the current directory
+  pub fn full_name(mut self) -> Self {
+    self.full_name = true;
+    self
+  }
+
+  /// Show the shortest unique object name prefix with at least the given
+  /// length, or the default length if `None` is passed
+  pub fn abbrev(mut self, length: Option<u32>) -> Self {
+    self.abbrev = Some(length);
+    self
+  }
+
+  /// Only list entries matching the given paths. This can be called multiple
+  /// times to add more paths.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("ls-tree");
+
+    if self.recursive {
+      args.arg("-r");
+    }
+    if self.name_only {
+      args.arg("--name-only");
+    }
+    if self.long {
+      args.arg("--long");
+    }
+    if self.full_tree {
+      args.arg("--full-tree");
+    }
+    if self.full_name {
+      args.arg("--full-name");
+    }
+    match self.abbrev {
+      Some(Some(length)) => {
+        args.arg(format!("--abbrev={}", length));
+      }
+      Some(None) => {
+        args.arg("--abbrev");
+      }
+      None => {}
+    }
+    if let Some(tree_ish) = self.tree_ish {
+      args.arg(tree_ish);
+    }
+    args.args(self.pathspecs);
+    args
+  }
+}
+
+impl_builder!(GitLsTreeBuilder);
@@ -0,0 +1,166 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git maintenance` command created by calling
+/// `Git::maintenance()`. An operation must be chosen with `op` before calling
+/// `make_cmd`. This requires git 2.30 or later.
+#[derive(Clone)]
+pub struct GitMaintenanceBuilder {
+  git: GitBuilder,
+  op: Option<MaintenanceOp>,
+}
+
+impl GitMaintenanceBuilder {
+  /// Internal function used by `Git`. `Git::maintenance()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitMaintenanceBuilder { git, op: None }
+  }
+
+  /// Choose which `git maintenance` subcommand to run. See `MaintenanceOp` for
+  /// what each of them do. Calling this again replaces the previously set
+  /// operation.
+  pub fn op(mut self, op: MaintenanceOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("maintenance");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      MaintenanceOp::Run { tasks, auto, quiet } => {
+        args.arg("run");
+        for task in tasks {
+          let task = match task {
+            MaintenanceTask::Gc => "gc".into(),
+            MaintenanceTask::CommitGraph => "commit-graph".into(),
+            MaintenanceTask::Fetch => "prefetch".into(),
+            MaintenanceTask::LooseObjects => "loose-objects".into(),
+            MaintenanceTask::IncrementalRepack => "incremental-repack".into(),
+            MaintenanceTask::PackRefs => "pack-refs".into(),
+            MaintenanceTask::WorktreePrune => "worktree-prune".into(),
+            MaintenanceTask::Custom(task) => task,
+          };
+          args.arg(format!("--task={}", task));
+        }
+        if auto {
+          args.arg("--auto");
+        }
+        if quiet {
+          args.arg("--quiet");
+        }
+      }
+      MaintenanceOp::Start => {
+        args.arg("start");
+      }
+      MaintenanceOp::Stop => {
+        args.arg("stop");
+      }
+      MaintenanceOp::Register => {
+        args.arg("register");
+      }
+      MaintenanceOp::Unregister => {
+        args.arg("unregister");
+      }
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitMaintenanceBuilder,
+  panics = "This will panic if no operation was set with `op`"
+);
+
+/// The operations that can be passed to `GitMaintenanceBuilder::op`
+#[derive(Clone)]
+pub enum MaintenanceOp {
+  /// Run maintenance tasks on the repository
+  Run {
+    /// The tasks to run, in order. If this is empty the tasks enabled with
+    /// `maintenance.<task>.enabled` are run, which is just `gc` by default.
+    tasks: Vec<MaintenanceTask>,
+    /// Only run the tasks that the repository needs, e.g. `gc` only runs if
+    /// there are enough loose objects
+    auto: bool,
+    /// Don't report progress or other information to stderr
+    quiet: bool,
+  },
+  /// Register the repository and schedule `Run` to be run in the background
+  /// hourly, daily, and weekly with the system scheduler
+  Start,
+  /// Stop running maintenance in the background for all registered
+  /// repositories, leaving them registered
+  Stop,
+  /// Add the repository to the `maintenance.repo` global config so that
+  /// background maintenance runs on it, and set config values that are
+  /// recommended for it
+  Register,
+  /// Remove the repository from background maintenance
+  Unregister,
+}
+
+/// The tasks that can be run with `MaintenanceOp::Run`
+#[derive(Clone)]
+pub enum MaintenanceTask {
+  /// Clean up and pack the object database with `git gc`
+  Gc,
+  /// Incrementally update the commit-graph file
+  CommitGraph,
+  /// Fetch the latest objects from all remotes into `refs/prefetch/` without
+  /// changing the remote-tracking branches. Git calls this task `prefetch`.
+  Fetch,
+  /// Pack loose objects and remove the ones that are already in a pack
+  LooseObjects,
+  /// Repack the object database in small steps using the multi-pack-index
+  IncrementalRepack,
+  /// Pack loose refs with `git pack-refs --all --prune`
+  PackRefs,
+  /// Prune stale worktrees with `git worktree prune`. This requires a newer
+  /// version of git than the other tasks.
+  WorktreePrune,
+  /// A task by name, for tasks added in newer versions of git
+  Custom(String),
+}
@@ -0,0 +1,154 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git mergetool` command created by calling
+/// `Git::mergetool()`. This runs a merge tool on each file with merge
+/// conflicts, so git must be in the middle of a merge, rebase, etc.
+#[derive(Clone)]
+pub struct GitMergetoolBuilder {
+  git: GitBuilder,
+  tool: Option<String>,
+  tool_help: bool,
+  prompt: Option<bool>,
+  gui: Option<bool>,
+  order_file: Option<PathBuf>,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitMergetoolBuilder {
+  /// Internal function used by `Git`. `Git::mergetool()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitMergetoolBuilder {
+      git,
+      tool: None,
+      tool_help: false,
+      prompt: None,
+      gui: None,
+      order_file: None,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// The merge tool to run, e.g. `"vimdiff"` or the name of a tool configured
+  /// with `mergetool.<tool>.cmd`. Defaults to the `merge.tool` config value.
+  pub fn tool(mut self, tool: impl Into<String>) -> Self {
+    self.tool = Some(tool.into());
+    self
+  }
+
+  /// Print the list of merge tools that can be used with `tool` instead of
+  /// running one
+  pub fn tool_help(mut self) -> Self {
+    self.tool_help = true;
+    self
+  }
+
+  /// Don't ask before running the merge tool on each file. This and `prompt`
+  /// override each other, so whichever one is called last is used, just like
+  /// git does with the flags.
+  pub fn no_prompt(mut self) -> Self {
+    self.prompt = Some(false);
+    self
+  }
+
+  /// Ask before running the merge tool on each file, even if
+  /// `mergetool.prompt` is set to false. This and `no_prompt` override each
+  /// other, so whichever one is called last is used, just like git does with
+  /// the flags.
+  pub fn prompt(mut self) -> Self {
+    self.prompt = Some(true);
+    self
+  }
+
+  /// Default to the `merge.guitool` config value when `tool` isn't set. This
+  /// and `no_gui` override each other, so whichever one is called last is
+  /// used, just like git does with the flags.
+  pub fn gui(mut self) -> Self {
+    self.gui = Some(true);
+    self
+  }
+
+  /// Default to the `merge.tool` config value when `tool` isn't set, even if
+  /// `mergetool.guiDefault` is set. This and `gui` override each other, so
+  /// whichever one is called last is used, just like git does with the flags.
+  pub fn no_gui(mut self) -> Self {
+    self.gui = Some(false);
+    self
+  }
+
+  /// Process the files in the order given by the patterns in this file, one
+  /// per line, instead of alphabetically
+  pub fn order_file(mut self, path: impl Into<PathBuf>) -> Self {
+    self.order_file = Some(path.into());
+    self
+  }
+
+  /// Only resolve the conflicts in the given paths. This can be called
+  /// multiple times to add more paths. The paths are placed after a `--` so
+  /// that they are never confused with options.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("mergetool");
+
+    if let Some(tool) = self.tool {
+      args.arg(format!("--tool={}", tool));
+    }
+    if self.tool_help {
+      args.arg("--tool-help");
+    }
+    match self.prompt {
+      Some(true) => {
+        args.arg("--prompt");
+      }
+      Some(false) => {
+        args.arg("--no-prompt");
+      }
+      None => {}
+    }
+    match self.gui {
+      Some(true) => {
+        args.arg("--gui");
+      }
+      Some(false) => {
+        args.arg("--no-gui");
+      }
+      None => {}
+    }
+    if let Some(path) = self.order_file {
+      // git mergetool only understands the file name joined to the flag
+      let mut arg = OsString::from("-O");
+      arg.push(path);
+      args.arg(arg);
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitMergetoolBuilder);
@@ -0,0 +1,111 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git mv` command created by calling `Git::mv()`.
+///
+/// With a single source the file or directory is renamed to `destination`.
+/// With multiple sources `destination` must be an existing directory and all
+/// the sources are moved into it.
+#[derive(Clone)]
+pub struct GitMvBuilder {
+  git: GitBuilder,
+  sources: Vec<PathBuf>,
+  destination: Option<PathBuf>,
+  force: bool,
+  dry_run: bool,
+  verbose: bool,
+}
+
+impl GitMvBuilder {
+  /// Internal function used by `Git`. `Git::mv()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitMvBuilder {
+      git,
+      sources: Vec::new(),
+      destination: None,
+      force: false,
+      dry_run: false,
+      verbose: false,
+    }
+  }
+
+  /// The file, symlink, or directory to move. Calling this multiple times
+  /// adds more sources.
+  pub fn source(mut self, path: impl Into<PathBuf>) -> Self {
+    self.sources.push(path.into());
+    self
+  }
+
+  /// Add multiple files, symlinks, or directories to move into the
+  /// `destination` directory
+  pub fn sources(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.sources.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// Where to move the sources to. This is either the new name of a single
+  /// source or an existing directory to move all the sources into.
+  pub fn destination(mut self, path: impl Into<PathBuf>) -> Self {
+    self.destination = Some(path.into());
+    self
+  }
+
+  /// Force renaming or moving of a file even if the destination exists
+  pub fn force(mut self) -> Self {
+    self.force = true;
+    self
+  }
+
+  /// Do nothing; only show what would happen
+  pub fn dry_run(mut self) -> Self {
+    self.dry_run = true;
+    self
+  }
+
+  /// Report the names of files as they are moved
+  pub fn verbose(mut self) -> Self {
+    self.verbose = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("mv");
+
+    if self.force {
+      args.arg("--force");
+    }
+    if self.dry_run {
+      args.arg("--dry-run");
+    }
+    if self.verbose {
+      args.arg("--verbose");
+    }
+    args.arg("--");
+    args.args(self.sources);
+    if let Some(path) = self.destination {
+      args.arg(path);
+    }
+    args
+  }
+}
+
+impl_builder!(GitMvBuilder);
@@ -0,0 +1,216 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git notes` command created by calling `Git::notes()`.
+/// An operation must be chosen with `op` before calling `make_cmd`.
+#[derive(Clone)]
+pub struct GitNotesBuilder {
+  git: GitBuilder,
+  op: Option<NotesOp>,
+}
+
+impl GitNotesBuilder {
+  /// Internal function used by `Git`. `Git::notes()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitNotesBuilder { git, op: None }
+  }
+
+  /// Choose which `git notes` subcommand to run. See `NotesOp` for what each
+  /// of them do. Calling this again replaces the previously set operation.
+  pub fn op(mut self, op: NotesOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("notes");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      NotesOp::Add {
+        object,
+        message,
+        file,
+        allow_empty,
+        force,
+      } => {
+        args.arg("add");
+        if force {
+          args.arg("--force");
+        }
+        if allow_empty {
+          args.arg("--allow-empty");
+        }
+        if let Some(message) = message {
+          args.arg(format!("--message={}", message));
+        }
+        if let Some(path) = file {
+          args.arg("--file");
+          args.arg(path);
+        }
+        if let Some(object) = object {
+          args.arg(object);
+        }
+      }
+      NotesOp::Append { object, message } => {
+        args.arg("append");
+        if let Some(message) = message {
+          args.arg(format!("--message={}", message));
+        }
+        if let Some(object) = object {
+          args.arg(object);
+        }
+      }
+      NotesOp::Copy { from, to, force } => {
+        args.arg("copy");
+        if force {
+          args.arg("--force");
+        }
+        args.arg(from);
+        args.arg(to);
+      }
+      NotesOp::Edit(object) => {
+        args.arg("edit");
+        if let Some(object) = object {
+          args.arg(object);
+        }
+      }
+      NotesOp::Show(object) => {
+        args.arg("show");
+        if let Some(object) = object {
+          args.arg(object);
+        }
+      }
+      NotesOp::List(object) => {
+        args.arg("list");
+        if let Some(object) = object {
+          args.arg(object);
+        }
+      }
+      NotesOp::Remove {
+        objects,
+        ignore_missing,
+      } => {
+        args.arg("remove");
+        if ignore_missing {
+          args.arg("--ignore-missing");
+        }
+        args.args(objects);
+      }
+      NotesOp::Prune { dry_run, verbose } => {
+        args.arg("prune");
+        if dry_run {
+          args.arg("--dry-run");
+        }
+        if verbose {
+          args.arg("--verbose");
+        }
+      }
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitNotesBuilder,
+  panics = "This will panic if no operation was set with `op`"
+);
+
+/// The operations that can be passed to `GitNotesBuilder::op`. Wherever an
+/// object is optional it defaults to `HEAD`.
+#[derive(Clone)]
+pub enum NotesOp {
+  /// Add a note to an object
+  Add {
+    /// The object to add the note to
+    object: Option<String>,
+    /// The contents of the note. If neither this nor `file` is set an editor
+    /// is opened.
+    message: Option<String>,
+    /// Read the contents of the note from this file
+    file: Option<PathBuf>,
+    /// Store the note even if it is empty
+    allow_empty: bool,
+    /// Overwrite the existing note of the object instead of failing
+    force: bool,
+  },
+  /// Append to the note of an object, creating it if it doesn't exist yet
+  Append {
+    /// The object to append to the note of
+    object: Option<String>,
+    /// The text to append. If this isn't set an editor is opened.
+    message: Option<String>,
+  },
+  /// Copy the note of one object to another
+  Copy {
+    /// The object to copy the note from
+    from: String,
+    /// The object to copy the note to
+    to: String,
+    /// Overwrite the existing note of `to` instead of failing
+    force: bool,
+  },
+  /// Edit the note of an object in an editor
+  Edit(Option<String>),
+  /// Show the note of an object
+  Show(Option<String>),
+  /// List the notes of an object, or every note and the object it annotates if
+  /// no object is given
+  List(Option<String>),
+  /// Remove the notes of objects
+  Remove {
+    /// The objects to remove the notes of
+    objects: Vec<String>,
+    /// Don't fail when an object has no note
+    ignore_missing: bool,
+  },
+  /// Remove the notes of objects that no longer exist
+  Prune {
+    /// Do not remove anything; just report what would be removed
+    dry_run: bool,
+    /// Report all removals
+    verbose: bool,
+  },
+}
@@ -0,0 +1,84 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git pack-refs` command created by calling
+/// `Git::pack_refs()`. This moves loose refs into the `packed-refs` file so
+/// that looking them up doesn't need a file per ref.
+#[derive(Clone)]
+pub struct GitPackRefsBuilder {
+  git: GitBuilder,
+  all: bool,
+  prune: Option<bool>,
+}
+
+impl GitPackRefsBuilder {
+  /// Internal function used by `Git`. `Git::pack_refs()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitPackRefsBuilder {
+      git,
+      all: false,
+      prune: None,
+    }
+  }
+
+  /// Pack all refs, including branches. By default only tags and refs that
+  /// are already packed are, since branches are expected to change often.
+  pub fn all(mut self) -> Self {
+    self.all = true;
+    self
+  }
+
+  /// Remove the loose refs after packing them. This is the default, but is
+  /// passed explicitly if set. This and `no_prune` override each other, so
+  /// whichever one is called last is used, just like git does with the flags.
+  pub fn prune(mut self) -> Self {
+    self.prune = Some(true);
+    self
+  }
+
+  /// Keep the loose refs after packing them. This and `prune` override each
+  /// other, so whichever one is called last is used, just like git does with
+  /// the flags.
+  pub fn no_prune(mut self) -> Self {
+    self.prune = Some(false);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("pack-refs");
+
+    if self.all {
+      args.arg("--all");
+    }
+    match self.prune {
+      Some(true) => {
+        args.arg("--prune");
+      }
+      Some(false) => {
+        args.arg("--no-prune");
+      }
+      None => {}
+    }
+    args
+  }
+}
+
+impl_builder!(GitPackRefsBuilder);
@@ -0,0 +1,70 @@
+use crate::GitError;
+use std::process::{Command, Output};
+
+/// Anything that can be turned into a `Command` to run. This is implemented
+/// for every builder, as well as for `Command` itself so that commands this
+/// crate doesn't have a builder for can be used with `GitPipeline` too.
+pub trait IntoMakeCmd {
+  /// Consume the builder and output the `std::process::Command` it describes
+  fn make_cmd(self) -> Command;
+}
+
+impl IntoMakeCmd for Command {
+  fn make_cmd(self) -> Command {
+    self
+  }
+}
+
+/// A list of commands that are run one after another, created by calling
+/// `GitPipeline::new()` and adding commands with `step`. This can't be cloned
+/// since it holds the `Command`s of its steps.
+///
+/// ```no_run
+/// use git_cmd::*;
+/// use std::process::Command;
+///
+/// let mut add = Command::new("git");
+/// add.args(["-C", "/tmp/repo", "add", "README.md"]);
+/// let outputs = GitPipeline::new()
+///   .step(Git::init().directory("/tmp/repo"))
+///   .step(add)
+///   .step(Git::builder().working_dir("/tmp/repo").log())
+///   .run();
+/// ```
+#[derive(Default)]
+pub struct GitPipeline {
+  steps: Vec<Command>,
+}
+
+impl GitPipeline {
+  /// Create an empty pipeline
+  pub fn new() -> Self {
+    GitPipeline { steps: Vec::new() }
+  }
+
+  /// Add a command to run after the ones that were added before it. The
+  /// builder's `make_cmd` is called right away, so it will panic here if the
+  /// builder would panic in `make_cmd`.
+  pub fn step(mut self, builder: impl IntoMakeCmd) -> Self {
+    self.steps.push(builder.make_cmd());
+    self
+  }
+
+  /// Run the steps in order, stopping at the first one that fails. This
+  /// returns the output of every step, or the index of the step that failed
+  /// along with its error. The steps after a failed one are never run.
+  pub fn run(self) -> Result<Vec<Output>, (usize, GitError)> {
+    self
+      .steps
+      .into_iter()
+      .enumerate()
+      .map(|(i, cmd)| GitError::execute(cmd).map_err(|err| (i, err)))
+      .collect()
+  }
+
+  /// Run every step in order, even if some of them fail, and return the
+  /// result of each of them
+  pub fn run_all(self) -> Vec<Result<Output, GitError>> {
+    self.steps.into_iter().map(GitError::execute).collect()
+  }
+}
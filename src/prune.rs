@@ -0,0 +1,94 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git prune` command created by calling `Git::prune()`.
+/// This removes objects that can't be reached from any ref, the index, or the
+/// reflogs. Most of the time `git gc` is the better choice since it runs this
+/// along with other housekeeping.
+#[derive(Clone)]
+pub struct GitPruneBuilder {
+  git: GitBuilder,
+  heads: Vec<String>,
+  dry_run: bool,
+  verbose: bool,
+  expire: Option<String>,
+}
+
+impl GitPruneBuilder {
+  /// Internal function used by `Git`. `Git::prune()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitPruneBuilder {
+      git,
+      heads: Vec::new(),
+      dry_run: false,
+      verbose: false,
+      expire: None,
+    }
+  }
+
+  /// Don't remove anything, just report what would be removed
+  pub fn dry_run(mut self) -> Self {
+    self.dry_run = true;
+    self
+  }
+
+  /// Report all removed objects
+  pub fn verbose(mut self) -> Self {
+    self.verbose = true;
+    self
+  }
+
+  /// Only remove unreachable objects older than the given date such as
+  /// `"now"` or `"1.week.ago"`
+  pub fn expire(mut self, date: impl Into<String>) -> Self {
+    self.expire = Some(date.into());
+    self
+  }
+
+  /// Keep the objects reachable from the given heads in addition to the ones
+  /// reachable from the refs. This can be called multiple times to add more
+  /// heads.
+  pub fn heads(mut self, heads: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.heads.extend(heads.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("prune");
+
+    if self.dry_run {
+      args.arg("--dry-run");
+    }
+    if self.verbose {
+      args.arg("--verbose");
+    }
+    if let Some(date) = self.expire {
+      args.arg(format!("--expire={}", date));
+    }
+    if !self.heads.is_empty() {
+      args.arg("--");
+      args.args(self.heads);
+    }
+    args
+  }
+}
+
+impl_builder!(GitPruneBuilder);
@@ -0,0 +1,118 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git range-diff` command created by calling
+/// `Git::range_diff()`. This compares two versions of a patch series, e.g.
+/// before and after a rebase, pairing up the commits of each and showing how
+/// they changed. The two ranges must be set with `ranges`.
+#[derive(Clone)]
+pub struct GitRangeDiffBuilder {
+  git: GitBuilder,
+  ranges: Option<(String, String)>,
+  creation_factor: Option<u32>,
+  no_dual_color: bool,
+  left_only: bool,
+  right_only: bool,
+  diff_opts: Vec<String>,
+}
+
+impl GitRangeDiffBuilder {
+  /// Internal function used by `Git`. `Git::range_diff()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitRangeDiffBuilder {
+      git,
+      ranges: None,
+      creation_factor: None,
+      no_dual_color: false,
+      left_only: false,
+      right_only: false,
+      diff_opts: Vec::new(),
+    }
+  }
+
+  /// The old and new versions of the patch series, e.g. `"main..topic@{1}"`
+  /// and `"main..topic"`
+  pub fn ranges(mut self, range1: impl Into<String>, range2: impl Into<String>) -> Self {
+    self.ranges = Some((range1.into(), range2.into()));
+    self
+  }
+
+  /// How much a commit may change and still be paired with its old version,
+  /// as a percentage. The default is 60, higher values pair up more commits.
+  pub fn creation_factor(mut self, percent: u32) -> Self {
+    self.creation_factor = Some(percent);
+    self
+  }
+
+  /// Don't color the lines of the inner diffs by which version they come
+  /// from. Only the outer diff markers are colored.
+  pub fn no_dual_color(mut self) -> Self {
+    self.no_dual_color = true;
+    self
+  }
+
+  /// Only show the commits of the old version, hiding the ones that were
+  /// added in the new version
+  pub fn left_only(mut self) -> Self {
+    self.left_only = true;
+    self
+  }
+
+  /// Only show the commits of the new version, hiding the ones that were
+  /// removed from the old version
+  pub fn right_only(mut self) -> Self {
+    self.right_only = true;
+    self
+  }
+
+  /// Pass an option such as `"--stat"` or `"--unified=1"` through to the diffs
+  /// that are generated for each commit. This can be called multiple times to
+  /// pass more options.
+  pub fn diff_opts(mut self, option: impl Into<String>) -> Self {
+    self.diff_opts.push(option.into());
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("range-diff");
+
+    if let Some(factor) = self.creation_factor {
+      args.arg(format!("--creation-factor={}", factor));
+    }
+    if self.no_dual_color {
+      args.arg("--no-dual-color");
+    }
+    if self.left_only {
+      args.arg("--left-only");
+    }
+    if self.right_only {
+      args.arg("--right-only");
+    }
+    args.args(self.diff_opts);
+    if let Some((range1, range2)) = self.ranges {
+      args.arg(range1);
+      args.arg(range2);
+    }
+    args
+  }
+}
+
+impl_builder!(GitRangeDiffBuilder);
@@ -0,0 +1,162 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git read-tree` command created by calling
+/// `Git::read_tree()`. This reads tree objects into the index without
+/// touching the working tree, unless `update` is set.
+#[derive(Clone)]
+pub struct GitReadTreeBuilder {
+  git: GitBuilder,
+  tree_ishes: Vec<String>,
+  merge: bool,
+  reset: bool,
+  update: bool,
+  index_output: Option<PathBuf>,
+  dry_run: bool,
+  trivial: bool,
+  aggressive: bool,
+  prefix: Option<String>,
+  empty: bool,
+}
+
+impl GitReadTreeBuilder {
+  /// Internal function used by `Git`. `Git::read_tree()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitReadTreeBuilder {
+      git,
+      tree_ishes: Vec::new(),
+      merge: false,
+      reset: false,
+      update: false,
+      index_output: None,
+      dry_run: false,
+      trivial: false,
+      aggressive: false,
+      prefix: None,
+      empty: false,
+    }
+  }
+
+  /// The tree to read into the index. This can be called up to three times
+  /// with `merge` to do a two or three way merge of the trees.
+  pub fn tree_ish(mut self, tree_ish: impl Into<String>) -> Self {
+    self.tree_ishes.push(tree_ish.into());
+    self
+  }
+
+  /// Merge the trees into the index instead of just reading them, keeping
+  /// the stat information of entries that don't change
+  pub fn merge(mut self) -> Self {
+    self.merge = true;
+    self
+  }
+
+  /// Like `merge`, but discard unmerged entries instead of failing
+  pub fn reset(mut self) -> Self {
+    self.reset = true;
+    self
+  }
+
+  /// After a successful merge, update the files in the working tree with the
+  /// result. Requires `merge`, `reset`, or `prefix`.
+  pub fn update(mut self) -> Self {
+    self.update = true;
+    self
+  }
+
+  /// Write the resulting index to the given file instead of `$GIT_INDEX_FILE`
+  pub fn index_output(mut self, path: impl Into<PathBuf>) -> Self {
+    self.index_output = Some(path.into());
+    self
+  }
+
+  /// Check whether the command would error out, without updating the index or
+  /// the working tree
+  pub fn dry_run(mut self) -> Self {
+    self.dry_run = true;
+    self
+  }
+
+  /// Only do a three way merge if no file level merging is needed, and leave
+  /// the conflicts unresolved otherwise
+  pub fn trivial(mut self) -> Self {
+    self.trivial = true;
+    self
+  }
+
+  /// Resolve a few more trivial cases during a three way merge, such as a path
+  /// deleted on one side or on both
+  pub fn aggressive(mut self) -> Self {
+    self.aggressive = true;
+    self
+  }
+
+  /// Read the tree into the index under the given directory, e.g. `"vendor/"`
+  /// with a trailing slash. Nothing may already be in the index under it.
+  pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+    self.prefix = Some(prefix.into());
+    self
+  }
+
+  /// Empty the index instead of reading a tree into it
+  pub fn empty(mut self) -> Self {
+    self.empty = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("read-tree");
+
+    if self.merge {
+      args.arg("-m");
+    }
+    if self.reset {
+      args.arg("--reset");
+    }
+    if self.update {
+      args.arg("-u");
+    }
+    if let Some(path) = self.index_output {
+      args.arg("--index-output");
+      args.arg(path);
+    }
+    if self.dry_run {
+      args.arg("--dry-run");
+    }
+    if self.trivial {
+      args.arg("--trivial");
+    }
+    if self.aggressive {
+      args.arg("--aggressive");
+    }
+    if let Some(prefix) = self.prefix {
+      args.arg(format!("--prefix={}", prefix));
+    }
+    if self.empty {
+      args.arg("--empty");
+    }
+    args.args(self.tree_ishes);
+    args
+  }
+}
+
+impl_builder!(GitReadTreeBuilder);
@@ -0,0 +1,143 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git reflog` command created by calling
+/// `Git::reflog()`. An operation must be chosen with `op` before calling
+/// `make_cmd`.
+#[derive(Clone)]
+pub struct GitReflogBuilder {
+  git: GitBuilder,
+  op: Option<ReflogOp>,
+}
+
+impl GitReflogBuilder {
+  /// Internal function used by `Git`. `Git::reflog()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitReflogBuilder { git, op: None }
+  }
+
+  /// Choose which `git reflog` subcommand to run. See `ReflogOp` for what each
+  /// of them do. Calling this again replaces the previously set operation.
+  pub fn op(mut self, op: ReflogOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("reflog");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      ReflogOp::Show { ref_name, format } => {
+        args.arg("show");
+        if let Some(format) = format {
+          args.arg(format!("--format={}", format));
+        }
+        if let Some(ref_name) = ref_name {
+          args.arg(ref_name);
+        }
+      }
+      ReflogOp::Expire {
+        all,
+        expire,
+        expire_unreachable,
+        refs,
+      } => {
+        args.arg("expire");
+        if all {
+          args.arg("--all");
+        }
+        if let Some(time) = expire {
+          args.arg(format!("--expire={}", time));
+        }
+        if let Some(time) = expire_unreachable {
+          args.arg(format!("--expire-unreachable={}", time));
+        }
+        args.args(refs);
+      }
+      ReflogOp::Delete(entries) => {
+        args.arg("delete");
+        args.args(entries);
+      }
+      ReflogOp::Exists(ref_name) => {
+        args.arg("exists");
+        args.arg(ref_name);
+      }
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitReflogBuilder,
+  panics = "This will panic if no operation was set with `op`"
+);
+
+/// The operations that can be passed to `GitReflogBuilder::op`
+#[derive(Clone)]
+pub enum ReflogOp {
+  /// Show the log of a reference
+  Show {
+    /// The reference to show the log of. Defaults to `HEAD`.
+    ref_name: Option<String>,
+    /// Pretty-print the entries in the given format, as with `git log`
+    format: Option<String>,
+  },
+  /// Prune reflog entries older than a certain time
+  Expire {
+    /// Process the reflogs of all references
+    all: bool,
+    /// Prune entries older than this time such as `"now"` or `"90.days.ago"`
+    expire: Option<String>,
+    /// Prune entries older than this time that aren't reachable from the
+    /// current tip of the branch
+    expire_unreachable: Option<String>,
+    /// The references to process the reflogs of
+    refs: Vec<String>,
+  },
+  /// Delete single entries such as `"HEAD@{2}"` from the reflog
+  Delete(Vec<String>),
+  /// Check whether a ref has a reflog, exiting with a zero status if it does
+  Exists(String),
+}
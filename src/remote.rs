@@ -0,0 +1,254 @@
+use crate::{impl_builder, Args, GitBuilder, ParseError};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git remote` command created by calling `Git::remote()`.
+/// With no operation set this lists the existing remotes, otherwise the last
+/// operation function called decides which `git remote` subcommand is run.
+#[derive(Clone)]
+pub struct GitRemoteBuilder {
+  git: GitBuilder,
+  verbose: bool,
+  op: Option<RemoteOp>,
+}
+
+/// The `git remote` subcommands that `GitRemoteBuilder` can run
+#[derive(Clone)]
+enum RemoteOp {
+  Add { name: String, url: String },
+  Remove(String),
+  Rename { old: String, new: String },
+  SetUrl { name: String, url: String },
+  GetUrl(String),
+  Show(Option<String>),
+  Prune(String),
+  List,
+}
+
+impl GitRemoteBuilder {
+  /// Internal function used by `Git`. `Git::remote()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitRemoteBuilder {
+      git,
+      verbose: false,
+      op: None,
+    }
+  }
+
+  /// Be a little more verbose and show the remote url after the name. When
+  /// listing remotes the output of this can be fed to
+  /// `GitRemoteEntry::parse_verbose_output`.
+  pub fn verbose(mut self) -> Self {
+    self.verbose = true;
+    self
+  }
+
+  /// Add a remote named `name` for the repository at `url`. Equivalent to `git
+  /// remote add <name> <url>`
+  pub fn add(mut self, name: impl Into<String>, url: impl Into<String>) -> Self {
+    self.op = Some(RemoteOp::Add {
+      name: name.into(),
+      url: url.into(),
+    });
+    self
+  }
+
+  /// Remove the remote named `name`. All remote-tracking branches and
+  /// configuration settings for the remote are removed. Equivalent to `git
+  /// remote remove <name>`
+  pub fn remove(mut self, name: impl Into<String>) -> Self {
+    self.op = Some(RemoteOp::Remove(name.into()));
+    self
+  }
+
+  /// Rename the remote named `old` to `new`. All remote-tracking branches and
+  /// configuration settings for the remote are updated. Equivalent to `git
+  /// remote rename <old> <new>`
+  pub fn rename(mut self, old: impl Into<String>, new: impl Into<String>) -> Self {
+    self.op = Some(RemoteOp::Rename {
+      old: old.into(),
+      new: new.into(),
+    });
+    self
+  }
+
+  /// Change the url of the remote named `name`. Equivalent to `git remote
+  /// set-url <name> <url>`
+  pub fn set_url(mut self, name: impl Into<String>, url: impl Into<String>) -> Self {
+    self.op = Some(RemoteOp::SetUrl {
+      name: name.into(),
+      url: url.into(),
+    });
+    self
+  }
+
+  /// Retrieve the url of the remote named `name`. Equivalent to `git remote
+  /// get-url <name>`
+  pub fn get_url(mut self, name: impl Into<String>) -> Self {
+    self.op = Some(RemoteOp::GetUrl(name.into()));
+    self
+  }
+
+  /// Give some information about the remote named `name`, or list the remotes
+  /// if `None` is passed in. Equivalent to `git remote show [<name>]`
+  pub fn show(mut self, name: Option<impl Into<String>>) -> Self {
+    self.op = Some(RemoteOp::Show(name.map(Into::into)));
+    self
+  }
+
+  /// Delete stale references associated with the remote named `name`.
+  /// Equivalent to `git remote prune <name>`
+  pub fn prune(mut self, name: impl Into<String>) -> Self {
+    self.op = Some(RemoteOp::Prune(name.into()));
+    self
+  }
+
+  /// List the existing remotes. This is what happens if no other operation is
+  /// set, but it can be used to explicitly override a previous operation.
+  pub fn list(mut self) -> Self {
+    self.op = Some(RemoteOp::List);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("remote");
+
+    if self.verbose {
+      args.arg("--verbose");
+    }
+
+    match self.op {
+      Some(RemoteOp::Add { name, url }) => {
+        args.arg("add");
+        args.arg(name);
+        args.arg(url);
+      }
+      Some(RemoteOp::Remove(name)) => {
+        args.arg("remove");
+        args.arg(name);
+      }
+      Some(RemoteOp::Rename { old, new }) => {
+        args.arg("rename");
+        args.arg(old);
+        args.arg(new);
+      }
+      Some(RemoteOp::SetUrl { name, url }) => {
+        args.arg("set-url");
+        args.arg(name);
+        args.arg(url);
+      }
+      Some(RemoteOp::GetUrl(name)) => {
+        args.arg("get-url");
+        args.arg(name);
+      }
+      Some(RemoteOp::Show(name)) => {
+        args.arg("show");
+        if let Some(name) = name {
+          args.arg(name);
+        }
+      }
+      Some(RemoteOp::Prune(name)) => {
+        args.arg("prune");
+        args.arg(name);
+      }
+      Some(RemoteOp::List) | None => {}
+    }
+    args
+  }
+}
+
+impl_builder!(GitRemoteBuilder);
+
+/// A single remote as listed by `git remote --verbose`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRemoteEntry {
+  /// The name of the remote e.g. `origin`
+  pub name: String,
+  /// The url objects are fetched from
+  pub fetch_url: String,
+  /// The urls objects are pushed to. Git pushes to all of them, so there can
+  /// be several of these when more than one was added with `git remote
+  /// set-url --add --push`.
+  pub push_urls: Vec<String>,
+}
+
+impl GitRemoteEntry {
+  /// Parse the output of `git remote --verbose` into one entry per remote, in
+  /// the order git listed them. Git only ever lists one fetch url per remote,
+  /// so more than one is an error, but every push url is collected. If git
+  /// only lists a fetch url for a remote then the push url is taken to be the
+  /// same as the fetch url.
+  pub fn parse_verbose_output(bytes: &[u8]) -> Result<Vec<Self>, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    let mut entries: Vec<GitRemoteEntry> = Vec::new();
+    for line in output.lines().filter(|line| !line.is_empty()) {
+      let (name, rest) = line
+        .split_once('\t')
+        .ok_or_else(|| ParseError::new(format!("missing remote name in line: {}", line)))?;
+      let (url, kind) = rest
+        .rsplit_once(' ')
+        .ok_or_else(|| ParseError::new(format!("missing url type in line: {}", line)))?;
+
+      let pos = match entries.iter().position(|entry| entry.name == name) {
+        Some(pos) => pos,
+        None => {
+          entries.push(GitRemoteEntry {
+            name: name.into(),
+            fetch_url: String::new(),
+            push_urls: Vec::new(),
+          });
+          entries.len() - 1
+        }
+      };
+      let entry = &mut entries[pos];
+      match kind {
+        "(fetch)" if !entry.fetch_url.is_empty() => {
+          return Err(ParseError::new(format!(
+            "more than one fetch url for remote {}",
+            name
+          )))
+        }
+        "(fetch)" => entry.fetch_url = url.into(),
+        "(push)" => entry.push_urls.push(url.into()),
+        _ => {
+          return Err(ParseError::new(format!(
+            "unknown url type {} in line: {}",
+            kind, line
+          )))
+        }
+      }
+    }
+
+    for entry in &mut entries {
+      if entry.fetch_url.is_empty() {
+        return Err(ParseError::new(format!(
+          "no fetch url for remote {}",
+          entry.name
+        )));
+      }
+      if entry.push_urls.is_empty() {
+        entry.push_urls.push(entry.fetch_url.clone());
+      }
+    }
+    Ok(entries)
+  }
+}
@@ -0,0 +1,149 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git reset` command created by calling `Git::reset()`.
+///
+/// Only one of the modes `soft`, `mixed`, `hard`, `merge`, and `keep` is used,
+/// whichever was called last. The modes reset the whole tree and git will
+/// refuse to run if they are combined with `pathspecs`. Without a mode,
+/// `pathspecs` resets only the index entries of the given paths to their state
+/// at `commit`.
+#[derive(Clone)]
+pub struct GitResetBuilder {
+  git: GitBuilder,
+  commit: Option<String>,
+  mode: Option<ResetMode>,
+  patch: bool,
+  pathspecs: Vec<PathBuf>,
+}
+
+/// The modes that `git reset` can work in
+#[derive(Clone)]
+enum ResetMode {
+  Soft,
+  Mixed,
+  Hard,
+  Merge,
+  Keep,
+}
+
+impl GitResetBuilder {
+  /// Internal function used by `Git`. `Git::reset()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitResetBuilder {
+      git,
+      commit: None,
+      mode: None,
+      patch: false,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// The commit to reset to. Defaults to `HEAD`.
+  pub fn commit(mut self, commit: impl Into<String>) -> Self {
+    self.commit = Some(commit.into());
+    self
+  }
+
+  /// Does not touch the index file or the working tree at all, but resets the
+  /// head to `commit`. This leaves all your changed files as "Changes to be
+  /// committed".
+  pub fn soft(mut self) -> Self {
+    self.mode = Some(ResetMode::Soft);
+    self
+  }
+
+  /// Resets the index but not the working tree (i.e., the changed files are
+  /// preserved but not marked for commit). This is the default mode.
+  pub fn mixed(mut self) -> Self {
+    self.mode = Some(ResetMode::Mixed);
+    self
+  }
+
+  /// Resets the index and working tree. Any changes to tracked files in the
+  /// working tree since `commit` are discarded.
+  pub fn hard(mut self) -> Self {
+    self.mode = Some(ResetMode::Hard);
+    self
+  }
+
+  /// Resets the index and updates the files in the working tree that are
+  /// different between `commit` and `HEAD`, but keeps those which are
+  /// different between the index and working tree. Useful for aborting a
+  /// merge.
+  pub fn merge(mut self) -> Self {
+    self.mode = Some(ResetMode::Merge);
+    self
+  }
+
+  /// Resets index entries and updates files in the working tree that are
+  /// different between `commit` and `HEAD`. If a file that is different
+  /// between `commit` and `HEAD` has local changes, the reset is aborted.
+  pub fn keep(mut self) -> Self {
+    self.mode = Some(ResetMode::Keep);
+    self
+  }
+
+  /// Interactively select hunks in the difference between the index and
+  /// `commit`. The chosen hunks are applied in reverse to the index.
+  pub fn patch(mut self) -> Self {
+    self.patch = true;
+    self
+  }
+
+  /// Only reset the index entries of the given paths. This can be called
+  /// multiple times to add more paths. The paths are placed after a `--` so
+  /// that they are never confused with ref names. This can't be combined with
+  /// any of the modes.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("reset");
+
+    if let Some(mode) = self.mode {
+      let arg = match mode {
+        ResetMode::Soft => "--soft",
+        ResetMode::Mixed => "--mixed",
+        ResetMode::Hard => "--hard",
+        ResetMode::Merge => "--merge",
+        ResetMode::Keep => "--keep",
+      };
+      args.arg(arg);
+    }
+    if self.patch {
+      args.arg("--patch");
+    }
+    if let Some(commit) = self.commit {
+      args.arg(commit);
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitResetBuilder);
@@ -0,0 +1,227 @@
+use crate::{impl_builder, Args, GitBuilder, ObjectFilter};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git rev-list` command created by calling
+/// `Git::rev_list()`. This lists the commits reachable from the given ones in
+/// reverse chronological order, one hash per line.
+#[derive(Clone)]
+pub struct GitRevListBuilder {
+  git: GitBuilder,
+  commits: Vec<String>,
+  max_count: Option<u32>,
+  skip: Option<u32>,
+  since: Option<String>,
+  until: Option<String>,
+  author: Option<String>,
+  grep: Option<String>,
+  all: bool,
+  ancestry_path: bool,
+  merges: Option<bool>,
+  count: bool,
+  objects: bool,
+  filter: Option<ObjectFilter>,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitRevListBuilder {
+  /// Internal function used by `Git`. `Git::rev_list()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitRevListBuilder {
+      git,
+      commits: Vec::new(),
+      max_count: None,
+      skip: None,
+      since: None,
+      until: None,
+      author: None,
+      grep: None,
+      all: false,
+      ancestry_path: false,
+      merges: None,
+      count: false,
+      objects: false,
+      filter: None,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// The commits to list the history of. Prefix a commit with `^` to exclude
+  /// the commits reachable from it, e.g. `["main", "^v1.0"]`, or use a range
+  /// like `"v1.0..main"`. This can be called multiple times to add more
+  /// commits.
+  pub fn commits(mut self, commits: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.commits.extend(commits.into_iter().map(Into::into));
+    self
+  }
+
+  /// Limit the number of commits to output
+  pub fn max_count(mut self, count: u32) -> Self {
+    self.max_count = Some(count);
+    self
+  }
+
+  /// Skip the given number of commits before starting to show the commit
+  /// output
+  pub fn skip(mut self, count: u32) -> Self {
+    self.skip = Some(count);
+    self
+  }
+
+  /// Show commits more recent than a specific date such as `"2 weeks ago"` or
+  /// `"2020-10-01"`
+  pub fn since(mut self, date: impl Into<String>) -> Self {
+    self.since = Some(date.into());
+    self
+  }
+
+  /// Show commits older than a specific date such as `"2 weeks ago"` or
+  /// `"2020-10-01"`
+  pub fn until(mut self, date: impl Into<String>) -> Self {
+    self.until = Some(date.into());
+    self
+  }
+
+  /// Limit the commits output to ones with author header lines that match the
+  /// given pattern (regular expression)
+  pub fn author(mut self, pattern: impl Into<String>) -> Self {
+    self.author = Some(pattern.into());
+    self
+  }
+
+  /// Limit the commits output to ones with a log message that matches the
+  /// given pattern (regular expression)
+  pub fn grep(mut self, pattern: impl Into<String>) -> Self {
+    self.grep = Some(pattern.into());
+    self
+  }
+
+  /// Pretend as if all the refs in `refs/`, along with `HEAD`, are listed on
+  /// the command line as commits to show
+  pub fn all(mut self) -> Self {
+    self.all = true;
+    self
+  }
+
+  /// When given a range like `"A..B"`, only show the commits that are both
+  /// descendants of `A` and ancestors of `B`
+  pub fn ancestry_path(mut self) -> Self {
+    self.ancestry_path = true;
+    self
+  }
+
+  /// Only show merge commits. This and `no_merges` override each other, so
+  /// whichever one is called last is used.
+  pub fn merges(mut self) -> Self {
+    self.merges = Some(true);
+    self
+  }
+
+  /// Don't show merge commits
+  pub fn no_merges(mut self) -> Self {
+    self.merges = Some(false);
+    self
+  }
+
+  /// Print the number of commits that would have been listed instead of
+  /// listing them
+  pub fn count(mut self) -> Self {
+    self.count = true;
+    self
+  }
+
+  /// Also list the trees and blobs the commits refer to, along with the path
+  /// they were found at
+  pub fn objects(mut self) -> Self {
+    self.objects = true;
+    self
+  }
+
+  /// Leave the objects the filter excludes out of the list printed by
+  /// `objects`. See `ObjectFilter` for the available filters.
+  pub fn filter(mut self, filter: ObjectFilter) -> Self {
+    self.filter = Some(filter);
+    self
+  }
+
+  /// Only show commits that touch the given paths. This can be called multiple
+  /// times to add more paths. The paths are placed after a `--` so that they
+  /// are never confused with ref names.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("rev-list");
+
+    if let Some(count) = self.max_count {
+      args.arg(format!("--max-count={}", count));
+    }
+    if let Some(count) = self.skip {
+      args.arg(format!("--skip={}", count));
+    }
+    if let Some(since) = self.since {
+      args.arg(format!("--since={}", since));
+    }
+    if let Some(until) = self.until {
+      args.arg(format!("--until={}", until));
+    }
+    if let Some(author) = self.author {
+      args.arg(format!("--author={}", author));
+    }
+    if let Some(grep) = self.grep {
+      args.arg(format!("--grep={}", grep));
+    }
+    if self.all {
+      args.arg("--all");
+    }
+    if self.ancestry_path {
+      args.arg("--ancestry-path");
+    }
+    match self.merges {
+      Some(true) => {
+        args.arg("--merges");
+      }
+      Some(false) => {
+        args.arg("--no-merges");
+      }
+      None => {}
+    }
+    if self.count {
+      args.arg("--count");
+    }
+    if self.objects {
+      args.arg("--objects");
+    }
+    if let Some(filter) = self.filter {
+      args.arg(format!("--filter={}", filter.to_filter_spec()));
+    }
+    args.args(self.commits);
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitRevListBuilder);
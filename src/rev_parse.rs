@@ -0,0 +1,208 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git rev-parse` command created by calling
+/// `Git::rev_parse()`. This resolves revisions to object names and answers
+/// questions about the repository such as where its top level is.
+#[derive(Clone)]
+pub struct GitRevParseBuilder {
+  git: GitBuilder,
+  revisions: Vec<String>,
+  abbrev_ref: Option<bool>,
+  short: Option<Option<u32>>,
+  verify: bool,
+  symbolic: bool,
+  symbolic_full_name: bool,
+  show_toplevel: bool,
+  git_dir: bool,
+  absolute_git_dir: bool,
+  is_inside_work_tree: bool,
+  is_inside_git_dir: bool,
+  is_bare_repository: bool,
+  sq: bool,
+}
+
+impl GitRevParseBuilder {
+  /// Internal function used by `Git`. `Git::rev_parse()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitRevParseBuilder {
+      git,
+      revisions: Vec::new(),
+      abbrev_ref: None,
+      short: None,
+      verify: false,
+      symbolic: false,
+      symbolic_full_name: false,
+      show_toplevel: false,
+      git_dir: false,
+      absolute_git_dir: false,
+      is_inside_work_tree: false,
+      is_inside_git_dir: false,
+      is_bare_repository: false,
+      sq: false,
+    }
+  }
+
+  /// The revisions to resolve such as `"HEAD"` or `"main~2"`. This can be
+  /// called multiple times to add more revisions.
+  pub fn revisions(mut self, revisions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.revisions.extend(revisions.into_iter().map(Into::into));
+    self
+  }
+
+  /// Output a non-ambiguous short name of the objects' names, e.g. `main`
+  /// instead of the commit `HEAD` points at. If `strict` is `true` the name is
+  /// guaranteed to be unambiguous among all refs, otherwise it only has to be
+  /// unambiguous among the refs of the same kind.
+  pub fn abbrev_ref(mut self, strict: bool) -> Self {
+    self.abbrev_ref = Some(strict);
+    self
+  }
+
+  /// Shorten the object names to a unique prefix with at least the given
+  /// length, or the length given by `core.abbrev` if `None` is passed
+  pub fn short(mut self, length: Option<u32>) -> Self {
+    self.short = Some(length);
+    self
+  }
+
+  /// Verify that exactly one revision was given and that it can be turned into
+  /// an object name, failing otherwise
+  pub fn verify(mut self) -> Self {
+    self.verify = true;
+    self
+  }
+
+  /// Output the revisions in a form as close to the original input as possible
+  pub fn symbolic(mut self) -> Self {
+    self.symbolic = true;
+    self
+  }
+
+  /// Like `symbolic`, but output the full ref names such as
+  /// `refs/heads/main`, omitting inputs that aren't refs
+  pub fn symbolic_full_name(mut self) -> Self {
+    self.symbolic_full_name = true;
+    self
+  }
+
+  /// Show the absolute path of the top-level directory of the working tree
+  pub fn show_toplevel(mut self) -> Self {
+    self.show_toplevel = true;
+    self
+  }
+
+  /// Show the path to the `.git` directory, relative to the current directory
+  /// where possible
+  pub fn git_dir(mut self) -> Self {
+    self.git_dir = true;
+    self
+  }
+
+  /// Like `git_dir`, but the path is always absolute
+  pub fn absolute_git_dir(mut self) -> Self {
+    self.absolute_git_dir = true;
+    self
+  }
+
+  /// Print `true` if the current directory is inside the working tree of the
+  /// repository, `false` otherwise
+  pub fn is_inside_work_tree(mut self) -> Self {
+    self.is_inside_work_tree = true;
+    self
+  }
+
+  /// Print `true` if the current directory is below the repository directory,
+  /// `false` otherwise
+  pub fn is_inside_git_dir(mut self) -> Self {
+    self.is_inside_git_dir = true;
+    self
+  }
+
+  /// Print `true` if the repository is bare, `false` otherwise
+  pub fn is_bare_repository(mut self) -> Self {
+    self.is_bare_repository = true;
+    self
+  }
+
+  /// Output a single line properly quoted for consumption by the shell
+  pub fn sq(mut self) -> Self {
+    self.sq = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("rev-parse");
+
+    if self.sq {
+      args.arg("--sq");
+    }
+    if self.verify {
+      args.arg("--verify");
+    }
+    match self.abbrev_ref {
+      Some(true) => {
+        args.arg("--abbrev-ref=strict");
+      }
+      Some(false) => {
+        args.arg("--abbrev-ref=loose");
+      }
+      None => {}
+    }
+    match self.short {
+      Some(Some(length)) => {
+        args.arg(format!("--short={}", length));
+      }
+      Some(None) => {
+        args.arg("--short");
+      }
+      None => {}
+    }
+    if self.symbolic {
+      args.arg("--symbolic");
+    }
+    if self.symbolic_full_name {
+      args.arg("--symbolic-full-name");
+    }
+    if self.show_toplevel {
+      args.arg("--show-toplevel");
+    }
+    if self.git_dir {
+      args.arg("--git-dir");
+    }
+    if self.absolute_git_dir {
+      args.arg("--absolute-git-dir");
+    }
+    if self.is_inside_work_tree {
+      args.arg("--is-inside-work-tree");
+    }
+    if self.is_inside_git_dir {
+      args.arg("--is-inside-git-dir");
+    }
+    if self.is_bare_repository {
+      args.arg("--is-bare-repository");
+    }
+    args.args(self.revisions);
+    args
+  }
+}
+
+impl_builder!(GitRevParseBuilder);
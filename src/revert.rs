@@ -0,0 +1,167 @@
+use crate::{impl_builder, Args, GitBuilder, MergeStrategy};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git revert` command created by calling `Git::revert()`.
+///
+/// `abort`, `continue_`, and `quit` control a revert that is already in
+/// progress (e.g. one that stopped because of a conflict) and are meant to be
+/// used on their own without `commits` or any of the other options.
+#[derive(Clone)]
+pub struct GitRevertBuilder {
+  git: GitBuilder,
+  commits: Vec<String>,
+  no_commit: bool,
+  signoff: bool,
+  mainline: Option<u32>,
+  no_edit: bool,
+  strategy: Option<MergeStrategy>,
+  strategy_options: Vec<String>,
+  sequencer: Option<Sequencer>,
+}
+
+/// The subcommands for a revert that is in progress
+#[derive(Clone)]
+enum Sequencer {
+  Abort,
+  Continue,
+  Quit,
+}
+
+impl GitRevertBuilder {
+  /// Internal function used by `Git`. `Git::revert()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitRevertBuilder {
+      git,
+      commits: Vec::new(),
+      no_commit: false,
+      signoff: false,
+      mainline: None,
+      no_edit: false,
+      strategy: None,
+      strategy_options: Vec::new(),
+      sequencer: None,
+    }
+  }
+
+  /// The commits to revert. This can be called multiple times to add more
+  /// commits.
+  pub fn commits(mut self, commits: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.commits.extend(commits.into_iter().map(Into::into));
+    self
+  }
+
+  /// Apply the changes necessary to revert the commits to the working tree and
+  /// the index, but don't make any commits
+  pub fn no_commit(mut self) -> Self {
+    self.no_commit = true;
+    self
+  }
+
+  /// Add a `Signed-off-by` trailer at the end of the commit message
+  pub fn signoff(mut self) -> Self {
+    self.signoff = true;
+    self
+  }
+
+  /// Usually you cannot revert a merge because you do not know which side of
+  /// the merge should be considered the mainline. This option specifies the
+  /// parent number (starting from 1) of the mainline and allows revert to
+  /// reverse the change relative to the specified parent.
+  pub fn mainline(mut self, parent: u32) -> Self {
+    self.mainline = Some(parent);
+    self
+  }
+
+  /// Don't start the commit message editor and use the generated message
+  pub fn no_edit(mut self) -> Self {
+    self.no_edit = true;
+    self
+  }
+
+  /// Use the given merge strategy. See `MergeStrategy` for the available
+  /// strategies.
+  pub fn strategy(mut self, strategy: MergeStrategy) -> Self {
+    self.strategy = Some(strategy);
+    self
+  }
+
+  /// Pass an option specific to the merge strategy, e.g. `"ours"` or
+  /// `"theirs"` to resolve conflicting hunks in favor of one side. This can be
+  /// called multiple times to pass more options.
+  pub fn strategy_option(mut self, option: impl Into<String>) -> Self {
+    self.strategy_options.push(option.into());
+    self
+  }
+
+  /// Cancel the operation and return to the pre-sequence state
+  pub fn abort(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Abort);
+    self
+  }
+
+  /// Continue the operation in progress after resolving a conflict
+  pub fn continue_(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Continue);
+    self
+  }
+
+  /// Forget about the current operation in progress. Can be used to clear the
+  /// sequencer state after a failed revert.
+  pub fn quit(mut self) -> Self {
+    self.sequencer = Some(Sequencer::Quit);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("revert");
+
+    if let Some(sequencer) = self.sequencer {
+      let arg = match sequencer {
+        Sequencer::Abort => "--abort",
+        Sequencer::Continue => "--continue",
+        Sequencer::Quit => "--quit",
+      };
+      args.arg(arg);
+    }
+    if self.no_commit {
+      args.arg("--no-commit");
+    }
+    if self.signoff {
+      args.arg("--signoff");
+    }
+    if let Some(parent) = self.mainline {
+      args.arg(format!("--mainline={}", parent));
+    }
+    if self.no_edit {
+      args.arg("--no-edit");
+    }
+    if let Some(strategy) = self.strategy {
+      args.arg(format!("--strategy={}", strategy.to_flag_value()));
+    }
+    for option in self.strategy_options {
+      args.arg(format!("--strategy-option={}", option));
+    }
+    args.args(self.commits);
+    args
+  }
+}
+
+impl_builder!(GitRevertBuilder);
@@ -0,0 +1,127 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git rm` command created by calling `Git::rm()`
+#[derive(Clone)]
+pub struct GitRmBuilder {
+  git: GitBuilder,
+  pathspecs: Vec<PathBuf>,
+  cached: bool,
+  force: bool,
+  dry_run: bool,
+  recursive: bool,
+  quiet: bool,
+  ignore_unmatch: bool,
+}
+
+impl GitRmBuilder {
+  /// Internal function used by `Git`. `Git::rm()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitRmBuilder {
+      git,
+      pathspecs: Vec::new(),
+      cached: false,
+      force: false,
+      dry_run: false,
+      recursive: false,
+      quiet: false,
+      ignore_unmatch: false,
+    }
+  }
+
+  /// The files to remove. This can be called multiple times to add more paths.
+  /// The paths are placed after a `--` so that they are never confused with
+  /// options.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// Only remove the paths from the index. Working tree files, whether
+  /// modified or not, will be left alone.
+  pub fn cached(mut self) -> Self {
+    self.cached = true;
+    self
+  }
+
+  /// Override the up-to-date check that stops files with local changes from
+  /// being removed
+  pub fn force(mut self) -> Self {
+    self.force = true;
+    self
+  }
+
+  /// Don't actually remove any file(s). Instead, just show if they exist in
+  /// the index and would otherwise be removed by the command.
+  pub fn dry_run(mut self) -> Self {
+    self.dry_run = true;
+    self
+  }
+
+  /// Allow recursive removal when a leading directory name is given
+  pub fn recursive(mut self) -> Self {
+    self.recursive = true;
+    self
+  }
+
+  /// Suppress the one line of output git normally prints for each file removed
+  pub fn quiet(mut self) -> Self {
+    self.quiet = true;
+    self
+  }
+
+  /// Exit with a zero status even if no files matched
+  pub fn ignore_unmatch(mut self) -> Self {
+    self.ignore_unmatch = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("rm");
+
+    if self.cached {
+      args.arg("--cached");
+    }
+    if self.force {
+      args.arg("--force");
+    }
+    if self.dry_run {
+      args.arg("--dry-run");
+    }
+    if self.recursive {
+      args.arg("-r");
+    }
+    if self.quiet {
+      args.arg("--quiet");
+    }
+    if self.ignore_unmatch {
+      args.arg("--ignore-unmatch");
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitRmBuilder);
@@ -0,0 +1,136 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git shortlog` command created by calling
+/// `Git::shortlog()`.
+///
+/// If no `revision_range` is given and stdin isn't a terminal, git reads a log
+/// from stdin instead of walking the history, so set one, e.g. `"HEAD"`, when
+/// running this from a program.
+#[derive(Clone)]
+pub struct GitShortlogBuilder {
+  git: GitBuilder,
+  summary: bool,
+  numbered: bool,
+  email: bool,
+  group: Option<ShortlogGroup>,
+  revision_range: Option<String>,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitShortlogBuilder {
+  /// Internal function used by `Git`. `Git::shortlog()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitShortlogBuilder {
+      git,
+      summary: false,
+      numbered: false,
+      email: false,
+      group: None,
+      revision_range: None,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// Suppress the commit descriptions and only provide a commit count summary
+  pub fn summary(mut self) -> Self {
+    self.summary = true;
+    self
+  }
+
+  /// Sort the output according to the number of commits per author instead of
+  /// alphabetically
+  pub fn numbered(mut self) -> Self {
+    self.numbered = true;
+    self
+  }
+
+  /// Show the email address of each author
+  pub fn email(mut self) -> Self {
+    self.email = true;
+    self
+  }
+
+  /// Group the commits by something other than the author. See
+  /// `ShortlogGroup` for the options.
+  pub fn group(mut self, group: ShortlogGroup) -> Self {
+    self.group = Some(group);
+    self
+  }
+
+  /// Only show the commits in the given range such as `"v1.0..HEAD"`
+  pub fn revision_range(mut self, range: impl Into<String>) -> Self {
+    self.revision_range = Some(range.into());
+    self
+  }
+
+  /// Only consider commits that touch the given paths. This can be called
+  /// multiple times to add more paths. The paths are placed after a `--` so
+  /// that they are never confused with revisions.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("shortlog");
+
+    if self.summary {
+      args.arg("--summary");
+    }
+    if self.numbered {
+      args.arg("--numbered");
+    }
+    if self.email {
+      args.arg("--email");
+    }
+    if let Some(group) = self.group {
+      let arg = match group {
+        ShortlogGroup::Author => "author".into(),
+        ShortlogGroup::Committer => "committer".into(),
+        ShortlogGroup::Trailer(trailer) => format!("trailer:{}", trailer),
+      };
+      args.arg(format!("--group={}", arg));
+    }
+    if let Some(range) = self.revision_range {
+      args.arg(range);
+    }
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitShortlogBuilder);
+
+/// Options for the `group` function
+#[derive(Clone)]
+pub enum ShortlogGroup {
+  /// Group commits by their author. This is the default.
+  Author,
+  /// Group commits by their committer
+  Committer,
+  /// Group commits by the value of the given trailer such as `"Co-authored-by"`
+  Trailer(String),
+}
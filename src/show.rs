@@ -0,0 +1,165 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git show` command created by calling `Git::show()`
+#[derive(Clone)]
+pub struct GitShowBuilder {
+  git: GitBuilder,
+  objects: Vec<String>,
+  format: Option<String>,
+  oneline: bool,
+  stat: bool,
+  shortstat: bool,
+  name_only: bool,
+  name_status: bool,
+  no_patch: bool,
+  unified: Option<u32>,
+  pathspecs: Vec<PathBuf>,
+}
+
+impl GitShowBuilder {
+  /// Internal function used by `Git`. `Git::show()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitShowBuilder {
+      git,
+      objects: Vec::new(),
+      format: None,
+      oneline: false,
+      stat: false,
+      shortstat: false,
+      name_only: false,
+      name_status: false,
+      no_patch: false,
+      unified: None,
+      pathspecs: Vec::new(),
+    }
+  }
+
+  /// The object to show, which defaults to `HEAD`. This can be anything git
+  /// can resolve to an object such as a SHA, a branch or tag name, `HEAD~1`,
+  /// or `<commit>:<path>` to show the contents of a file at a commit. This can
+  /// be called multiple times to show more objects.
+  pub fn object(mut self, object: impl Into<String>) -> Self {
+    self.objects.push(object.into());
+    self
+  }
+
+  /// Pretty-print commits in the given format. This can be one of the named
+  /// formats such as `medium` or `fuller`, or a format string like
+  /// `format:%H %s`. See the PRETTY FORMATS section of `git help show` for
+  /// the placeholders you can use.
+  pub fn format(mut self, format: impl Into<String>) -> Self {
+    self.format = Some(format.into());
+    self
+  }
+
+  /// Show commits on a single line made up of the abbreviated hash and the
+  /// title line of the commit message
+  pub fn oneline(mut self) -> Self {
+    self.oneline = true;
+    self
+  }
+
+  /// Show a diffstat instead of a patch
+  pub fn stat(mut self) -> Self {
+    self.stat = true;
+    self
+  }
+
+  /// Output only the last line of the diffstat which contains the total number
+  /// of modified files, as well as the number of added and deleted lines
+  pub fn shortstat(mut self) -> Self {
+    self.shortstat = true;
+    self
+  }
+
+  /// Show only the names of the changed files
+  pub fn name_only(mut self) -> Self {
+    self.name_only = true;
+    self
+  }
+
+  /// Show only the names and the status (added, modified, deleted etc.) of the
+  /// changed files
+  pub fn name_status(mut self) -> Self {
+    self.name_status = true;
+    self
+  }
+
+  /// Suppress the diff output so that only the commit information is shown
+  pub fn no_patch(mut self) -> Self {
+    self.no_patch = true;
+    self
+  }
+
+  /// Generate diffs with the given number of lines of context instead of the
+  /// usual three
+  pub fn unified(mut self, lines: u32) -> Self {
+    self.unified = Some(lines);
+    self
+  }
+
+  /// Limit the diff to the given paths. This can be called multiple times to
+  /// add more paths. The paths are placed after a `--` so that they are never
+  /// confused with ref names.
+  pub fn pathspecs(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.pathspecs.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("show");
+
+    if let Some(format) = self.format {
+      args.arg(format!("--format={}", format));
+    }
+    if self.oneline {
+      args.arg("--oneline");
+    }
+    if self.stat {
+      args.arg("--stat");
+    }
+    if self.shortstat {
+      args.arg("--shortstat");
+    }
+    if self.name_only {
+      args.arg("--name-only");
+    }
+    if self.name_status {
+      args.arg("--name-status");
+    }
+    if self.no_patch {
+      args.arg("--no-patch");
+    }
+    if let Some(lines) = self.unified {
+      args.arg(format!("-U{}", lines));
+    }
+    args.args(self.objects);
+    if !self.pathspecs.is_empty() {
+      args.arg("--");
+      args.args(self.pathspecs);
+    }
+    args
+  }
+}
+
+impl_builder!(GitShowBuilder);
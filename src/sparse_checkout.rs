@@ -0,0 +1,171 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git sparse-checkout` command created by calling
+/// `Git::sparse_checkout()`. An operation must be chosen with `op` before
+/// calling `make_cmd`.
+///
+/// Git supports two kinds of patterns: cone mode, where the patterns are
+/// directories and everything inside them is checked out, and non-cone mode,
+/// where the patterns work like a `.gitignore` file. Since git 2.37 cone mode
+/// is the default, so the `cone` fields of `SparseOp` always pass either
+/// `--cone` or `--no-cone` to get the same behavior on every version of git.
+#[derive(Clone)]
+pub struct GitSparseCheckoutBuilder {
+  git: GitBuilder,
+  op: Option<SparseOp>,
+}
+
+impl GitSparseCheckoutBuilder {
+  /// Internal function used by `Git`. `Git::sparse_checkout()` is just a
+  /// wrapper around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitSparseCheckoutBuilder { git, op: None }
+  }
+
+  /// Choose which `git sparse-checkout` subcommand to run. See `SparseOp` for
+  /// what each of them do. Calling this again replaces the previously set
+  /// operation.
+  pub fn op(mut self, op: SparseOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("sparse-checkout");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      SparseOp::Init { cone, sparse_index } => {
+        args.arg("init");
+        args.arg(cone_arg(cone));
+        if sparse_index {
+          args.arg("--sparse-index");
+        }
+      }
+      SparseOp::Set {
+        patterns,
+        cone,
+        stdin,
+      } => {
+        args.arg("set");
+        args.arg(cone_arg(cone));
+        if stdin {
+          args.arg("--stdin");
+        }
+        args.args(patterns);
+      }
+      SparseOp::Add { patterns, stdin } => {
+        args.arg("add");
+        if stdin {
+          args.arg("--stdin");
+        }
+        args.args(patterns);
+      }
+      SparseOp::List => {
+        args.arg("list");
+      }
+      SparseOp::Reapply => {
+        args.arg("reapply");
+      }
+      SparseOp::Disable => {
+        args.arg("disable");
+      }
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitSparseCheckoutBuilder,
+  panics = "This will panic if no operation was set with `op`"
+);
+
+/// Cone mode is always passed explicitly since its default changed in git 2.37
+fn cone_arg(cone: bool) -> &'static str {
+  if cone {
+    "--cone"
+  } else {
+    "--no-cone"
+  }
+}
+
+/// The operations that can be passed to `GitSparseCheckoutBuilder::op`
+#[derive(Clone)]
+pub enum SparseOp {
+  /// Enable sparse checkout with patterns that only match the files in the
+  /// top-level directory. `Set` does this too, so this is mostly kept for
+  /// backwards compatibility.
+  Init {
+    /// Use cone mode patterns. Setting this to `false` passes `--no-cone`.
+    cone: bool,
+    /// Use a sparse index, which only records the directories outside of the
+    /// sparse checkout instead of every file in them
+    sparse_index: bool,
+  },
+  /// Enable sparse checkout if needed and replace the patterns with the given
+  /// ones, updating the working tree to match
+  Set {
+    /// The directories to check out in cone mode, or gitignore style patterns
+    /// otherwise
+    patterns: Vec<String>,
+    /// Use cone mode patterns. Setting this to `false` passes `--no-cone`.
+    cone: bool,
+    /// Read the patterns from stdin, one per line, instead of from `patterns`
+    stdin: bool,
+  },
+  /// Add patterns to the existing ones, updating the working tree to match
+  Add {
+    /// The directories or patterns to add
+    patterns: Vec<String>,
+    /// Read the patterns from stdin, one per line, instead of from `patterns`
+    stdin: bool,
+  },
+  /// List the current patterns
+  List,
+  /// Reapply the patterns to the working tree, e.g. after a merge checked out
+  /// files outside of them
+  Reapply,
+  /// Disable sparse checkout and restore the full working tree
+  Disable,
+}
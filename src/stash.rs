@@ -0,0 +1,300 @@
+use crate::{impl_builder, Args, GitBuilder, GitError, IncompleteArgs, ParseError};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git stash` command created by calling `Git::stash()`.
+/// An operation must be chosen with `op` before calling `make_cmd`.
+#[derive(Clone)]
+pub struct GitStashBuilder {
+  git: GitBuilder,
+  op: Option<StashOp>,
+  list_format: Option<&'static str>,
+}
+
+impl GitStashBuilder {
+  /// Internal function used by `Git`. `Git::stash()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitStashBuilder {
+      git,
+      op: None,
+      list_format: None,
+    }
+  }
+
+  /// Choose which `git stash` subcommand to run. See `StashOp` for what each
+  /// of them do. Calling this again replaces the previously set operation.
+  pub fn op(mut self, op: StashOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("stash");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      StashOp::Push {
+        message,
+        include_untracked,
+        all,
+        pathspec_from_file,
+        pathspec_file_nul,
+      } => {
+        args.arg("push");
+        if let Some(msg) = message {
+          args.arg(format!("--message={}", msg));
+        }
+        if include_untracked {
+          args.arg("--include-untracked");
+        }
+        if all {
+          args.arg("--all");
+        }
+        if let Some(path) = pathspec_from_file {
+          args.arg("--pathspec-from-file");
+          args.arg(path);
+        }
+        if pathspec_file_nul {
+          args.arg("--pathspec-file-nul");
+        }
+      }
+      StashOp::Pop {
+        stash_ref,
+        index_flag,
+      } => {
+        args.arg("pop");
+        if index_flag {
+          args.arg("--index");
+        }
+        if let Some(stash) = stash_ref {
+          args.arg(stash);
+        }
+      }
+      StashOp::Apply {
+        stash_ref,
+        index_flag,
+      } => {
+        args.arg("apply");
+        if index_flag {
+          args.arg("--index");
+        }
+        if let Some(stash) = stash_ref {
+          args.arg(stash);
+        }
+      }
+      StashOp::Drop(stash_ref) => {
+        args.arg("drop");
+        if let Some(stash) = stash_ref {
+          args.arg(stash);
+        }
+      }
+      StashOp::List => {
+        args.arg("list");
+        if let Some(format) = self.list_format {
+          args.arg(format!("--format={}", format));
+        }
+      }
+      StashOp::Show { stash_ref } => {
+        args.arg("show");
+        if let Some(stash) = stash_ref {
+          args.arg(stash);
+        }
+      }
+      StashOp::Branch { name, stash_ref } => {
+        args.arg("branch");
+        args.arg(name);
+        if let Some(stash) = stash_ref {
+          args.arg(stash);
+        }
+      }
+      StashOp::Clear => {
+        args.arg("clear");
+      }
+    }
+    Ok(args)
+  }
+
+  /// List the stash entries and parse them into a `GitStashEntry` each, the
+  /// latest one first. This always runs `git stash list`, replacing any
+  /// operation set with `op`.
+  pub fn entries(mut self) -> Result<Vec<GitStashEntry>, GitError> {
+    self.op = Some(StashOp::List);
+    self.list_format = Some(GitStashEntry::FORMAT);
+    let output = self.execute()?;
+    GitStashEntry::parse_stash_list(&output.stdout).map_err(GitError::ParseError)
+  }
+}
+
+impl_builder!(
+  GitStashBuilder,
+  panics = "This will panic if no operation was set with `op`"
+);
+
+/// The operations that can be passed to `GitStashBuilder::op`. Where a
+/// `stash_ref` is optional it refers to a stash entry like `stash@{1}` and
+/// defaults to the latest one, `stash@{0}`.
+#[derive(Clone)]
+pub enum StashOp {
+  /// Save your local modifications to a new stash entry and roll them back to
+  /// `HEAD`
+  Push {
+    /// A description to use for the stash entry
+    message: Option<String>,
+    /// Also stash untracked files and then clean them up
+    include_untracked: bool,
+    /// Also stash ignored and untracked files and then clean them up
+    all: bool,
+    /// Only stash the files matching the pathspecs read from this file, one
+    /// per line. This is useful when there are too many paths to pass on the
+    /// command line. A path of `-` reads them from stdin.
+    pathspec_from_file: Option<PathBuf>,
+    /// Separate the pathspecs in `pathspec_from_file` with NUL bytes instead
+    /// of newlines
+    pathspec_file_nul: bool,
+  },
+  /// Remove a single stash entry from the stash list and apply it on top of
+  /// the current working tree state
+  Pop {
+    /// The stash entry to pop
+    stash_ref: Option<String>,
+    /// Try to reinstate not only the working tree's changes, but also the
+    /// index's ones
+    index_flag: bool,
+  },
+  /// Like `Pop`, but do not remove the entry from the stash list
+  Apply {
+    /// The stash entry to apply
+    stash_ref: Option<String>,
+    /// Try to reinstate not only the working tree's changes, but also the
+    /// index's ones
+    index_flag: bool,
+  },
+  /// Remove a single stash entry from the list of stash entries
+  Drop(Option<String>),
+  /// List the stash entries that you currently have
+  List,
+  /// Show the changes recorded in the stash entry as a diff between the
+  /// stashed contents and the commit back when the stash entry was created
+  Show {
+    /// The stash entry to show
+    stash_ref: Option<String>,
+  },
+  /// Create and check out a new branch starting from the commit at which the
+  /// stash entry was originally created, apply the changes to it, and drop the
+  /// entry if that succeeded
+  Branch {
+    /// The name of the branch to create
+    name: String,
+    /// The stash entry to turn into a branch
+    stash_ref: Option<String>,
+  },
+  /// Remove all the stash entries. Note that those entries will then be
+  /// subject to pruning, and may be impossible to recover.
+  Clear,
+}
+
+/// A single stash entry parsed from the output of `git stash list` run with
+/// `--format` set to `GitStashEntry::FORMAT`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitStashEntry {
+  /// The position of the entry in the stash list, `1` for `stash@{1}`
+  pub index: u32,
+  /// The branch that was checked out when the entry was made e.g. `main`, or
+  /// `(no branch)` for a detached `HEAD`. This is empty for entries that git
+  /// didn't describe with a branch, such as the ones `git rebase --autostash`
+  /// stores.
+  pub branch: String,
+  /// The message of the entry. Without an explicit message git uses the
+  /// abbreviated hash and subject of the commit it was made on.
+  pub message: String,
+  /// The full hash of the stash commit
+  pub commit_hash: String,
+}
+
+impl GitStashEntry {
+  /// The format to pass to `git stash list --format` to get output that
+  /// `parse_stash_list` understands. The fields are separated by NUL bytes so
+  /// that no message can be confused with the next field.
+  pub const FORMAT: &'static str = "%gd%x00%H%x00%gs";
+
+  /// Parse the output of `git stash list --format=<GitStashEntry::FORMAT>`
+  /// into one entry per stash, in the order git listed them
+  pub fn parse_stash_list(bytes: &[u8]) -> Result<Vec<Self>, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    output
+      .lines()
+      .filter(|line| !line.is_empty())
+      .map(|line| {
+        let fields: Vec<&str> = line.split('\0').collect();
+        if fields.len() != 3 {
+          return Err(ParseError::new(format!(
+            "expected 3 fields but got {} in line: {}",
+            fields.len(),
+            line.replace('\0', " ")
+          )));
+        }
+        let index = fields[0]
+          .strip_prefix("stash@{")
+          .and_then(|index| index.strip_suffix('}'))
+          .and_then(|index| index.parse().ok())
+          .ok_or_else(|| ParseError::new(format!("invalid stash ref: {}", fields[0])))?;
+        // Git describes entries as "On <branch>: <message>", or as
+        // "WIP on <branch>: <hash> <subject>" when no message was given
+        let (branch, message) = fields[2]
+          .strip_prefix("WIP on ")
+          .or_else(|| fields[2].strip_prefix("On "))
+          .and_then(|subject| subject.split_once(": "))
+          .unwrap_or(("", fields[2]));
+        Ok(GitStashEntry {
+          index,
+          branch: branch.into(),
+          message: message.into(),
+          commit_hash: fields[1].into(),
+        })
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,42 @@
+/// A merge strategy for the commands that merge histories, such as
+/// `git cherry-pick` and `git revert`. Use `to_flag_value` to get the value
+/// for git's `--strategy=<strategy>` option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeStrategy {
+  /// The default strategy since git 2.34 for merging two heads, `ort`
+  Ort,
+  /// The default strategy before `Ort`, `recursive`. Newer versions of git
+  /// treat this as a synonym for `Ort`.
+  Recursive,
+  /// Merge two heads using a three-way merge with a single common ancestor,
+  /// `resolve`
+  Resolve,
+  /// The default strategy for merging more than two heads, `octopus`. This
+  /// refuses to do a merge that needs manual conflict resolution.
+  Octopus,
+  /// Keep the tree of the current branch and ignore all changes from the
+  /// other branches, `ours`. Not to be confused with the `ours` option of the
+  /// `Ort` strategy.
+  Ours,
+  /// A modified `Ort` that adjusts the trees when one of them is a
+  /// subdirectory of the other, `subtree`
+  Subtree,
+  /// The name of a strategy passed to git as is. Git runs
+  /// `git-merge-<name>` for strategies it doesn't know itself.
+  Custom(String),
+}
+
+impl MergeStrategy {
+  /// The strategy name to pass to git as `--strategy=<strategy>`
+  pub fn to_flag_value(&self) -> &str {
+    match self {
+      MergeStrategy::Ort => "ort",
+      MergeStrategy::Recursive => "recursive",
+      MergeStrategy::Resolve => "resolve",
+      MergeStrategy::Octopus => "octopus",
+      MergeStrategy::Ours => "ours",
+      MergeStrategy::Subtree => "subtree",
+      MergeStrategy::Custom(name) => name,
+    }
+  }
+}
@@ -0,0 +1,297 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git submodule` command created by calling
+/// `Git::submodule()`. An operation must be chosen with `op` before calling
+/// `make_cmd`.
+#[derive(Clone)]
+pub struct GitSubmoduleBuilder {
+  git: GitBuilder,
+  op: Option<SubmoduleOp>,
+}
+
+impl GitSubmoduleBuilder {
+  /// Internal function used by `Git`. `Git::submodule()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitSubmoduleBuilder { git, op: None }
+  }
+
+  /// Choose which `git submodule` subcommand to run. See `SubmoduleOp` for
+  /// what each of them do. Calling this again replaces the previously set
+  /// operation.
+  pub fn op(mut self, op: SubmoduleOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("submodule");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      SubmoduleOp::Add {
+        repository,
+        path,
+        branch,
+        depth,
+        force,
+      } => {
+        args.arg("add");
+        if let Some(branch) = branch {
+          args.arg("--branch");
+          args.arg(branch);
+        }
+        if let Some(depth) = depth {
+          args.arg(format!("--depth={}", depth));
+        }
+        if force {
+          args.arg("--force");
+        }
+        args.arg("--");
+        args.arg(repository);
+        if let Some(path) = path {
+          args.arg(path);
+        }
+      }
+      SubmoduleOp::Init { paths } => {
+        args.arg("init");
+        if !paths.is_empty() {
+          args.arg("--");
+          args.args(paths);
+        }
+      }
+      SubmoduleOp::Update {
+        paths,
+        init,
+        remote,
+        merge,
+        rebase,
+        recursive,
+        depth,
+        force,
+      } => {
+        args.arg("update");
+        if init {
+          args.arg("--init");
+        }
+        if remote {
+          args.arg("--remote");
+        }
+        if merge {
+          args.arg("--merge");
+        }
+        if rebase {
+          args.arg("--rebase");
+        }
+        if recursive {
+          args.arg("--recursive");
+        }
+        if let Some(depth) = depth {
+          args.arg(format!("--depth={}", depth));
+        }
+        if force {
+          args.arg("--force");
+        }
+        if !paths.is_empty() {
+          args.arg("--");
+          args.args(paths);
+        }
+      }
+      SubmoduleOp::SetBranch { path, branch } => {
+        args.arg("set-branch");
+        match branch {
+          Some(branch) => {
+            args.arg("--branch");
+            args.arg(branch);
+          }
+          None => {
+            args.arg("--default");
+          }
+        }
+        args.arg("--");
+        args.arg(path);
+      }
+      SubmoduleOp::Status { recursive, cached } => {
+        args.arg("status");
+        if recursive {
+          args.arg("--recursive");
+        }
+        if cached {
+          args.arg("--cached");
+        }
+      }
+      SubmoduleOp::Sync { recursive } => {
+        args.arg("sync");
+        if recursive {
+          args.arg("--recursive");
+        }
+      }
+      SubmoduleOp::Deinit { paths, all, force } => {
+        args.arg("deinit");
+        if all {
+          args.arg("--all");
+        }
+        if force {
+          args.arg("--force");
+        }
+        if !paths.is_empty() {
+          args.arg("--");
+          args.args(paths);
+        }
+      }
+      SubmoduleOp::Foreach { command, recursive } => {
+        args.arg("foreach");
+        if recursive {
+          args.arg("--recursive");
+        }
+        args.arg(command);
+      }
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitSubmoduleBuilder,
+  panics = "This will panic if no operation was set with `op`"
+);
+
+/// The operations that can be passed to `GitSubmoduleBuilder::op`. Where
+/// `paths` can be given, an empty list means every submodule.
+#[derive(Clone)]
+pub enum SubmoduleOp {
+  /// Add the given repository as a submodule at the given path to the
+  /// changeset to be committed next to the current project
+  Add {
+    /// The URL of the new submodule's origin repository. This may be either
+    /// an absolute URL, or a path relative to the superproject's default
+    /// remote repository.
+    repository: String,
+    /// The path the submodule is cloned to. Defaults to the "humanish" part of
+    /// the repository e.g. `repo` for `/path/to/repo.git`
+    path: Option<PathBuf>,
+    /// The branch of the repository to track
+    branch: Option<String>,
+    /// Create a shallow clone with a history truncated to this many commits
+    depth: Option<u32>,
+    /// Allow adding an otherwise ignored submodule path
+    force: bool,
+  },
+  /// Initialize the submodules recorded in the index by setting
+  /// `submodule.$name.url` in `.git/config`
+  Init {
+    /// The submodules to initialize
+    paths: Vec<PathBuf>,
+  },
+  /// Update the registered submodules to match what the superproject expects
+  /// by cloning missing submodules, fetching missing commits in submodules and
+  /// updating the working tree of the submodules
+  Update {
+    /// The submodules to update
+    paths: Vec<PathBuf>,
+    /// Initialize all submodules for which `Init` has not been run before
+    /// updating
+    init: bool,
+    /// Instead of using the superproject's recorded SHA-1 to update the
+    /// submodule, use the status of the submodule's remote-tracking branch.
+    /// The branch can be chosen with `SetBranch`.
+    remote: bool,
+    /// Merge the commit recorded in the superproject into the current branch
+    /// of the submodule
+    merge: bool,
+    /// Rebase the current branch of the submodule onto the commit recorded in
+    /// the superproject
+    rebase: bool,
+    /// Also update nested submodules inside each submodule
+    recursive: bool,
+    /// Create a shallow clone with a history truncated to this many commits
+    depth: Option<u32>,
+    /// Throw away local changes in submodules when switching to a different
+    /// commit, and always run a checkout operation in the submodule
+    force: bool,
+  },
+  /// Set the branch of the submodule's remote that `Update` with `remote`
+  /// follows. This is stored as `submodule.<name>.branch` in `.gitmodules`.
+  SetBranch {
+    /// The path of the submodule
+    path: PathBuf,
+    /// The branch to follow, or `None` to go back to the remote's `HEAD`
+    branch: Option<String>,
+  },
+  /// Show the status of the submodules
+  Status {
+    /// Also show the status of nested submodules
+    recursive: bool,
+    /// Show the commits stored in the index instead of the ones checked out in
+    /// the submodules
+    cached: bool,
+  },
+  /// Synchronize the submodules' remote URL configuration setting to the value
+  /// specified in `.gitmodules`
+  Sync {
+    /// Also synchronize nested submodules
+    recursive: bool,
+  },
+  /// Unregister the given submodules, i.e. remove the whole
+  /// `submodule.$name` section from `.git/config` together with their work
+  /// tree
+  Deinit {
+    /// The submodules to unregister
+    paths: Vec<PathBuf>,
+    /// Unregister all submodules in the working tree. Git refuses to unregister
+    /// everything if `paths` is empty and this isn't set.
+    all: bool,
+    /// Remove the submodule working trees even if they contain local
+    /// modifications
+    force: bool,
+  },
+  /// Run an arbitrary shell command in each checked out submodule
+  Foreach {
+    /// The shell command to run
+    command: String,
+    /// Also run the command in nested submodules
+    recursive: bool,
+  },
+}
@@ -0,0 +1,105 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git symbolic-ref` command created by calling
+/// `Git::symbolic_ref()`. With only `ref_name` set this prints the ref that the
+/// symbolic ref points to, and with `target` set too it points it at a new
+/// ref, e.g. to switch `HEAD` to another branch without touching the working
+/// tree.
+#[derive(Clone)]
+pub struct GitSymbolicRefBuilder {
+  git: GitBuilder,
+  ref_name: Option<String>,
+  target: Option<String>,
+  quiet: bool,
+  delete: bool,
+  short: bool,
+}
+
+impl GitSymbolicRefBuilder {
+  /// Internal function used by `Git`. `Git::symbolic_ref()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitSymbolicRefBuilder {
+      git,
+      ref_name: None,
+      target: None,
+      quiet: false,
+      delete: false,
+      short: false,
+    }
+  }
+
+  /// The symbolic ref to read, write, or delete, e.g. `"HEAD"`
+  pub fn ref_name(mut self, name: impl Into<String>) -> Self {
+    self.ref_name = Some(name.into());
+    self
+  }
+
+  /// Point the symbolic ref at this ref, e.g. `"refs/heads/main"`, instead of
+  /// printing what it points at
+  pub fn target(mut self, target: impl Into<String>) -> Self {
+    self.target = Some(target.into());
+    self
+  }
+
+  /// Don't print an error if the ref isn't a symbolic ref, just exit with a
+  /// non-zero status
+  pub fn quiet(mut self) -> Self {
+    self.quiet = true;
+    self
+  }
+
+  /// Delete the symbolic ref
+  pub fn delete(mut self) -> Self {
+    self.delete = true;
+    self
+  }
+
+  /// Print the ref it points at shortened, e.g. `main` instead of
+  /// `refs/heads/main`
+  pub fn short(mut self) -> Self {
+    self.short = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("symbolic-ref");
+
+    if self.quiet {
+      args.arg("--quiet");
+    }
+    if self.delete {
+      args.arg("--delete");
+    }
+    if self.short {
+      args.arg("--short");
+    }
+    if let Some(name) = self.ref_name {
+      args.arg(name);
+    }
+    if let Some(target) = self.target {
+      args.arg(target);
+    }
+    args
+  }
+}
+
+impl_builder!(GitSymbolicRefBuilder);
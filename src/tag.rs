@@ -0,0 +1,210 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git tag` command created by calling `Git::tag()`.
+///
+/// The command works in one of four modes depending on which functions are
+/// called:
+///
+/// - Create (the default if a `name` is given): creates the tag `name`
+///   pointing at `commit_ish`, or `HEAD` if it isn't set. The tag is
+///   lightweight unless `annotate`, `sign`, `message` or `message_file` are
+///   used. `force` replaces an existing tag with the same name.
+/// - Delete (`delete`): deletes the tag `name`.
+/// - List (`list`, or the default if no `name` is given): lists the tags,
+///   optionally only those matching the pattern given with `name`. The listing
+///   can be filtered with `contains` and `points_at` and ordered with `sort`.
+/// - Verify (`verify`): verifies the GPG signature of the tag `name`.
+///
+/// If more than one of `delete`, `list`, and `verify` are set git will reject
+/// the command.
+#[derive(Clone)]
+pub struct GitTagBuilder {
+  git: GitBuilder,
+  name: Option<String>,
+  annotate: bool,
+  sign: bool,
+  message: Option<String>,
+  message_file: Option<PathBuf>,
+  force: bool,
+  delete: bool,
+  list: bool,
+  verify: bool,
+  contains: Option<String>,
+  sort: Option<String>,
+  commit_ish: Option<String>,
+  points_at: Option<String>,
+}
+
+impl GitTagBuilder {
+  /// Internal function used by `Git`. `Git::tag()` is just a wrapper around
+  /// this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitTagBuilder {
+      git,
+      name: None,
+      annotate: false,
+      sign: false,
+      message: None,
+      message_file: None,
+      force: false,
+      delete: false,
+      list: false,
+      verify: false,
+      contains: None,
+      sort: None,
+      commit_ish: None,
+      points_at: None,
+    }
+  }
+
+  /// The name of the tag to create, delete, or verify. When listing tags this
+  /// is instead a shell wildcard pattern the tags must match to be shown.
+  pub fn name(mut self, name: impl Into<String>) -> Self {
+    self.name = Some(name.into());
+    self
+  }
+
+  /// Make an unsigned, annotated tag object
+  pub fn annotate(mut self) -> Self {
+    self.annotate = true;
+    self
+  }
+
+  /// Make a GPG-signed tag, using the default e-mail address's key
+  pub fn sign(mut self) -> Self {
+    self.sign = true;
+    self
+  }
+
+  /// Use the given tag message. This implies `annotate` if neither `annotate`
+  /// or `sign` are set.
+  pub fn message(mut self, msg: impl Into<String>) -> Self {
+    self.message = Some(msg.into());
+    self
+  }
+
+  /// Take the tag message from the given file. This implies `annotate` if
+  /// neither `annotate` or `sign` are set.
+  pub fn message_file(mut self, path: impl Into<PathBuf>) -> Self {
+    self.message_file = Some(path.into());
+    self
+  }
+
+  /// Replace an existing tag with the given name instead of failing
+  pub fn force(mut self) -> Self {
+    self.force = true;
+    self
+  }
+
+  /// Delete the tag with the given name
+  pub fn delete(mut self) -> Self {
+    self.delete = true;
+    self
+  }
+
+  /// List tags, only showing the ones that match the `name` pattern if it is
+  /// set
+  pub fn list(mut self) -> Self {
+    self.list = true;
+    self
+  }
+
+  /// Verify the GPG signature of the tag with the given name
+  pub fn verify(mut self) -> Self {
+    self.verify = true;
+    self
+  }
+
+  /// Only list tags which contain the given commit. Implies `list`.
+  pub fn contains(mut self, commit: impl Into<String>) -> Self {
+    self.contains = Some(commit.into());
+    self
+  }
+
+  /// Sort the listed tags based on the given key such as `refname` or
+  /// `-creatordate`. Prefix the key with `-` to sort in descending order.
+  pub fn sort(mut self, key: impl Into<String>) -> Self {
+    self.sort = Some(key.into());
+    self
+  }
+
+  /// The object the new tag will refer to, usually a commit. Defaults to
+  /// `HEAD`.
+  pub fn commit_ish(mut self, object: impl Into<String>) -> Self {
+    self.commit_ish = Some(object.into());
+    self
+  }
+
+  /// Only list tags of the given object. Implies `list`.
+  pub fn points_at(mut self, object: impl Into<String>) -> Self {
+    self.points_at = Some(object.into());
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("tag");
+
+    if self.annotate {
+      args.arg("--annotate");
+    }
+    if self.sign {
+      args.arg("--sign");
+    }
+    if let Some(msg) = self.message {
+      args.arg(format!("--message={}", msg));
+    }
+    if let Some(path) = self.message_file {
+      args.arg("--file");
+      args.arg(path);
+    }
+    if self.force {
+      args.arg("--force");
+    }
+    if self.delete {
+      args.arg("--delete");
+    }
+    if self.list {
+      args.arg("--list");
+    }
+    if self.verify {
+      args.arg("--verify");
+    }
+    if let Some(commit) = self.contains {
+      args.arg(format!("--contains={}", commit));
+    }
+    if let Some(key) = self.sort {
+      args.arg(format!("--sort={}", key));
+    }
+    if let Some(object) = self.points_at {
+      args.arg(format!("--points-at={}", object));
+    }
+    if let Some(name) = self.name {
+      args.arg(name);
+    }
+    if let Some(object) = self.commit_ish {
+      args.arg(object);
+    }
+    args
+  }
+}
+
+impl_builder!(GitTagBuilder);
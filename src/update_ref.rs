@@ -0,0 +1,168 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// The builder for the `git update-ref` command created by calling
+/// `Git::update_ref()`. This safely points a ref at a new object, or deletes
+/// it with `delete`. Unless `stdin` is used, `ref_name` must be set, as must
+/// `new_value` when not deleting.
+#[derive(Clone)]
+pub struct GitUpdateRefBuilder {
+  git: GitBuilder,
+  ref_name: Option<String>,
+  new_value: Option<String>,
+  old_value: Option<String>,
+  no_deref: bool,
+  delete: bool,
+  create_reflog: bool,
+  message: Option<String>,
+  stdin: bool,
+}
+
+impl GitUpdateRefBuilder {
+  /// Internal function used by `Git`. `Git::update_ref()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitUpdateRefBuilder {
+      git,
+      ref_name: None,
+      new_value: None,
+      old_value: None,
+      no_deref: false,
+      delete: false,
+      create_reflog: false,
+      message: None,
+      stdin: false,
+    }
+  }
+
+  /// The full name of the ref to update, e.g. `"refs/heads/main"`
+  pub fn ref_name(mut self, name: impl Into<String>) -> Self {
+    self.ref_name = Some(name.into());
+    self
+  }
+
+  /// The object to point the ref at. This is ignored when `delete` is set.
+  pub fn new_value(mut self, value: impl Into<String>) -> Self {
+    self.new_value = Some(value.into());
+    self
+  }
+
+  /// Only update the ref if it currently points at this object, failing
+  /// otherwise. This makes it safe to update refs that something else might
+  /// be changing at the same time. An all zero hash means the ref must not
+  /// exist yet. This must be combined with `new_value` or `delete`.
+  pub fn old_value(mut self, value: impl Into<String>) -> Self {
+    self.old_value = Some(value.into());
+    self
+  }
+
+  /// Update the ref itself rather than the ref it points to if it is a
+  /// symbolic ref
+  pub fn no_deref(mut self) -> Self {
+    self.no_deref = true;
+    self
+  }
+
+  /// Delete the ref instead of updating it
+  pub fn delete(mut self) -> Self {
+    self.delete = true;
+    self
+  }
+
+  /// Create a reflog for the ref if it doesn't have one yet
+  pub fn create_reflog(mut self) -> Self {
+    self.create_reflog = true;
+    self
+  }
+
+  /// The reason for the update to record in the reflog
+  pub fn message(mut self, message: impl Into<String>) -> Self {
+    self.message = Some(message.into());
+    self
+  }
+
+  /// Read commands such as `update <ref> <new> [<old>]` from stdin, one per
+  /// line, and apply all of them in a single transaction: either every update
+  /// succeeds or none of them do.
+  pub fn stdin(mut self) -> Self {
+    self.stdin = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if `old_value` was set without `new_value` or `delete`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if `old_value` was set without `new_value` or `delete`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    // Git would take a lone value after the ref name as the new value, which
+    // overwrites the ref instead of checking the old one
+    let lone_old_value = self.old_value.is_some() && self.new_value.is_none() && !self.delete;
+
+    let mut args = self.git.args();
+    args.arg("update-ref");
+
+    if let Some(message) = self.message {
+      args.arg("-m");
+      args.arg(message);
+    }
+    if self.no_deref {
+      args.arg("--no-deref");
+    }
+    if self.create_reflog {
+      args.arg("--create-reflog");
+    }
+    if self.delete {
+      args.arg("-d");
+    }
+    if self.stdin {
+      args.arg("--stdin");
+    }
+    if let Some(name) = self.ref_name {
+      args.arg(name);
+    }
+    if let Some(value) = self.new_value {
+      if !self.delete {
+        args.arg(value);
+      }
+    }
+    if lone_old_value {
+      return Err(args.incomplete("<new-value?>", "`old_value` needs `new_value` or `delete`"));
+    }
+    if let Some(value) = self.old_value {
+      args.arg(value);
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitUpdateRefBuilder,
+  panics = "This will panic if `old_value` was set without `new_value` or `delete`"
+);
@@ -0,0 +1,80 @@
+use crate::{impl_builder, Args, GitBuilder};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git verify-pack` command created by calling
+/// `Git::verify_pack()`. This checks that pack files are valid, given the
+/// paths to their `.idx` files, e.g. the ones in `.git/objects/pack/`.
+#[derive(Clone)]
+pub struct GitVerifyPackBuilder {
+  git: GitBuilder,
+  idx_files: Vec<PathBuf>,
+  verbose: bool,
+  stat_only: bool,
+}
+
+impl GitVerifyPackBuilder {
+  /// Internal function used by `Git`. `Git::verify_pack()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitVerifyPackBuilder {
+      git,
+      idx_files: Vec::new(),
+      verbose: false,
+      stat_only: false,
+    }
+  }
+
+  /// The `.idx` files of the packs to verify. This can be called multiple
+  /// times to add more files.
+  pub fn idx_files(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+    self.idx_files.extend(paths.into_iter().map(Into::into));
+    self
+  }
+
+  /// After verifying a pack, list every object in it along with its type and
+  /// size, followed by a histogram of delta chain lengths
+  pub fn verbose(mut self) -> Self {
+    self.verbose = true;
+    self
+  }
+
+  /// Don't verify the packs, only show the histogram of delta chain lengths.
+  /// Together with `verbose` the objects are listed too.
+  pub fn stat_only(mut self) -> Self {
+    self.stat_only = true;
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("verify-pack");
+
+    if self.verbose {
+      args.arg("--verbose");
+    }
+    if self.stat_only {
+      args.arg("--stat-only");
+    }
+    args.args(self.idx_files);
+    args
+  }
+}
+
+impl_builder!(GitVerifyPackBuilder);
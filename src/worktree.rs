@@ -0,0 +1,310 @@
+use crate::{impl_builder, Args, GitBuilder, IncompleteArgs, ParseError};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git worktree` command created by calling
+/// `Git::worktree()`. An operation must be chosen with `op` before calling
+/// `make_cmd`.
+#[derive(Clone)]
+pub struct GitWorktreeBuilder {
+  git: GitBuilder,
+  op: Option<WorktreeOp>,
+}
+
+impl GitWorktreeBuilder {
+  /// Internal function used by `Git`. `Git::worktree()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitWorktreeBuilder { git, op: None }
+  }
+
+  /// Choose which `git worktree` subcommand to run. See `WorktreeOp` for what
+  /// each of them do. Calling this again replaces the previously set
+  /// operation.
+  pub fn op(mut self, op: WorktreeOp) -> Self {
+    self.op = Some(op);
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  ///
+  /// # Panics
+  /// This will panic if no operation was set with `op`
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`, panicking if they
+  /// are incomplete
+  fn args(self) -> Args {
+    self
+      .try_args()
+      .unwrap_or_else(|err| panic!("{}", err.error))
+  }
+
+  /// Collect the arguments, or the ones collected so far along with the reason
+  /// they are incomplete, so that `Display` can still show them
+  fn try_args(self) -> Result<Args, IncompleteArgs> {
+    let mut args = self.git.args();
+    args.arg("worktree");
+
+    let op = match self.op {
+      Some(op) => op,
+      None => {
+        return Err(args.incomplete(
+          "<op?>",
+          "an operation must be set with `op` before calling `make_cmd`",
+        ))
+      }
+    };
+    match op {
+      WorktreeOp::Add {
+        path,
+        branch,
+        create_branch,
+        force_create,
+        detach,
+        checkout,
+        lock,
+        lock_reason,
+      } => {
+        args.arg("add");
+        if let Some(name) = create_branch {
+          args.arg(if force_create { "-B" } else { "-b" });
+          args.arg(name);
+        }
+        if detach {
+          args.arg("--detach");
+        }
+        if !checkout {
+          args.arg("--no-checkout");
+        }
+        // Git refuses `--reason` without `--lock`, so a reason implies it
+        if lock || lock_reason.is_some() {
+          args.arg("--lock");
+        }
+        if let Some(reason) = lock_reason {
+          args.arg(format!("--reason={}", reason));
+        }
+        args.arg(path);
+        if let Some(branch) = branch {
+          args.arg(branch);
+        }
+      }
+      WorktreeOp::List { porcelain } => {
+        args.arg("list");
+        if porcelain {
+          args.arg("--porcelain");
+        }
+      }
+      WorktreeOp::Lock { path, reason } => {
+        args.arg("lock");
+        if let Some(reason) = reason {
+          args.arg(format!("--reason={}", reason));
+        }
+        args.arg(path);
+      }
+      WorktreeOp::Unlock(path) => {
+        args.arg("unlock");
+        args.arg(path);
+      }
+      WorktreeOp::Move { worktree, new_path } => {
+        args.arg("move");
+        args.arg(worktree);
+        args.arg(new_path);
+      }
+      WorktreeOp::Remove { path, force } => {
+        args.arg("remove");
+        if force {
+          args.arg("--force");
+        }
+        args.arg(path);
+      }
+      WorktreeOp::Prune { dry_run, verbose } => {
+        args.arg("prune");
+        if dry_run {
+          args.arg("--dry-run");
+        }
+        if verbose {
+          args.arg("--verbose");
+        }
+      }
+    }
+    Ok(args)
+  }
+}
+
+impl_builder!(
+  GitWorktreeBuilder,
+  panics = "This will panic if no operation was set with `op`"
+);
+
+/// The operations that can be passed to `GitWorktreeBuilder::op`
+#[derive(Clone)]
+pub enum WorktreeOp {
+  /// Create a new working tree at `path` and check out `branch` into it
+  Add {
+    /// Where to create the new working tree
+    path: PathBuf,
+    /// The commit-ish to check out. If neither this nor `create_branch` is
+    /// set, a new branch named after the last component of `path` is created.
+    branch: Option<String>,
+    /// Create a new branch with this name starting at `branch` and check it
+    /// out
+    create_branch: Option<String>,
+    /// Reset the `create_branch` branch to `branch` if it already exists
+    /// instead of failing
+    force_create: bool,
+    /// Check out `branch` with a detached `HEAD`
+    detach: bool,
+    /// Check out the files into the new working tree. Set this to `false` to
+    /// create the working tree without any files, which is useful for making
+    /// sparse checkouts.
+    checkout: bool,
+    /// Keep the working tree locked after creation
+    lock: bool,
+    /// An explanation for why the working tree is locked. Setting this locks
+    /// the working tree even if `lock` is `false`.
+    lock_reason: Option<String>,
+  },
+  /// List details of each working tree, starting with the main one
+  List {
+    /// Output in a stable format designed for machine consumption
+    porcelain: bool,
+  },
+  /// Lock a working tree to prevent it from being pruned, moved, or deleted
+  Lock {
+    /// The working tree to lock
+    path: PathBuf,
+    /// An explanation for why the working tree is locked
+    reason: Option<String>,
+  },
+  /// Unlock a working tree so that it can be pruned, moved, or deleted
+  Unlock(PathBuf),
+  /// Move a working tree to a new location
+  Move {
+    /// The working tree to move
+    worktree: PathBuf,
+    /// Where to move the working tree to
+    new_path: PathBuf,
+  },
+  /// Remove a working tree. Only clean working trees can be removed unless
+  /// `force` is set.
+  Remove {
+    /// The working tree to remove
+    path: PathBuf,
+    /// Remove the working tree even if it has modifications or untracked
+    /// files
+    force: bool,
+  },
+  /// Prune the administrative information of working trees that no longer
+  /// exist
+  Prune {
+    /// Do not remove anything; just report what would be removed
+    dry_run: bool,
+    /// Report all removals
+    verbose: bool,
+  },
+}
+
+/// A single working tree parsed from the output of `git worktree list
+/// --porcelain`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitWorktreeEntry {
+  /// The path of the working tree
+  pub worktree: PathBuf,
+  /// The commit checked out in the working tree. This is `None` for a bare
+  /// repository.
+  pub head_hash: Option<String>,
+  /// The full name of the branch checked out in the working tree e.g.
+  /// `refs/heads/main`. This is `None` if `HEAD` is detached or the repository
+  /// is bare.
+  pub branch: Option<String>,
+  /// Whether this is a bare repository
+  pub is_bare: bool,
+  /// Whether `HEAD` is detached
+  pub is_detached: bool,
+  /// Whether the working tree is locked, which stops it from being pruned,
+  /// moved, or removed
+  pub is_locked: bool,
+  /// The reason the working tree was locked with, if one was given
+  pub lock_reason: Option<String>,
+  /// Whether `WorktreeOp::Prune` would remove the working tree, e.g. because
+  /// its directory was deleted
+  pub is_prunable: bool,
+  /// Why the working tree can be pruned, if `is_prunable` is set
+  pub prunable_reason: Option<String>,
+}
+
+impl GitWorktreeEntry {
+  /// Parse the output of `git worktree list --porcelain` into one entry per
+  /// working tree, in the order git listed them. Attributes this parser
+  /// doesn't know about are skipped, since git may add new ones.
+  pub fn parse_porcelain(bytes: &[u8]) -> Result<Vec<Self>, ParseError> {
+    let output = std::str::from_utf8(bytes)
+      .map_err(|e| ParseError::new(format!("output is not valid utf-8: {}", e)))?;
+
+    let mut entries: Vec<GitWorktreeEntry> = Vec::new();
+    // Whether the next line starts a new stanza
+    let mut new = true;
+    for line in output.lines() {
+      if line.is_empty() {
+        new = true;
+        continue;
+      }
+      let (key, value) = match line.split_once(' ') {
+        Some((key, value)) => (key, Some(value)),
+        None => (line, None),
+      };
+      if new {
+        let path = value
+          .filter(|_| key == "worktree")
+          .ok_or_else(|| ParseError::new(format!("expected a worktree line but got: {}", line)))?;
+        entries.push(GitWorktreeEntry {
+          worktree: path.into(),
+          head_hash: None,
+          branch: None,
+          is_bare: false,
+          is_detached: false,
+          is_locked: false,
+          lock_reason: None,
+          is_prunable: false,
+          prunable_reason: None,
+        });
+        new = false;
+        continue;
+      }
+
+      let entry = entries.last_mut().unwrap();
+      match key {
+        "HEAD" => entry.head_hash = value.map(Into::into),
+        "branch" => entry.branch = value.map(Into::into),
+        "bare" => entry.is_bare = true,
+        "detached" => entry.is_detached = true,
+        "locked" => {
+          entry.is_locked = true;
+          entry.lock_reason = value.map(Into::into);
+        }
+        "prunable" => {
+          entry.is_prunable = true;
+          entry.prunable_reason = value.map(Into::into);
+        }
+        _ => {}
+      }
+    }
+    Ok(entries)
+  }
+}
@@ -0,0 +1,87 @@
+use crate::{impl_builder, stdout_hash, Args, GitBuilder, GitError};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The builder for the `git write-tree` command created by calling
+/// `Git::write_tree()`. This writes the current index as a tree object, which
+/// can then be committed with `Git::commit_tree()`.
+#[derive(Clone)]
+pub struct GitWriteTreeBuilder {
+  git: GitBuilder,
+  missing_ok: bool,
+  prefix: Option<PathBuf>,
+}
+
+impl GitWriteTreeBuilder {
+  /// Internal function used by `Git`. `Git::write_tree()` is just a wrapper
+  /// around this function.
+  pub(crate) fn new(git: GitBuilder) -> Self {
+    GitWriteTreeBuilder {
+      git,
+      missing_ok: false,
+      prefix: None,
+    }
+  }
+
+  /// Write the tree even if the index refers to objects that are missing from
+  /// the object database
+  pub fn missing_ok(mut self) -> Self {
+    self.missing_ok = true;
+    self
+  }
+
+  /// Write the tree of the given subdirectory of the index instead of the
+  /// whole index
+  pub fn prefix(mut self, prefix: impl Into<PathBuf>) -> Self {
+    self.prefix = Some(prefix.into());
+    self
+  }
+
+  /// The arguments `make_cmd` passes to git, starting with any global options
+  /// from `GitBuilder` followed by the subcommand. This is useful for logging
+  /// or running git some other way, and doesn't create a `Command`.
+  pub fn to_args(&self) -> Vec<OsString> {
+    self.clone().args().into()
+  }
+
+  /// Consume the builder and output an `std::process::Command` object you can
+  /// add env vars to etc. The command will not execute till you tell it to. See
+  /// the standard library docs for more details
+  pub fn make_cmd(self) -> Command {
+    self.args().command()
+  }
+
+  /// Collect the arguments for `make_cmd` and `to_args`
+  fn args(self) -> Args {
+    let mut args = self.git.args();
+    args.arg("write-tree");
+
+    if self.missing_ok {
+      args.arg("--missing-ok");
+    }
+    if let Some(prefix) = self.prefix {
+      args.arg("--prefix");
+      args.arg(prefix);
+    }
+    args
+  }
+
+  /// Run the command, waiting for it to finish and returning the hash of the
+  /// tree. This returns an error if git couldn't be run or didn't exit
+  /// successfully, see `GitError` for the details.
+  pub fn execute(self) -> Result<String, GitError> {
+    let output = GitError::execute(self.make_cmd())?;
+    Ok(stdout_hash(&output))
+  }
+
+  /// Like `execute`, but runs git with `tokio` so that waiting for it doesn't
+  /// block the async runtime. This requires the `async` feature.
+  #[cfg(feature = "async")]
+  pub async fn execute_async(self) -> Result<String, GitError> {
+    let output = GitError::execute_async(self.make_cmd()).await?;
+    Ok(stdout_hash(&output))
+  }
+}
+
+impl_builder!(GitWriteTreeBuilder, without_execute);
@@ -0,0 +1,78 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_am() {
+  let dir = repo("git_am");
+  commit(dir.path(), "file", "one\n", "first");
+  commit(dir.path(), "file", "one\ntwo\n", "second");
+  let patch = stdout(&git(dir.path(), &["format-patch", "-1", "HEAD"]));
+  let patch = dir.path().join(patch.trim());
+  git(dir.path(), &["reset", "--hard", "HEAD~1"]);
+
+  let out = Git::am()
+    .mboxes([&patch])
+    .signoff()
+    .patch_format(PatchFormat::Mbox)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let message = stdout(&git(dir.path(), &["log", "-1", "--format=%B"]));
+  assert_eq!(
+    message,
+    "second\n\nSigned-off-by: Test User <test@example.com>\n\n"
+  );
+  let contents = std::fs::read_to_string(dir.path().join("file")).unwrap();
+  assert_eq!(contents, "one\ntwo\n");
+}
+
+#[test]
+fn git_am_abort() {
+  let dir = repo("git_am");
+  commit(dir.path(), "file", "one\n", "first");
+  commit(dir.path(), "file", "one\ntwo\n", "second");
+  let patch = stdout(&git(dir.path(), &["format-patch", "-1", "HEAD"]));
+  let patch = dir.path().join(patch.trim());
+  git(dir.path(), &["reset", "--hard", "HEAD~1"]);
+  commit(dir.path(), "file", "conflict\n", "conflict");
+  let head = rev(dir.path(), "HEAD");
+
+  let git_dir = Git::builder().working_dir(dir.path());
+  assert!(git_dir.am().mboxes([&patch]).execute().is_err());
+  assert!(dir.path().join(".git/rebase-apply").exists());
+  git_dir.am().abort().execute().unwrap();
+  assert!(!dir.path().join(".git/rebase-apply").exists());
+  assert_eq!(rev(dir.path(), "HEAD"), head);
+}
+
+#[test]
+fn git_am_args() {
+  let args = Git::am()
+    .utf8()
+    .no_utf8()
+    .keep()
+    .three_way()
+    .reject()
+    .patch_format(PatchFormat::StgitSeries)
+    .mboxes(["series"])
+    .to_args();
+  assert_eq!(
+    args,
+    [
+      "am",
+      "--no-utf8",
+      "--keep",
+      "--3way",
+      "--reject",
+      "--patch-format=stgit-series",
+      "series"
+    ]
+  );
+  assert_eq!(Git::am().continue_().to_args(), ["am", "--continue"]);
+  assert_eq!(Git::am().skip().to_args(), ["am", "--skip"]);
+  assert_eq!(Git::am().quit().to_args(), ["am", "--quit"]);
+}
@@ -0,0 +1,176 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+/// Creates a repo with a patch for `file` saved to `change.patch` and the
+/// change itself reset
+fn patched_repo() -> tempdir::TempDir {
+  let dir = repo("git_apply");
+  commit(dir.path(), "file", "hello\n", "first");
+  fs::write(dir.path().join("file"), "hello\nworld \n").unwrap();
+  let patch = git(dir.path(), &["diff"]).stdout;
+  fs::write(dir.path().join("change.patch"), patch).unwrap();
+  git(dir.path(), &["checkout", "--", "file"]);
+  dir
+}
+
+#[test]
+fn git_apply() {
+  let dir = patched_repo();
+  let out = Git::apply()
+    .check()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    fs::read_to_string(dir.path().join("file")).unwrap(),
+    "hello\n"
+  );
+
+  let out = Git::apply()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    fs::read_to_string(dir.path().join("file")).unwrap(),
+    "hello\nworld \n"
+  );
+
+  let out = Git::apply()
+    .reverse()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    fs::read_to_string(dir.path().join("file")).unwrap(),
+    "hello\n"
+  );
+}
+
+#[test]
+fn git_apply_stats() {
+  let dir = patched_repo();
+  let out = Git::apply()
+    .numstat()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "1\t0\tfile\n");
+
+  let out = Git::apply()
+    .stat()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).contains("1 file changed, 1 insertion(+)"));
+}
+
+#[test]
+fn git_apply_index_cached() {
+  let dir = patched_repo();
+  let out = Git::apply()
+    .cached()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    fs::read_to_string(dir.path().join("file")).unwrap(),
+    "hello\n"
+  );
+  assert_eq!(
+    stdout(&git(dir.path(), &["diff", "--cached", "--name-only"])),
+    "file\n"
+  );
+
+  git(dir.path(), &["reset", "--quiet", "--hard"]);
+  let out = Git::apply()
+    .index()
+    .verbose()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(String::from_utf8_lossy(&out.stderr).contains("Applied patch file cleanly."));
+  assert_eq!(
+    stdout(&git(dir.path(), &["status", "--porcelain"])),
+    "M  file\n?? change.patch\n"
+  );
+}
+
+#[test]
+fn git_apply_whitespace() {
+  let dir = patched_repo();
+  let out = Git::apply()
+    .whitespace(WhitespaceMode::Error)
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+
+  let out = Git::apply()
+    .whitespace(WhitespaceMode::Fix)
+    .ignore_space_change()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    fs::read_to_string(dir.path().join("file")).unwrap(),
+    "hello\nworld\n"
+  );
+}
+
+#[test]
+fn git_apply_reject_three_way() {
+  let dir = patched_repo();
+  commit(dir.path(), "file", "goodbye\n", "second");
+  let out = Git::apply()
+    .reject()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  assert!(dir.path().join("file.rej").exists());
+
+  let out = Git::apply()
+    .three_way()
+    .patches(["change.patch"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  // The patch conflicts, but the 3-way merge leaves conflict markers behind
+  // instead of failing outright
+  assert!(!out.status.success());
+  assert!(fs::read_to_string(dir.path().join("file"))
+    .unwrap()
+    .contains("<<<<<<<"));
+}
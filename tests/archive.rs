@@ -0,0 +1,82 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_archive_output() {
+  let dir = repo("git_archive");
+  commit(dir.path(), "file", "contents", "first");
+  let output = dir.path().join("out.tar.gz");
+  let out = Git::archive()
+    .format(ArchiveFormat::TarGz)
+    .output(&output)
+    .tree_ish("HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let archive = fs::read(output).unwrap();
+  // Gzip magic number
+  assert_eq!(&archive[..2], &[0x1f, 0x8b]);
+}
+
+#[test]
+fn git_archive_prefix_pathspecs() {
+  let dir = repo("git_archive");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  let out = Git::archive()
+    .format(ArchiveFormat::Tar)
+    .prefix("project/")
+    .tree_ish("HEAD")
+    .pathspecs(["b"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let tar = String::from_utf8_lossy(&out.stdout);
+  assert!(tar.contains("project/b"));
+  assert!(!tar.contains("project/a"));
+}
+
+#[test]
+fn git_archive_zip_worktree_attributes() {
+  let dir = repo("git_archive");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "secret", "secret", "second");
+  fs::write(dir.path().join(".gitattributes"), "secret export-ignore\n").unwrap();
+
+  let out = Git::archive()
+    .format(ArchiveFormat::Zip)
+    .worktree_attributes()
+    .tree_ish("HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(&out.stdout[..2], b"PK");
+  assert!(!String::from_utf8_lossy(&out.stdout).contains("secret"));
+}
+
+#[test]
+fn git_archive_remote() {
+  let remote = repo("git_archive");
+  commit(remote.path(), "a", "a", "first");
+  let dir = repo("git_archive");
+  let out = Git::archive()
+    .remote(remote.path().to_str().unwrap())
+    .exec("git-upload-archive")
+    .format(ArchiveFormat::Custom("tar".into()))
+    .tree_ish("main")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!out.stdout.is_empty());
+}
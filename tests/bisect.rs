@@ -0,0 +1,270 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+fn bisect(dir: &std::path::Path, op: BisectOp) -> std::process::Output {
+  Git::bisect()
+    .op(op)
+    .make_cmd()
+    .current_dir(dir)
+    .output()
+    .unwrap()
+}
+
+#[test]
+fn git_bisect() {
+  let dir = repo("git_bisect");
+  for i in 1..=4 {
+    let contents = if i >= 3 { "bug" } else { "fine" };
+    commit(
+      dir.path(),
+      "file",
+      &format!("{} {}", contents, i),
+      &format!("commit {}", i),
+    );
+  }
+  let culprit = rev(dir.path(), "HEAD~1");
+
+  let out = bisect(
+    dir.path(),
+    BisectOp::Start {
+      bad: Some("HEAD".into()),
+      good: vec!["HEAD~3".into()],
+      no_checkout: false,
+      term_new: None,
+      term_old: None,
+    },
+  );
+  assert!(out.status.success());
+
+  // Keep marking commits until git tells us which one introduced the bug
+  let mut result = String::new();
+  for _ in 0..3 {
+    let contents = std::fs::read_to_string(dir.path().join("file")).unwrap();
+    let op = if contents.starts_with("bug") {
+      BisectOp::Bad(vec![])
+    } else {
+      BisectOp::Good(vec![])
+    };
+    let out = bisect(dir.path(), op);
+    assert!(out.status.success());
+    result = stdout(&out);
+    if result.contains("is the first bad commit") {
+      break;
+    }
+  }
+  assert!(result.starts_with(&format!("{} is the first bad commit", culprit)));
+
+  let out = bisect(dir.path(), BisectOp::Log);
+  assert!(out.status.success());
+  assert!(stdout(&out).contains(&format!("# first bad commit: [{}]", culprit)));
+
+  let out = bisect(dir.path(), BisectOp::Reset(None));
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&git(dir.path(), &["branch", "--show-current"])),
+    "main\n"
+  );
+}
+
+#[test]
+fn git_bisect_replay_skip_terms() {
+  let dir = repo("git_bisect");
+  for i in 1..=4 {
+    commit(dir.path(), "file", &i.to_string(), &format!("commit {}", i));
+  }
+  let out = bisect(
+    dir.path(),
+    BisectOp::Start {
+      bad: None,
+      good: vec![],
+      no_checkout: true,
+      term_new: None,
+      term_old: None,
+    },
+  );
+  assert!(out.status.success());
+  assert!(bisect(dir.path(), BisectOp::Bad(vec!["HEAD".into()]))
+    .status
+    .success());
+  assert!(bisect(dir.path(), BisectOp::Good(vec!["HEAD~3".into()]))
+    .status
+    .success());
+  assert!(
+    bisect(dir.path(), BisectOp::Skip(vec!["BISECT_HEAD".into()]))
+      .status
+      .success()
+  );
+
+  let out = bisect(
+    dir.path(),
+    BisectOp::Terms {
+      term_bad: Some("bad".into()),
+      term_good: None,
+    },
+  );
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "bad\n");
+
+  let out = bisect(dir.path(), BisectOp::Visualize(vec!["--oneline".into()]));
+  assert!(out.status.success());
+
+  let log = dir.path().join("bisect.log");
+  std::fs::write(&log, bisect(dir.path(), BisectOp::Log).stdout).unwrap();
+  assert!(bisect(dir.path(), BisectOp::Reset(Some("main".into())))
+    .status
+    .success());
+  let out = bisect(dir.path(), BisectOp::Replay(log));
+  assert!(out.status.success());
+  assert!(bisect(dir.path(), BisectOp::Reset(None)).status.success());
+}
+
+#[test]
+fn git_bisect_run_cmd() {
+  let dir = repo("git_bisect");
+  for i in 1..=6 {
+    let contents = if i >= 4 { "bug" } else { "fine" };
+    commit(
+      dir.path(),
+      "file",
+      &format!("{} {}", contents, i),
+      &format!("commit {}", i),
+    );
+  }
+  let culprit = rev(dir.path(), "HEAD~2");
+  let out = bisect(
+    dir.path(),
+    BisectOp::Start {
+      bad: Some("HEAD".into()),
+      good: vec!["HEAD~5".into()],
+      no_checkout: false,
+      term_new: None,
+      term_old: None,
+    },
+  );
+  assert!(out.status.success());
+
+  // grep exits with 0 when the file is fine and 1 when it has the bug
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .bisect()
+    .run_cmd("grep", ["-q", "fine", "file"])
+    .execute()
+    .unwrap();
+  assert!(stdout(&out).contains(&format!("{} is the first bad commit", culprit)));
+  bisect(dir.path(), BisectOp::Reset(None));
+}
+
+#[test]
+fn git_bisect_run_cmd_args() {
+  let args = Git::bisect()
+    .run_cmd("sh", ["-c", "make test; exit $?"])
+    .to_args();
+  assert_eq!(args, ["bisect", "run", "sh", "-c", "make test; exit $?"]);
+}
+
+#[test]
+fn git_bisect_custom_terms() {
+  let dir = repo("git_bisect");
+  for i in 1..=4 {
+    let contents = if i >= 3 { "fixed" } else { "broken" };
+    commit(
+      dir.path(),
+      "file",
+      &format!("{} {}", contents, i),
+      &format!("commit {}", i),
+    );
+  }
+  let fix = rev(dir.path(), "HEAD~1");
+  let out = bisect(
+    dir.path(),
+    BisectOp::Start {
+      bad: None,
+      good: vec![],
+      no_checkout: false,
+      term_new: Some("fixed".into()),
+      term_old: Some("broken".into()),
+    },
+  );
+  assert!(out.status.success());
+  let out = bisect(
+    dir.path(),
+    BisectOp::Terms {
+      term_bad: Some("new".into()),
+      term_good: None,
+    },
+  );
+  assert_eq!(stdout(&out), "fixed\n");
+
+  let mark = |term: &str, rev: &str| {
+    bisect(
+      dir.path(),
+      BisectOp::Mark {
+        term: term.into(),
+        revs: vec![rev.into()],
+      },
+    )
+  };
+  assert!(mark("fixed", "HEAD").status.success());
+  let mut out = mark("broken", "HEAD~3");
+  while !stdout(&out).contains("is the first fixed commit") {
+    assert!(out.status.success());
+    let contents = std::fs::read_to_string(dir.path().join("file")).unwrap();
+    let term = if contents.starts_with("fixed") {
+      "fixed"
+    } else {
+      "broken"
+    };
+    out = mark(term, "HEAD");
+  }
+  assert!(stdout(&out).starts_with(&fix));
+  assert!(bisect(dir.path(), BisectOp::Reset(None)).status.success());
+}
+
+#[test]
+#[should_panic(
+  expected = "`good` revisions can only be passed to `BisectOp::Start` along with a `bad` one"
+)]
+fn git_bisect_start_good_without_bad() {
+  Git::bisect()
+    .op(BisectOp::Start {
+      bad: None,
+      good: vec!["HEAD~1".into()],
+      no_checkout: false,
+      term_new: None,
+      term_old: None,
+    })
+    .make_cmd();
+}
+
+#[test]
+fn git_bisect_terms_args() {
+  let terms = |term_bad: Option<&str>, term_good: Option<&str>| {
+    Git::bisect()
+      .op(BisectOp::Terms {
+        term_bad: term_bad.map(Into::into),
+        term_good: term_good.map(Into::into),
+      })
+      .to_string()
+  };
+  assert_eq!(terms(None, None), "git bisect terms");
+  assert_eq!(terms(Some("new"), None), "git bisect terms --term-new");
+  assert_eq!(terms(None, Some("good")), "git bisect terms --term-good");
+  assert_eq!(terms(Some("foo"), None), "git bisect terms <term?>");
+  assert_eq!(terms(None, Some("bad")), "git bisect terms <term?>");
+  assert_eq!(terms(Some("bad"), Some("good")), "git bisect terms <term?>");
+}
+
+#[test]
+#[should_panic(
+  expected = "`BisectOp::Terms` only accepts `bad` or `new` for `term_bad` and `good` or `old` for `term_good`"
+)]
+fn git_bisect_terms_invalid() {
+  Git::bisect()
+    .op(BisectOp::Terms {
+      term_bad: Some("foo".into()),
+      term_good: None,
+    })
+    .make_cmd();
+}
@@ -0,0 +1,227 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_blame() {
+  let dir = repo("git_blame");
+  commit(dir.path(), "file", "one\ntwo\n", "first");
+  let first = rev(dir.path(), "HEAD");
+  commit(dir.path(), "file", "one\ntwo\nthree\n", "second");
+  let second = rev(dir.path(), "HEAD");
+
+  let out = Git::blame()
+    .file("file")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let blame = stdout(&out);
+  let lines = blame.lines().collect::<Vec<_>>();
+  assert_eq!(lines.len(), 3);
+  for (line, hash) in lines.iter().zip(&[&first, &first, &second]) {
+    // The boundary commit is prefixed with `^` and one character shorter
+    let line = line.trim_start_matches('^');
+    assert!(hash.starts_with(&line[..7]));
+  }
+}
+
+#[test]
+fn git_blame_porcelain_line_range() {
+  let dir = repo("git_blame");
+  commit(dir.path(), "file", "one\ntwo\n", "first");
+  commit(dir.path(), "file", "one\ntwo\nthree\n", "second");
+  let second = rev(dir.path(), "HEAD");
+
+  let out = Git::blame()
+    .line_porcelain()
+    .root()
+    .line_range(3, 3)
+    .file("file")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let blame = stdout(&out);
+  assert!(blame.starts_with(&format!("{} 3 3 1\n", second)));
+  assert!(blame.contains("summary second\n"));
+  assert!(blame.ends_with("\tthree\n"));
+
+  let out = Git::blame()
+    .porcelain()
+    .show_stats()
+    .file("file")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).contains("num get patch:"));
+}
+
+#[test]
+fn git_blame_function_range() {
+  let dir = repo("git_blame");
+  commit(
+    dir.path(),
+    "main.rs",
+    "fn a() {\n  1\n}\n\nfn b() {\n  2\n}\n",
+    "first",
+  );
+  let out = Git::blame()
+    .function_range("b")
+    .file("main.rs")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let blame = stdout(&out);
+  assert!(blame.contains("fn b()"));
+  assert!(!blame.contains("fn a()"));
+}
+
+#[test]
+fn git_blame_contents_reverse_since() {
+  let dir = repo("git_blame");
+  commit(dir.path(), "file", "one\n", "first");
+  commit(dir.path(), "file", "one\ntwo\n", "second");
+  let contents = dir.path().join("contents");
+  fs::write(&contents, "one\ntwo\nthree\n").unwrap();
+
+  let out = Git::blame()
+    .contents(&contents)
+    .file("file")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out)
+    .lines()
+    .last()
+    .unwrap()
+    .contains("Not Committed Yet"));
+
+  let out = Git::blame()
+    .reverse("HEAD~1..HEAD")
+    .file("file")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::blame()
+    .since("1 day ago")
+    .file("file")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out).lines().count(), 2);
+}
+
+#[test]
+fn git_blame_parse_line_porcelain() {
+  let dir = repo("git_blame");
+  commit(dir.path(), "file", "one\ntwo\n", "first");
+  let first = rev(dir.path(), "HEAD");
+  commit(dir.path(), "file", "one\n2\ntwo\nthree\n", "second");
+  let second = rev(dir.path(), "HEAD");
+
+  for porcelain in &[true, false] {
+    let builder = Git::builder().working_dir(dir.path()).blame().file("file");
+    let builder = if *porcelain {
+      builder.porcelain()
+    } else {
+      builder.line_porcelain()
+    };
+    let out = builder.execute().unwrap();
+    let entries = GitBlameEntry::parse_line_porcelain(&out.stdout).unwrap();
+
+    let lines = entries
+      .iter()
+      .map(|entry| {
+        (
+          entry.commit_hash.as_str(),
+          entry.original_line,
+          entry.final_line,
+          entry.content.as_str(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      lines,
+      vec![
+        (first.as_str(), 1, 1, "one"),
+        (second.as_str(), 2, 2, "2"),
+        (first.as_str(), 2, 3, "two"),
+        (second.as_str(), 4, 4, "three"),
+      ]
+    );
+    for entry in &entries {
+      assert_eq!(entry.author, "Test User");
+      assert_eq!(entry.author_mail, "<test@example.com>");
+      assert_eq!(entry.committer, "Test User");
+      assert!(entry.author_time > 0);
+      assert_eq!(entry.filename, std::path::PathBuf::from("file"));
+    }
+    assert_eq!(entries[2].summary, "first");
+    assert_eq!(entries[3].summary, "second");
+  }
+}
+
+#[test]
+fn git_blame_parse_line_porcelain_invalid() {
+  assert!(GitBlameEntry::parse_line_porcelain(b"abc 1\n\tline\n").is_err());
+  assert!(GitBlameEntry::parse_line_porcelain(b"abc 1 1 1\nauthor Test User\n").is_err());
+}
+
+#[test]
+fn git_blame_parse_line_porcelain_crlf() {
+  let dir = repo("git_blame");
+  commit(dir.path(), "file", "one\r\ntwo\r\n", "first");
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .blame()
+    .line_porcelain()
+    .file("file")
+    .execute()
+    .unwrap();
+  let entries = GitBlameEntry::parse_line_porcelain(&out.stdout).unwrap();
+  let contents = entries
+    .iter()
+    .map(|entry| entry.content.as_str())
+    .collect::<Vec<_>>();
+  assert_eq!(contents, ["one\r", "two\r"]);
+}
+
+#[test]
+fn git_blame_parse_line_porcelain_not_utf8() {
+  let dir = repo("git_blame");
+  fs::write(dir.path().join("file"), b"caf\xe9\nok\n").unwrap();
+  git(dir.path(), &["add", "file"]);
+  git(dir.path(), &["commit", "--quiet", "-m", "latin-1"]);
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .blame()
+    .line_porcelain()
+    .file("file")
+    .execute()
+    .unwrap();
+  let entries = GitBlameEntry::parse_line_porcelain(&out.stdout).unwrap();
+  let contents = entries
+    .iter()
+    .map(|entry| entry.content.as_str())
+    .collect::<Vec<_>>();
+  assert_eq!(contents, ["caf\u{fffd}", "ok"]);
+  assert_eq!(entries[0].summary, "latin-1");
+
+  assert!(GitBlameEntry::parse_line_porcelain(b"abc 1 1 1\nauthor \xe9\n\tline\n").is_err());
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+/// Lists the branches of the repo in `dir` with `GitBranchEntry::FORMAT`
+fn branches(dir: &std::path::Path) -> Vec<GitBranchEntry> {
+  let format = format!("--format={}", GitBranchEntry::FORMAT);
+  let out = git(dir, &["branch", &format]);
+  GitBranchEntry::parse_branch_output(&out.stdout).unwrap()
+}
+
+#[test]
+fn git_branch_parse_branch_output() {
+  let dir = repo("git_branch");
+  commit(dir.path(), "file", "1", "first");
+  git(dir.path(), &["branch", "no-upstream"]);
+  git(dir.path(), &["branch", "ahead"]);
+  git(dir.path(), &["branch", "diverged"]);
+  git(
+    dir.path(),
+    &["branch", "--quiet", "--set-upstream-to=main", "ahead"],
+  );
+  git(
+    dir.path(),
+    &["branch", "--quiet", "--set-upstream-to=main", "diverged"],
+  );
+  commit(dir.path(), "file", "2", "second on main");
+  git(dir.path(), &["checkout", "--quiet", "ahead"]);
+  commit(dir.path(), "ahead", "1", "ahead one");
+  commit(dir.path(), "ahead", "2", "ahead two");
+  git(dir.path(), &["checkout", "--quiet", "diverged"]);
+  commit(dir.path(), "diverged", "1", "diverged one");
+  git(dir.path(), &["checkout", "--quiet", "ahead"]);
+
+  let entries = branches(dir.path());
+  assert_eq!(entries.len(), 4);
+  assert_eq!(
+    entries[0],
+    GitBranchEntry {
+      name: "ahead".into(),
+      is_current: true,
+      upstream: Some("main".into()),
+      ahead: Some(2),
+      behind: Some(1),
+      commit_hash: rev(dir.path(), "ahead"),
+      subject: "ahead two".into(),
+    }
+  );
+  assert_eq!(entries[1].name, "diverged");
+  assert!(!entries[1].is_current);
+  assert_eq!((entries[1].ahead, entries[1].behind), (Some(1), Some(1)));
+  assert_eq!(entries[2].name, "main");
+  assert_eq!(entries[2].upstream, None);
+  assert_eq!((entries[2].ahead, entries[2].behind), (None, None));
+  assert_eq!(entries[3].name, "no-upstream");
+  assert_eq!(entries[3].subject, "first");
+}
+
+#[test]
+fn git_branch_parse_branch_output_tracking() {
+  let dir = repo("git_branch");
+  commit(dir.path(), "file", "1", "first");
+  git(
+    dir.path(),
+    &["branch", "--quiet", "--track", "up-to-date", "main"],
+  );
+  git(dir.path(), &["branch", "temporary"]);
+  git(
+    dir.path(),
+    &["branch", "--quiet", "--track", "gone", "temporary"],
+  );
+  git(dir.path(), &["branch", "--quiet", "-D", "temporary"]);
+
+  let entries = branches(dir.path());
+  assert_eq!(entries.len(), 3);
+  assert_eq!(entries[0].name, "gone");
+  assert_eq!(entries[0].upstream, Some("temporary".into()));
+  assert_eq!((entries[0].ahead, entries[0].behind), (None, None));
+  assert_eq!(entries[2].name, "up-to-date");
+  assert_eq!(entries[2].upstream, Some("main".into()));
+  assert_eq!((entries[2].ahead, entries[2].behind), (Some(0), Some(0)));
+}
+
+#[test]
+fn git_branch_parse_branch_output_error() {
+  let err = GitBranchEntry::parse_branch_output(b"*\0main\n").unwrap_err();
+  assert_eq!(
+    err.to_string(),
+    "unable to parse git output: expected 6 fields but got 2 in line: * main"
+  );
+}
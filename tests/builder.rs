@@ -0,0 +1,274 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::path::Path;
+use tempdir::TempDir;
+
+#[test]
+fn git_builder_working_dir() {
+  let dir = TempDir::new("git_builder").unwrap();
+  let cwd = std::env::current_dir().unwrap();
+  let cmd = GitBuilder::new()
+    .working_dir(dir.path())
+    .init()
+    .quiet()
+    .make_cmd();
+  let args: Vec<_> = cmd.get_args().collect();
+  assert_eq!(
+    args,
+    [
+      OsStr::new("-C"),
+      dir.path().as_os_str(),
+      OsStr::new("init"),
+      OsStr::new("--quiet"),
+    ]
+  );
+
+  let out = GitBuilder::new()
+    .working_dir(dir.path())
+    .init()
+    .quiet()
+    .make_cmd()
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(dir.path().join(".git").is_dir());
+  assert_eq!(std::env::current_dir().unwrap(), cwd);
+}
+
+#[test]
+fn git_builder_reuse() {
+  let dir = repo("git_builder");
+  commit(dir.path(), "file", "contents", "first");
+  let git = Git::builder().working_dir(dir.path());
+  let out = git.log().make_cmd().output().unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).contains("first"));
+
+  let out = git
+    .rev_parse()
+    .abbrev_ref(true)
+    .revisions(["HEAD"])
+    .make_cmd()
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "main\n");
+}
+
+#[test]
+fn git_builder_default() {
+  // Without any options the builders are the same as the ones from `Git`
+  let from_builder: Vec<_> = GitBuilder::new()
+    .log()
+    .make_cmd()
+    .get_args()
+    .map(ToOwned::to_owned)
+    .collect();
+  let from_git: Vec<_> = Git::log()
+    .make_cmd()
+    .get_args()
+    .map(ToOwned::to_owned)
+    .collect();
+  assert_eq!(from_builder, from_git);
+  assert_eq!(from_git, [OsStr::new("log")]);
+}
+
+#[test]
+fn git_builder_git_dir_work_tree() {
+  let work_tree = TempDir::new("git_builder_work_tree").unwrap();
+  let git_dir = TempDir::new("git_builder_git_dir").unwrap();
+  let git_dir = git_dir.path().join("repo.git");
+  let out = Git::init()
+    .quiet()
+    .separate_git_dir(&git_dir)
+    .directory(work_tree.path())
+    .make_cmd()
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  fs::write(work_tree.path().join("file"), "contents").unwrap();
+
+  let git = GitBuilder::new()
+    .git_dir(&git_dir)
+    .work_tree(work_tree.path());
+  let args: Vec<_> = git
+    .ls_files()
+    .make_cmd()
+    .get_args()
+    .map(ToOwned::to_owned)
+    .collect();
+  let mut expected_git_dir = OsString::from("--git-dir=");
+  expected_git_dir.push(&git_dir);
+  let mut expected_work_tree = OsString::from("--work-tree=");
+  expected_work_tree.push(work_tree.path());
+  assert_eq!(
+    args,
+    [expected_git_dir, expected_work_tree, "ls-files".into()]
+  );
+
+  // Run from an unrelated directory so only the options locate the repo
+  let elsewhere = TempDir::new("git_builder_elsewhere").unwrap();
+  let out = git
+    .ls_files()
+    .others()
+    .make_cmd()
+    .current_dir(elsewhere.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "file\n");
+
+  let out = git
+    .rev_parse()
+    .show_toplevel()
+    .make_cmd()
+    .current_dir(elsewhere.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    Path::new(stdout(&out).trim()).canonicalize().unwrap(),
+    work_tree.path().canonicalize().unwrap()
+  );
+}
+
+#[test]
+fn git_builder_all_global_options() {
+  let work_tree = repo("git_builder");
+  commit(work_tree.path(), "file", "contents", "first");
+  // Relative paths are resolved against the `-C` directory
+  let out = GitBuilder::new()
+    .working_dir(work_tree.path())
+    .git_dir(".git")
+    .work_tree(".")
+    .ls_files()
+    .make_cmd()
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "file\n");
+}
+
+#[test]
+fn git_builder_config_override() {
+  let dir = repo("git_builder");
+  commit(dir.path(), "file", "contents", "first");
+  let builder = GitBuilder::new()
+    .working_dir(dir.path())
+    .config_override("user.email", "ci@example.com")
+    .config_override("user.name", "CI Bot");
+  let args: Vec<_> = builder
+    .log()
+    .make_cmd()
+    .get_args()
+    .map(ToOwned::to_owned)
+    .collect();
+  let mut expected = vec![OsString::from("-C"), dir.path().into()];
+  expected.extend(
+    [
+      "-c",
+      "user.email=ci@example.com",
+      "-c",
+      "user.name=CI Bot",
+      "log",
+    ]
+    .map(OsString::from),
+  );
+  assert_eq!(args, expected);
+
+  let out = builder
+    .revert()
+    .no_edit()
+    .commits(["HEAD"])
+    .make_cmd()
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let author = git(dir.path(), &["log", "-1", "--format=%an <%ae>|%cn <%ce>"]);
+  assert_eq!(
+    stdout(&author),
+    "CI Bot <ci@example.com>|CI Bot <ci@example.com>\n"
+  );
+}
+
+#[test]
+fn git_builder_config_overrides() {
+  let dir = repo("git_builder");
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .config_overrides([("custom.first", "1"), ("custom.second", "2")])
+    .config()
+    .list()
+    .make_cmd()
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let listing = stdout(&out);
+  assert!(listing.contains("custom.first=1\ncustom.second=2\n"));
+}
+
+#[test]
+fn git_builder_to_args() {
+  let builder = GitBuilder::new()
+    .working_dir("/tmp/repo")
+    .config_override("core.pager", "cat")
+    .log()
+    .max_count(1);
+  let args = builder.to_args();
+  assert_eq!(
+    args[..5],
+    ["-C", "/tmp/repo", "-c", "core.pager=cat", "log"]
+  );
+  assert_eq!(args, builder.make_cmd().get_args().collect::<Vec<_>>());
+}
+
+#[test]
+fn git_builder_display() {
+  let builder = GitBuilder::new()
+    .working_dir("/tmp/repo")
+    .config_override("user.name", "Test User")
+    .log()
+    .grep("it's")
+    .format("");
+  assert_eq!(
+    builder.to_string(),
+    r#"git -C /tmp/repo -c 'user.name=Test User' log --format= '--grep=it'\''s'"#
+  );
+}
+
+#[test]
+fn git_builder_display_incomplete() {
+  // Builders that would panic in `make_cmd` are shown with a placeholder for
+  // the missing part instead, so that they can always be logged
+  assert_eq!(Git::stash().to_string(), "git stash <op?>");
+  assert_eq!(Git::commit_tree().to_string(), "git commit-tree <tree?>");
+  assert_eq!(
+    Git::init().shared(Shared::Octal(0o1000)).to_string(),
+    "git init --shared=<invalid octal>"
+  );
+  let start = BisectOp::Start {
+    bad: None,
+    good: vec!["HEAD~1".into()],
+    no_checkout: false,
+    term_new: None,
+    term_old: None,
+  };
+  assert_eq!(
+    Git::bisect().op(start).to_string(),
+    "git bisect start <bad?>"
+  );
+  assert_eq!(
+    format!(
+      "{}",
+      Git::update_ref()
+        .ref_name("refs/heads/main")
+        .old_value("abc")
+    ),
+    "git update-ref refs/heads/main <new-value?>"
+  );
+  assert_eq!(Git::stash().op(StashOp::List).to_string(), "git stash list");
+}
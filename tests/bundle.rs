@@ -0,0 +1,96 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use tempdir::TempDir;
+
+#[test]
+fn git_bundle() {
+  let dir = repo("git_bundle");
+  commit(dir.path(), "file", "1", "first");
+  commit(dir.path(), "file", "2", "second");
+  let head = rev(dir.path(), "HEAD");
+  let file = dir.path().join("repo.bundle");
+  let out = Git::bundle()
+    .op(BundleOp::Create {
+      file: file.clone(),
+      prerequisites: Vec::new(),
+      refs: Vec::new(),
+      all: true,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::bundle()
+    .op(BundleOp::Verify {
+      file: file.clone(),
+      quiet: true,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::bundle()
+    .op(BundleOp::ListHeads { file: file.clone() })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).contains(&format!("{} refs/heads/main\n", head)));
+
+  let bare = TempDir::new("git_bundle_bare").unwrap();
+  git(bare.path(), &["init", "--quiet", "--bare"]);
+  let out = Git::bundle()
+    .op(BundleOp::Unbundle {
+      file,
+      refs: vec!["refs/heads/main".into()],
+    })
+    .make_cmd()
+    .current_dir(bare.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), format!("{} refs/heads/main\n", head));
+  assert_eq!(
+    stdout(&git(bare.path(), &["log", "--format=%s", &head])),
+    "second\nfirst\n"
+  );
+}
+
+#[test]
+fn git_bundle_prerequisites() {
+  let dir = repo("git_bundle");
+  commit(dir.path(), "file", "1", "first");
+  let first = rev(dir.path(), "HEAD");
+  commit(dir.path(), "file", "2", "second");
+  let file = dir.path().join("incremental.bundle");
+  let out = Git::bundle()
+    .op(BundleOp::Create {
+      file: file.clone(),
+      prerequisites: vec![format!("^{}", first)],
+      refs: vec!["main".into()],
+      all: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  // A repository without the prerequisite commit can't use the bundle
+  let empty = repo("git_bundle_empty");
+  let out = Git::bundle()
+    .op(BundleOp::Verify { file, quiet: false })
+    .make_cmd()
+    .current_dir(empty.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  assert!(String::from_utf8_lossy(&out.stderr).contains(&first));
+}
@@ -0,0 +1,146 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+use std::io::Write;
+use std::process::Stdio;
+
+#[test]
+fn git_cat_file() {
+  let dir = repo("git_cat_file");
+  fs::write(dir.path().join("file"), "hello world\n").unwrap();
+  let hash = stdout(&git(dir.path(), &["hash-object", "-w", "file"]));
+  let hash = hash.trim();
+
+  let out = Git::cat_file()
+    .mode(CatFileMode::PrettyPrint)
+    .object(hash)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "hello world\n");
+
+  let out = Git::cat_file()
+    .mode(CatFileMode::Type)
+    .object(hash)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "blob\n");
+
+  let out = Git::cat_file()
+    .mode(CatFileMode::Size)
+    .allow_unknown_type()
+    .object(hash)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "12\n");
+}
+
+#[test]
+fn git_cat_file_exists() {
+  let dir = repo("git_cat_file");
+  commit(dir.path(), "file", "contents", "first");
+  let out = Git::cat_file()
+    .mode(CatFileMode::Exists)
+    .object("HEAD:file")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "");
+
+  let out = Git::cat_file()
+    .mode(CatFileMode::Exists)
+    .object("HEAD:missing")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+}
+
+#[test]
+fn git_cat_file_batch() {
+  let dir = repo("git_cat_file");
+  commit(dir.path(), "file", "contents", "first");
+  let hash = rev(dir.path(), "HEAD:file");
+
+  let mut child = Git::cat_file()
+    .batch_check("%(objecttype) %(objectsize)")
+    .make_cmd()
+    .current_dir(dir.path())
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .unwrap();
+  child
+    .stdin
+    .take()
+    .unwrap()
+    .write_all(b"HEAD:file\nHEAD\n")
+    .unwrap();
+  let out = child.wait_with_output().unwrap();
+  assert!(out.status.success());
+  let lines: Vec<_> = stdout(&out).lines().map(String::from).collect();
+  assert_eq!(lines[0], "blob 8");
+  assert!(lines[1].starts_with("commit "));
+
+  let mut child = Git::cat_file()
+    .batch("")
+    .make_cmd()
+    .current_dir(dir.path())
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .unwrap();
+  child
+    .stdin
+    .take()
+    .unwrap()
+    .write_all(b"HEAD:file\n")
+    .unwrap();
+  let out = child.wait_with_output().unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), format!("{} blob 8\ncontents\n", hash));
+}
+
+#[test]
+fn git_cat_file_make_child_with_input() {
+  use std::io::{BufRead, BufReader};
+
+  let dir = repo("git_cat_file");
+  commit(dir.path(), "file", "contents", "first");
+  let mut child = Git::builder()
+    .working_dir(dir.path())
+    .cat_file()
+    .batch_check("%(objecttype) %(objectsize)")
+    .make_child_with_input()
+    .unwrap();
+  let mut stdin = child.stdin.take().unwrap();
+  let mut stdout = BufReader::new(child.stdout.take().unwrap());
+  let mut line = String::new();
+
+  // Each object is answered before the next one is asked for
+  stdin.write_all(b"HEAD:file\n").unwrap();
+  stdin.flush().unwrap();
+  stdout.read_line(&mut line).unwrap();
+  assert_eq!(line, "blob 8\n");
+  line.clear();
+  stdin.write_all(b"missing\n").unwrap();
+  stdin.flush().unwrap();
+  stdout.read_line(&mut line).unwrap();
+  assert_eq!(line, "missing missing\n");
+
+  drop(stdin);
+  assert!(child.wait().unwrap().success());
+}
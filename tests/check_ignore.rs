@@ -0,0 +1,103 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::path::PathBuf;
+
+#[test]
+fn git_check_ignore() {
+  let dir = repo("git_check_ignore");
+  std::fs::write(dir.path().join(".gitignore"), "*.log\n").unwrap();
+  std::fs::write(dir.path().join("debug.log"), "").unwrap();
+  std::fs::write(dir.path().join("main.rs"), "").unwrap();
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .check_ignore()
+    .pathspecs(vec!["debug.log", "main.rs"])
+    .execute()
+    .unwrap();
+  assert_eq!(stdout(&out), "debug.log\n");
+
+  let err = Git::builder()
+    .working_dir(dir.path())
+    .check_ignore()
+    .pathspecs(vec!["main.rs"])
+    .execute()
+    .unwrap_err();
+  assert!(matches!(err, GitError::NonZeroExit { code: Some(1), .. }));
+}
+
+#[test]
+fn git_check_ignore_verbose() {
+  let dir = repo("git_check_ignore");
+  std::fs::write(dir.path().join(".gitignore"), "*.log\n").unwrap();
+  std::fs::write(dir.path().join("debug.log"), "").unwrap();
+  std::fs::write(dir.path().join("main.rs"), "").unwrap();
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .check_ignore()
+    .verbose()
+    .non_matching()
+    .pathspecs(vec!["debug.log", "main.rs"])
+    .execute()
+    .unwrap();
+  let entries = GitCheckIgnoreEntry::parse_verbose_output(&out.stdout).unwrap();
+  assert_eq!(
+    entries,
+    vec![
+      GitCheckIgnoreEntry {
+        pattern: "*.log".into(),
+        source_file: Some(PathBuf::from(".gitignore")),
+        line_number: Some(1),
+        path: "debug.log".into(),
+      },
+      GitCheckIgnoreEntry {
+        pattern: "".into(),
+        source_file: None,
+        line_number: None,
+        path: "main.rs".into(),
+      },
+    ]
+  );
+}
+
+#[test]
+fn git_check_ignore_stdin() {
+  let dir = repo("git_check_ignore");
+  std::fs::write(dir.path().join(".gitignore"), "target/\n*.log\n").unwrap();
+
+  assert_eq!(
+    Git::check_ignore()
+      .stdin()
+      .stdin_null()
+      .no_index()
+      .to_args(),
+    vec!["check-ignore", "--stdin", "-z", "--no-index"]
+  );
+  let mut child = Git::builder()
+    .working_dir(dir.path())
+    .check_ignore()
+    .stdin()
+    .stdin_null()
+    .no_index()
+    .make_child_with_input()
+    .unwrap();
+  {
+    use std::io::Write;
+    let mut stdin = child.stdin.take().unwrap();
+    stdin
+      .write_all(b"target/debug\0src/lib.rs\0a.log\0")
+      .unwrap();
+  }
+  let out = child.wait_with_output().unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "target/debug\0a.log\0");
+}
+
+#[test]
+fn git_check_ignore_parse_error() {
+  assert!(GitCheckIgnoreEntry::parse_verbose_output(b"no tab here\n").is_err());
+  assert!(GitCheckIgnoreEntry::parse_verbose_output(b".gitignore:x:*.log\ta.log\n").is_err());
+}
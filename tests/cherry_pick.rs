@@ -0,0 +1,157 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_cherry_pick() {
+  let dir = repo("git_cherry_pick");
+  commit(dir.path(), "a", "a", "first");
+  git(dir.path(), &["checkout", "--quiet", "-b", "feature"]);
+  commit(dir.path(), "b", "b\n", "add b");
+  commit(dir.path(), "c", "c\n", "add c");
+  git(dir.path(), &["checkout", "--quiet", "main"]);
+
+  let out = Git::cherry_pick()
+    .commits(["feature~1"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&git(dir.path(), &["log", "--format=%s"])),
+    "add b\nfirst\n"
+  );
+  let diff = stdout(&git(dir.path(), &["show", "--format=", "HEAD"]));
+  assert!(diff.contains("+++ b/b\n"));
+  assert!(diff.contains("\n+b\n"));
+  assert!(!dir.path().join("c").exists());
+}
+
+#[test]
+fn git_cherry_pick_range() {
+  let dir = repo("git_cherry_pick");
+  commit(dir.path(), "a", "a", "first");
+  git(dir.path(), &["checkout", "--quiet", "-b", "feature"]);
+  commit(dir.path(), "b", "b", "add b");
+  commit(dir.path(), "c", "c", "add c");
+  git(dir.path(), &["checkout", "--quiet", "main"]);
+
+  let out = Git::cherry_pick()
+    .signoff()
+    .commits(["main..feature"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&git(dir.path(), &["log", "--format=%s"])),
+    "add c\nadd b\nfirst\n"
+  );
+  assert!(stdout(&git(dir.path(), &["log", "-1", "--format=%b"])).contains("Signed-off-by:"));
+}
+
+#[test]
+fn git_cherry_pick_no_commit() {
+  let dir = repo("git_cherry_pick");
+  commit(dir.path(), "a", "a", "first");
+  git(dir.path(), &["checkout", "--quiet", "-b", "feature"]);
+  commit(dir.path(), "b", "b", "add b");
+  git(dir.path(), &["checkout", "--quiet", "main"]);
+
+  let out = Git::cherry_pick()
+    .no_commit()
+    .commits(["feature"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(rev(dir.path(), "HEAD"), rev(dir.path(), "main"));
+  assert_eq!(
+    stdout(&git(dir.path(), &["diff", "--cached", "--name-status"])),
+    "A\tb\n"
+  );
+}
+
+#[test]
+fn git_cherry_pick_conflict() {
+  let dir = repo("git_cherry_pick");
+  commit(dir.path(), "a", "a", "first");
+  git(dir.path(), &["checkout", "--quiet", "-b", "feature"]);
+  commit(dir.path(), "a", "feature", "change a on feature");
+  commit(dir.path(), "b", "b", "add b");
+  git(dir.path(), &["checkout", "--quiet", "main"]);
+  commit(dir.path(), "a", "main", "change a on main");
+  let head = rev(dir.path(), "HEAD");
+
+  let out = Git::cherry_pick()
+    .commits(["main..feature"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  let out = Git::cherry_pick()
+    .skip()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(rev(dir.path(), "HEAD~1"), head);
+  assert!(dir.path().join("b").exists());
+
+  git(dir.path(), &["reset", "--quiet", "--hard", &head]);
+  let out = Git::cherry_pick()
+    .commits(["feature~1"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  let out = Git::cherry_pick()
+    .abort()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(rev(dir.path(), "HEAD"), head);
+}
+
+#[test]
+fn git_cherry_pick_allow_empty() {
+  let dir = repo("git_cherry_pick");
+  commit(dir.path(), "a", "a", "first");
+  git(
+    dir.path(),
+    &["commit", "--quiet", "--allow-empty", "-m", "empty"],
+  );
+  git(dir.path(), &["branch", "empty"]);
+  git(dir.path(), &["reset", "--quiet", "--hard", "HEAD~1"]);
+
+  let out = Git::cherry_pick()
+    .commits(["empty"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  git(dir.path(), &["cherry-pick", "--abort"]);
+
+  let out = Git::cherry_pick()
+    .allow_empty()
+    .commits(["empty"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&git(dir.path(), &["log", "-1", "--format=%s"])),
+    "empty\n"
+  );
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_clean_dry_run_force() {
+  let dir = repo("git_clean");
+  commit(dir.path(), "tracked", "tracked", "first");
+  fs::write(dir.path().join("a.o"), "a").unwrap();
+  fs::write(dir.path().join("b.o"), "b").unwrap();
+
+  let out = Git::clean()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+
+  let out = Git::clean()
+    .dry_run()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "Would remove a.o\nWould remove b.o\n");
+  assert!(dir.path().join("a.o").exists());
+  assert!(dir.path().join("b.o").exists());
+
+  let out = Git::clean()
+    .force()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("a.o").exists());
+  assert!(!dir.path().join("b.o").exists());
+  assert!(dir.path().join("tracked").exists());
+}
+
+#[test]
+fn git_clean_directories_exclude() {
+  let dir = repo("git_clean");
+  commit(dir.path(), "tracked", "tracked", "first");
+  fs::create_dir(dir.path().join("build")).unwrap();
+  fs::write(dir.path().join("build/out"), "out").unwrap();
+  fs::write(dir.path().join("keep.log"), "log").unwrap();
+
+  let out = Git::clean()
+    .force()
+    .quiet()
+    .exclude("*.log")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(dir.path().join("build/out").exists());
+
+  let out = Git::clean()
+    .force()
+    .quiet()
+    .directories()
+    .exclude("*.log")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(out.stdout.is_empty());
+  assert!(!dir.path().join("build").exists());
+  assert!(dir.path().join("keep.log").exists());
+}
+
+#[test]
+fn git_clean_pathspecs() {
+  let dir = repo("git_clean");
+  commit(dir.path(), "tracked", "tracked", "first");
+  fs::write(dir.path().join("a"), "a").unwrap();
+  fs::write(dir.path().join("b"), "b").unwrap();
+  let out = Git::clean()
+    .force()
+    .pathspecs(["a"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "Removing a\n");
+  assert!(dir.path().join("b").exists());
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_commit_tree() {
+  let dir = repo("git_commit_tree");
+  std::fs::write(dir.path().join("file"), "contents").unwrap();
+  git(dir.path(), &["add", "file"]);
+  let tree = stdout(&git(dir.path(), &["write-tree"]));
+  let tree = tree.trim();
+
+  let root = Git::builder()
+    .working_dir(dir.path())
+    .commit_tree()
+    .tree(tree)
+    .message("root")
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&git(dir.path(), &["cat-file", "-t", &root])),
+    "commit\n"
+  );
+  assert_eq!(rev(dir.path(), &format!("{}^{{tree}}", root)), tree);
+
+  let other = Git::builder()
+    .working_dir(dir.path())
+    .commit_tree()
+    .tree(tree)
+    .message("other root")
+    .execute()
+    .unwrap();
+  let merge = Git::builder()
+    .working_dir(dir.path())
+    .commit_tree()
+    .tree(tree)
+    .parent(&root)
+    .parent(&other)
+    .message("subject")
+    .message("body")
+    .no_gpg_sign()
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&git(dir.path(), &["log", "-1", "--format=%P%n%B", &merge])),
+    format!("{} {}\nsubject\n\nbody\n\n", root, other)
+  );
+}
+
+#[test]
+fn git_commit_tree_args() {
+  assert_eq!(
+    Git::commit_tree()
+      .tree("abc")
+      .parent("def")
+      .message_file("-")
+      .gpg_sign(Some("KEY".into()))
+      .to_args(),
+    [
+      "commit-tree",
+      "-p",
+      "def",
+      "-F",
+      "-",
+      "--gpg-sign=KEY",
+      "abc"
+    ]
+  );
+  assert_eq!(
+    Git::commit_tree().tree("abc").gpg_sign(None).to_args(),
+    ["commit-tree", "--gpg-sign", "abc"]
+  );
+}
+
+#[test]
+#[should_panic(expected = "a tree must be set with `tree` before calling `make_cmd`")]
+fn git_commit_tree_no_tree() {
+  Git::commit_tree().message("no tree").make_cmd();
+}
@@ -0,0 +1,71 @@
+// Not every test binary makes use of every helper
+#![allow(dead_code)]
+
+use git_cmd::*;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempdir::TempDir;
+
+/// Creates a new repository on the `main` branch in a temp dir. A user is
+/// configured for the repo so that commits can be made in it.
+pub fn repo(name: &str) -> TempDir {
+  let dir = TempDir::new(name).unwrap();
+  let out = Git::init()
+    .quiet()
+    .initial_branch("main")
+    .directory(dir.path())
+    .make_cmd()
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  git(dir.path(), &["config", "user.name", "Test User"]);
+  git(dir.path(), &["config", "user.email", "test@example.com"]);
+  dir
+}
+
+/// Runs git with the given arguments in `dir` and panics if it fails
+pub fn git(dir: &Path, args: &[&str]) -> Output {
+  let out = Command::new("git")
+    .current_dir(dir)
+    .args(args)
+    .output()
+    .unwrap();
+  assert!(
+    out.status.success(),
+    "git {:?} failed: {}",
+    args,
+    String::from_utf8_lossy(&out.stderr)
+  );
+  out
+}
+
+/// Writes `contents` to `file` and commits it with the message `msg`
+pub fn commit(dir: &Path, file: &str, contents: &str, msg: &str) {
+  let path = dir.join(file);
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent).unwrap();
+  }
+  fs::write(path, contents).unwrap();
+  git(dir, &["add", file]);
+  git(dir, &["commit", "--quiet", "-m", msg]);
+}
+
+/// Returns the full hash of the given revision
+pub fn rev(dir: &Path, rev: &str) -> String {
+  stdout(&git(dir, &["rev-parse", rev])).trim().to_string()
+}
+
+/// Lossily converts the stdout of a command to a `String`
+pub fn stdout(out: &Output) -> String {
+  String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+/// Allows git to clone from local paths for the given command. Newer versions
+/// of git refuse to do so for submodules without this.
+pub fn allow_file_protocol(cmd: &mut Command) -> &mut Command {
+  cmd
+    .env("GIT_CONFIG_COUNT", "1")
+    .env("GIT_CONFIG_KEY_0", "protocol.file.allow")
+    .env("GIT_CONFIG_VALUE_0", "always")
+}
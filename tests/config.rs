@@ -0,0 +1,189 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_config_set_get_unset() {
+  let dir = repo("git_config");
+  let out = Git::config()
+    .set("test.value", "hello")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::config()
+    .get("test.value")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "hello\n");
+
+  let out = Git::config()
+    .unset("test.value")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let out = Git::config()
+    .get("test.value")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+}
+
+#[test]
+fn git_config_add_unset_all() {
+  let dir = repo("git_config");
+  for value in &["a", "b"] {
+    let out = Git::config()
+      .add("test.multi", *value)
+      .make_cmd()
+      .current_dir(dir.path())
+      .output()
+      .unwrap();
+    assert!(out.status.success());
+  }
+  assert_eq!(
+    stdout(&git(dir.path(), &["config", "--get-all", "test.multi"])),
+    "a\nb\n"
+  );
+
+  let out = Git::config()
+    .unset_all("test.multi")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&git(dir.path(), &["config", "--list"]))
+    .find("test.multi")
+    .is_none());
+}
+
+#[test]
+fn git_config_file_list() {
+  let dir = repo("git_config");
+  let file = dir.path().join("custom.config");
+  let out = Git::config()
+    .scope(ConfigScope::File(file.clone()))
+    .set("custom.key", "value")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(file.exists());
+
+  let out = Git::config()
+    .scope(ConfigScope::File(file))
+    .list()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "custom.key=value\n");
+
+  let out = Git::config()
+    .scope(ConfigScope::Local)
+    .get("user.name")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "Test User\n");
+}
+
+#[test]
+fn git_config_value_type() {
+  let dir = repo("git_config");
+  git(dir.path(), &["config", "test.flag", "yes"]);
+  git(dir.path(), &["config", "test.size", "1k"]);
+  let get = |value_type, key| {
+    let out = Git::config()
+      .value_type(value_type)
+      .get(key)
+      .make_cmd()
+      .current_dir(dir.path())
+      .output()
+      .unwrap();
+    assert!(out.status.success());
+    stdout(&out)
+  };
+  assert_eq!(get(ConfigType::Bool, "test.flag"), "true\n");
+  assert_eq!(get(ConfigType::Int, "test.size"), "1024\n");
+  assert_eq!(get(ConfigType::Bool01, "test.flag"), "true\n");
+}
+
+#[test]
+fn git_config_legacy_types() {
+  let dir = repo("git_config");
+  git(dir.path(), &["config", "test.flag", "off"]);
+  git(dir.path(), &["config", "test.size", "2k"]);
+  let get = |config: GitConfigBuilder, key| stdout(&config.get(key).execute().unwrap());
+  let config = Git::builder().working_dir(dir.path()).config();
+  assert_eq!(get(config.clone().bool_type(), "test.flag"), "false\n");
+  assert_eq!(get(config.clone().int_type(), "test.size"), "2048\n");
+  assert_eq!(
+    get(config.value_type(ConfigType::Bool), "test.flag"),
+    "false\n"
+  );
+
+  assert_eq!(
+    Git::config().path_type().get("core.hooksPath").to_args(),
+    ["config", "--path", "--get", "core.hooksPath"]
+  );
+  assert_eq!(
+    Git::config()
+      .bool_type()
+      .value_type(ConfigType::Int)
+      .get("test.size")
+      .to_args(),
+    ["config", "--type=int", "--get", "test.size"]
+  );
+}
+
+#[test]
+fn git_config_entries() {
+  let dir = repo("git_config");
+  git(dir.path(), &["config", "test.multiline", "first\nsecond"]);
+  git(dir.path(), &["config", "Test.Sub.Key", "value"]);
+
+  let entries = Git::builder()
+    .working_dir(dir.path())
+    .config()
+    .scope(ConfigScope::Local)
+    .entries()
+    .unwrap();
+  let find = |key: &str| entries.iter().find(|entry| entry.key == key).cloned();
+  assert_eq!(
+    find("test.multiline"),
+    Some(GitConfigEntry {
+      key: "test.multiline".into(),
+      value: "first\nsecond".into(),
+    })
+  );
+  assert_eq!(find("test.Sub.key").unwrap().value, "value");
+  assert!(find("second").is_none());
+}
+
+#[test]
+fn git_config_parse_list_output_nul() {
+  let entries = GitConfigEntry::parse_list_output_nul(b"a.b\nc\nd\0a.flag\0").unwrap();
+  assert_eq!(entries[0].value, "c\nd");
+  assert_eq!(entries[1].key, "a.flag");
+  assert_eq!(entries[1].value, "");
+  assert!(GitConfigEntry::parse_list_output_nul(b"")
+    .unwrap()
+    .is_empty());
+  assert!(GitConfigEntry::parse_list_output_nul(b"\nvalue\0").is_err());
+}
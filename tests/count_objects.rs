@@ -0,0 +1,60 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+fn count(dir: &std::path::Path) -> GitCountObjectsOutput {
+  let out = Git::builder()
+    .working_dir(dir)
+    .count_objects()
+    .verbose()
+    .execute()
+    .unwrap();
+  GitCountObjectsOutput::parse(&out.stdout).unwrap()
+}
+
+#[test]
+fn git_count_objects() {
+  let dir = repo("git_count_objects");
+  let before = count(dir.path());
+  assert_eq!(before.count, 0);
+  commit(dir.path(), "file", "contents", "first");
+  let after = count(dir.path());
+  // A blob, a tree, and a commit
+  assert_eq!(after.count, 3);
+  assert_eq!(after.packs, 0);
+}
+
+#[test]
+fn git_count_objects_human_readable() {
+  let dir = repo("git_count_objects");
+  commit(dir.path(), "file", "contents", "first");
+  let out = Git::count_objects()
+    .human_readable()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).starts_with("3 objects, "));
+}
+
+#[test]
+fn git_count_objects_parse() {
+  let output = b"count: 3\nsize: 12\nin-pack: 6\npacks: 1\nsize-pack: 2\nprune-packable: 0\ngarbage: 0\nsize-garbage: 0\n";
+  assert_eq!(
+    GitCountObjectsOutput::parse(output).unwrap(),
+    GitCountObjectsOutput {
+      count: 3,
+      size_kb: 12,
+      in_pack: 6,
+      packs: 1,
+      size_pack_kb: 2,
+      prune_packable: 0,
+      garbage: 0,
+      size_garbage_kb: 0,
+    }
+  );
+  assert!(GitCountObjectsOutput::parse(b"3 objects, 12 kilobytes\n").is_err());
+  assert!(GitCountObjectsOutput::parse(b"count: 3\n").is_err());
+}
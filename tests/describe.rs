@@ -0,0 +1,126 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_describe() {
+  let dir = repo("git_describe");
+  commit(dir.path(), "file", "1", "first");
+  git(dir.path(), &["tag", "-a", "v1.0", "-m", "v1.0"]);
+  let out = Git::describe()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "v1.0\n");
+
+  commit(dir.path(), "file", "2", "second");
+  let out = Git::describe()
+    .long()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let hash = rev(dir.path(), "HEAD");
+  let expected = format!("v1.0-1-g{}\n", &hash[..7]);
+  assert_eq!(stdout(&out), expected);
+
+  let out = Git::describe()
+    .abbrev(0)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "v1.0\n");
+}
+
+#[test]
+fn git_describe_tags_patterns() {
+  let dir = repo("git_describe");
+  commit(dir.path(), "file", "1", "first");
+  git(dir.path(), &["tag", "v1.0"]);
+  git(dir.path(), &["tag", "release-1"]);
+
+  let out = Git::describe()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  // Lightweight tags are only considered with `tags`
+  assert!(!out.status.success());
+
+  let out = Git::describe()
+    .tags()
+    .match_pattern("v*")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "v1.0\n");
+
+  let out = Git::describe()
+    .tags()
+    .exclude("v*")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "release-1\n");
+
+  let out = Git::describe()
+    .all()
+    .commit_ish("HEAD")
+    .exclude("v*")
+    .exclude("release-*")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "heads/main\n");
+}
+
+#[test]
+fn git_describe_always_dirty() {
+  let dir = repo("git_describe");
+  commit(dir.path(), "file", "1", "first");
+  let out = Git::describe()
+    .always()
+    .abbrev(10)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&out),
+    format!("{}\n", &rev(dir.path(), "HEAD")[..10])
+  );
+
+  git(dir.path(), &["tag", "-a", "v1.0", "-m", "v1.0"]);
+  fs::write(dir.path().join("file"), "changed").unwrap();
+  let out = Git::describe()
+    .dirty(None::<String>)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "v1.0-dirty\n");
+
+  let out = Git::describe()
+    .dirty(Some("-modified"))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "v1.0-modified\n");
+}
@@ -0,0 +1,255 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_diff_commits() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "file", "hello\nworld\n", "first");
+  commit(dir.path(), "file", "hello\nthere\n", "second");
+  let out = Git::diff()
+    .commits("HEAD~1", "HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let diff = stdout(&out);
+  assert!(diff.contains("\n-world\n"));
+  assert!(diff.contains("\n+there\n"));
+  assert!(diff.contains("\n hello\n"));
+}
+
+#[test]
+fn git_diff_unified() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "file", "hello\nworld\n", "first");
+  commit(dir.path(), "file", "hello\nthere\n", "second");
+  let out = Git::diff()
+    .unified(0)
+    .commits("HEAD~1", "HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!stdout(&out).contains("\n hello\n"));
+}
+
+#[test]
+fn git_diff_cached() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "a", "a", "first");
+  fs::write(dir.path().join("a"), "b").unwrap();
+  fs::write(dir.path().join("b"), "b").unwrap();
+  git(dir.path(), &["add", "b"]);
+
+  let out = Git::diff()
+    .name_only()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "a\n");
+
+  let out = Git::diff()
+    .staged()
+    .name_status()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "A\tb\n");
+}
+
+#[test]
+fn git_diff_pathspecs() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  fs::write(dir.path().join("a"), "aa").unwrap();
+  fs::write(dir.path().join("b"), "bb").unwrap();
+  let out = Git::diff()
+    .shortstat()
+    .pathspecs(["b"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&out),
+    " 1 file changed, 1 insertion(+), 1 deletion(-)\n"
+  );
+}
+
+#[test]
+fn git_diff_output_file() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "file", "hello\n", "first");
+  commit(dir.path(), "file", "hello\nworld\n", "second");
+  let output = dir.path().join("out.diff");
+  let out = Git::diff()
+    .stat()
+    .commits("HEAD~1", "HEAD")
+    .output_file(&output)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(out.stdout.is_empty());
+  assert!(fs::read_to_string(output).unwrap().contains("file | 1 +"));
+}
+
+#[test]
+fn git_diff_stat_entries() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "a", "one\ntwo\nthree\n", "first");
+  commit(dir.path(), "b", "one\n", "second");
+  fs::write(dir.path().join("a"), "one\n2\n3\nfour\n").unwrap();
+  fs::write(dir.path().join("b"), "").unwrap();
+  git(dir.path(), &["commit", "-am", "third"]);
+  let stat = Git::builder()
+    .working_dir(dir.path())
+    .diff()
+    .commits("HEAD~1", "HEAD")
+    .stat_entries()
+    .unwrap();
+  assert_eq!(
+    stat,
+    GitDiffStat {
+      files: vec![
+        GitDiffStatEntry {
+          path: "a".into(),
+          insertions: 3,
+          deletions: 2,
+        },
+        GitDiffStatEntry {
+          path: "b".into(),
+          insertions: 0,
+          deletions: 1,
+        },
+      ],
+      total_insertions: 3,
+      total_deletions: 3,
+    }
+  );
+}
+
+#[test]
+fn git_diff_stat_entries_no_changes() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "file", "hello\n", "first");
+  let stat = Git::builder()
+    .working_dir(dir.path())
+    .diff()
+    .stat_entries()
+    .unwrap();
+  assert!(stat.files.is_empty());
+  assert_eq!(stat.total_insertions, 0);
+  assert_eq!(stat.total_deletions, 0);
+}
+
+#[test]
+fn git_diff_stat_entries_ignores_other_output_options() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "a", "one\n", "first");
+  fs::write(dir.path().join("a"), "one\ntwo\n").unwrap();
+  let out = dir.path().join("diff.out");
+  let stat = Git::builder()
+    .working_dir(dir.path())
+    .diff()
+    .stat()
+    .name_status()
+    .output_file(&out)
+    .stat_entries()
+    .unwrap();
+  assert_eq!(
+    stat,
+    GitDiffStat {
+      files: vec![GitDiffStatEntry {
+        path: "a".into(),
+        insertions: 1,
+        deletions: 0,
+      }],
+      total_insertions: 1,
+      total_deletions: 0,
+    }
+  );
+  assert!(!out.exists());
+}
+
+#[test]
+fn git_diff_parse_numstat_output() {
+  let output =
+    b"-\t-\timage.png\n4\t0\tsrc/{old.rs => new.rs}\n 2 files changed, 4 insertions(+)\n";
+  let stat = GitDiffStat::parse_numstat_output(output).unwrap();
+  assert_eq!(stat.files[0].insertions, 0);
+  assert_eq!(
+    stat.files[1].path,
+    std::path::PathBuf::from("src/{old.rs => new.rs}")
+  );
+  assert_eq!(stat.total_insertions, 4);
+  assert_eq!(stat.total_deletions, 0);
+
+  assert!(GitDiffStat::parse_numstat_output(b"1\tfile\n").is_err());
+}
+
+#[test]
+fn git_diff_parse_numstat_output_rejects_stat() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "a", "1\n", "first");
+  fs::write(dir.path().join("a"), "2\n3\n").unwrap();
+  let out = Git::diff()
+    .stat()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).contains("a | 3 ++-"));
+  assert!(GitDiffStat::parse_numstat_output(&out.stdout).is_err());
+}
+
+#[test]
+fn git_diff_word_diff_args() {
+  assert_eq!(
+    Git::diff()
+      .word_diff(WordDiffMode::Plain)
+      .word_diff_regex("[^[:space:]]")
+      .to_args(),
+    [
+      "diff",
+      "--word-diff=plain",
+      "--word-diff-regex=[^[:space:]]"
+    ]
+  );
+  assert_eq!(
+    Git::diff().word_diff(WordDiffMode::None).to_args(),
+    ["diff", "--word-diff=none"]
+  );
+}
+
+#[test]
+fn git_diff_word_diff_porcelain() {
+  let dir = repo("git_diff");
+  commit(dir.path(), "file", "the quick brown fox\n", "first");
+  std::fs::write(dir.path().join("file"), "the slow brown fox\n").unwrap();
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .diff()
+    .word_diff(WordDiffMode::Porcelain)
+    .execute()
+    .unwrap();
+  let out = stdout(&out);
+  let lines: Vec<&str> = out
+    .lines()
+    .skip_while(|line| !line.starts_with("@@"))
+    .collect();
+  assert_eq!(lines[1..], [" the ", "-quick", "+slow", "  brown fox", "~"]);
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_difftool_extcmd() {
+  let dir = repo("git_difftool");
+  commit(dir.path(), "a", "old a\n", "first");
+  commit(dir.path(), "b", "old b\n", "second");
+  commit(dir.path(), "a", "new a\n", "third");
+  // `cat` stands in for a real diff tool so the test doesn't need one
+  let out = Git::difftool()
+    .extcmd("cat")
+    .no_prompt()
+    .commit_range("HEAD~2")
+    .pathspecs(["a"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "old a\nnew a\n");
+}
+
+#[test]
+fn git_difftool_trust_exit_code() {
+  let dir = repo("git_difftool");
+  commit(dir.path(), "a", "old a\n", "first");
+  commit(dir.path(), "a", "new a\n", "second");
+  let builder = Git::builder()
+    .working_dir(dir.path())
+    .difftool()
+    .extcmd("false")
+    .no_prompt()
+    .commit_range("HEAD~1");
+  assert!(builder.clone().execute().is_ok());
+  assert!(builder.trust_exit_code().execute().is_err());
+}
+
+#[test]
+fn git_difftool_args() {
+  let args = Git::difftool()
+    .tool("meld")
+    .dir_diff()
+    .no_gui()
+    .commit_range("main..topic")
+    .pathspecs(["src"])
+    .to_args();
+  assert_eq!(
+    args,
+    [
+      "difftool",
+      "--tool=meld",
+      "--dir-diff",
+      "--no-gui",
+      "main..topic",
+      "--",
+      "src"
+    ]
+  );
+  assert_eq!(Git::difftool().gui().to_args(), ["difftool", "--gui"]);
+}
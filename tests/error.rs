@@ -0,0 +1,34 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use tempdir::TempDir;
+
+#[test]
+fn git_execute_success() {
+  let dir = TempDir::new("git_execute").unwrap();
+  let out = Git::init().quiet().directory(dir.path()).execute().unwrap();
+  assert!(out.status.success());
+  assert!(dir.path().join(".git").is_dir());
+}
+
+#[test]
+fn git_execute_non_zero_exit() {
+  let dir = repo("git_execute");
+  // There are no commits yet, so there is nothing to log
+  let err = Git::builder()
+    .working_dir(dir.path())
+    .log()
+    .execute()
+    .unwrap_err();
+  match &err {
+    GitError::NonZeroExit { code, stderr } => {
+      assert_eq!(*code, Some(128));
+      assert!(String::from_utf8_lossy(stderr).contains("does not have any commits yet"));
+    }
+    err => panic!("unexpected error: {:?}", err),
+  }
+  assert!(err
+    .to_string()
+    .starts_with("git exited with code 128: fatal:"));
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::*;
+use git_cmd::*;
+use tempdir::TempDir;
+
+#[tokio::test]
+async fn git_execute_async() {
+  let dir = TempDir::new("git_execute_async").unwrap();
+  let out = Git::init()
+    .quiet()
+    .directory(dir.path())
+    .execute_async()
+    .await
+    .unwrap();
+  assert!(out.status.success());
+  assert!(dir.path().join(".git").is_dir());
+}
+
+#[tokio::test]
+async fn git_execute_async_non_zero_exit() {
+  let dir = repo("git_execute_async");
+  let err = Git::builder()
+    .working_dir(dir.path())
+    .log()
+    .execute_async()
+    .await
+    .unwrap_err();
+  match err {
+    GitError::NonZeroExit { code, .. } => assert_eq!(code, Some(128)),
+    err => panic!("unexpected error: {:?}", err),
+  }
+}
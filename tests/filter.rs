@@ -0,0 +1,67 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn object_filter_spec() {
+  assert_eq!(ObjectFilter::BlobNone.to_filter_spec(), "blob:none");
+  assert_eq!(
+    ObjectFilter::BlobLimit("1m".into()).to_filter_spec(),
+    "blob:limit=1m"
+  );
+  assert_eq!(ObjectFilter::TreeDepth(0).to_filter_spec(), "tree:0");
+  assert_eq!(
+    ObjectFilter::Object("commit".into()).to_filter_spec(),
+    "object:type=commit"
+  );
+  assert_eq!(
+    ObjectFilter::Sparse("main:.sparse".into()).to_filter_spec(),
+    "sparse:oid=main:.sparse"
+  );
+  assert_eq!(
+    ObjectFilter::Combine(vec![ObjectFilter::BlobNone, ObjectFilter::TreeDepth(0)])
+      .to_filter_spec(),
+    "combine:blob:none+tree:0"
+  );
+  assert_eq!(
+    ObjectFilter::Custom("sparse:oid=main:filter".into()).to_filter_spec(),
+    "sparse:oid=main:filter"
+  );
+  let filter = ObjectFilter::Combine(vec![
+    ObjectFilter::BlobNone,
+    ObjectFilter::TreeDepth(2),
+    ObjectFilter::Custom("object:type=a+b".into()),
+  ]);
+  assert_eq!(
+    filter.to_filter_spec(),
+    "combine:blob:none+tree:2+object:type=a%2bb"
+  );
+}
+
+#[test]
+fn object_filter_partial_clone() {
+  let origin = repo("object_filter");
+  commit(origin.path(), "file", "contents", "first");
+  let dir = tempdir::TempDir::new("object_filter").unwrap();
+  let filter = ObjectFilter::Combine(vec![
+    ObjectFilter::BlobLimit("1k".into()),
+    ObjectFilter::TreeDepth(1),
+  ]);
+  git(
+    dir.path(),
+    &[
+      "clone",
+      "--quiet",
+      "--no-checkout",
+      &format!("--filter={}", filter.to_filter_spec()),
+      &format!("file://{}", origin.path().display()),
+      "clone",
+    ],
+  );
+  let config = stdout(&git(
+    &dir.path().join("clone"),
+    &["config", "remote.origin.partialclonefilter"],
+  ));
+  assert_eq!(config, "combine:blob:limit=1k+tree:1\n");
+}
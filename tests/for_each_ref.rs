@@ -0,0 +1,76 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_for_each_ref() {
+  let dir = repo("git_for_each_ref");
+  commit(dir.path(), "file", "1", "first");
+  git(dir.path(), &["tag", "v1.0"]);
+  commit(dir.path(), "file", "2", "second");
+  git(dir.path(), &["tag", "v1.1"]);
+  git(dir.path(), &["tag", "-a", "-m", "release", "v2.0"]);
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .for_each_ref()
+    .patterns(["refs/tags/"])
+    .format("%(refname)")
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&out),
+    "refs/tags/v1.0\nrefs/tags/v1.1\nrefs/tags/v2.0\n"
+  );
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .for_each_ref()
+    .patterns(["refs/tags/"])
+    .format("%(refname:short)")
+    .sort(["-refname"])
+    .count(2)
+    .execute()
+    .unwrap();
+  assert_eq!(stdout(&out), "v2.0\nv1.1\n");
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .for_each_ref()
+    .format("%(refname:short)")
+    .points_at("HEAD")
+    .execute()
+    .unwrap();
+  assert_eq!(stdout(&out), "main\nv1.1\nv2.0\n");
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .for_each_ref()
+    .patterns(["refs/tags/"])
+    .format("%(refname:short)")
+    .no_contains("HEAD")
+    .execute()
+    .unwrap();
+  assert_eq!(stdout(&out), "v1.0\n");
+}
+
+#[test]
+fn git_for_each_ref_args() {
+  assert_eq!(
+    Git::for_each_ref()
+      .sort(["refname", "-creatordate"])
+      .contains("abc")
+      .no_merged("main")
+      .patterns(["refs/heads/"])
+      .to_args(),
+    [
+      "for-each-ref",
+      "--sort=refname",
+      "--sort=-creatordate",
+      "--no-merged=main",
+      "--contains=abc",
+      "refs/heads/"
+    ]
+  );
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+use std::path::PathBuf;
+
+/// Creates a repo with a single commit on a `feature` branch on top of `main`
+fn feature_repo() -> tempdir::TempDir {
+  let dir = repo("git_format_patch");
+  commit(dir.path(), "file", "hello\n", "first");
+  git(dir.path(), &["checkout", "--quiet", "-b", "feature"]);
+  commit(dir.path(), "file", "hello\nworld\n", "add world");
+  dir
+}
+
+/// Returns the sorted paths of the `.patch` files in `dir`
+fn patch_files(dir: &std::path::Path) -> Vec<PathBuf> {
+  let mut files: Vec<_> = fs::read_dir(dir)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .filter(|path| path.extension().is_some_and(|ext| ext == "patch"))
+    .collect();
+  files.sort();
+  files
+}
+
+#[test]
+fn git_format_patch() {
+  let dir = feature_repo();
+  let out_dir = dir.path().join("out");
+  let out = Git::format_patch()
+    .range("main..feature")
+    .output_directory(&out_dir)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let files = patch_files(&out_dir);
+  assert_eq!(files.len(), 1);
+  assert!(files[0].ends_with("0001-add-world.patch"));
+
+  git(dir.path(), &["checkout", "--quiet", "main"]);
+  let out = Git::apply()
+    .patches(&files)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    fs::read_to_string(dir.path().join("file")).unwrap(),
+    "hello\nworld\n"
+  );
+}
+
+#[test]
+fn git_format_patch_headers() {
+  let dir = feature_repo();
+  let out_dir = dir.path().join("out");
+  let out = Git::format_patch()
+    .range("main")
+    .output_directory(&out_dir)
+    .numbered()
+    .start_number(3)
+    .subject_prefix("RFC PATCH")
+    .from("Other Person <other@example.com>")
+    .to(["a@example.com", "b@example.com"])
+    .cc(["c@example.com"])
+    .signoff()
+    .base("main")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let files = patch_files(&out_dir);
+  assert_eq!(files.len(), 1);
+  assert!(files[0].ends_with("0003-add-world.patch"));
+  let patch = fs::read_to_string(&files[0]).unwrap();
+  assert!(patch.contains("From: Other Person <other@example.com>\n"));
+  assert!(patch.contains("Subject: [RFC PATCH 3/3] add world\n"));
+  assert!(patch.contains("To: a@example.com,\n    b@example.com\n"));
+  assert!(patch.contains("Cc: c@example.com\n"));
+  assert!(patch.contains("Signed-off-by: Test User <test@example.com>\n"));
+  assert!(patch.contains(&format!("base-commit: {}\n", rev(dir.path(), "main"))));
+}
+
+#[test]
+fn git_format_patch_cover_letter_attach() {
+  let dir = feature_repo();
+  let out_dir = dir.path().join("out");
+  let out = Git::format_patch()
+    .range("main")
+    .output_directory(&out_dir)
+    .no_numbered()
+    .cover_letter()
+    .attach()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let files = patch_files(&out_dir);
+  assert_eq!(files.len(), 2);
+  assert!(files[0].ends_with("0000-cover-letter.patch"));
+  let patch = fs::read_to_string(&files[1]).unwrap();
+  assert!(patch.contains("Subject: [PATCH] add world\n"));
+  assert!(patch.contains("Content-Type: multipart/mixed;"));
+}
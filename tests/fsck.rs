@@ -0,0 +1,84 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_fsck() {
+  let dir = repo("git_fsck");
+  commit(dir.path(), "file", "contents", "first");
+  let out = Git::fsck()
+    .full()
+    .strict()
+    .no_dangling()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+}
+
+#[test]
+fn git_fsck_root() {
+  let dir = repo("git_fsck");
+  commit(dir.path(), "file", "contents", "first");
+  let first = rev(dir.path(), "HEAD");
+  commit(dir.path(), "file", "more contents", "second");
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .fsck()
+    .root()
+    .execute()
+    .unwrap();
+  assert_eq!(stdout(&out), format!("root {}\n", first));
+}
+
+#[test]
+fn git_fsck_corrupt_object() {
+  let dir = repo("git_fsck");
+  commit(dir.path(), "file", "contents", "first");
+  let blob = stdout(&git(dir.path(), &["rev-parse", "HEAD:file"]));
+  let blob = blob.trim();
+  let path = dir
+    .path()
+    .join(".git/objects")
+    .join(&blob[..2])
+    .join(&blob[2..]);
+  // Loose objects are read-only, so replace the file instead of writing to it
+  fs::remove_file(&path).unwrap();
+  fs::write(&path, "not a zlib stream").unwrap();
+
+  let err = Git::builder()
+    .working_dir(dir.path())
+    .fsck()
+    .execute()
+    .unwrap_err();
+  assert!(matches!(err, GitError::NonZeroExit { .. }));
+}
+
+#[test]
+fn git_fsck_args() {
+  let args = Git::fsck()
+    .dangling()
+    .lost_found()
+    .name_objects()
+    .no_reflogs()
+    .connectivity_only()
+    .verbose()
+    .objects(["HEAD"])
+    .to_args();
+  assert_eq!(
+    args,
+    [
+      "fsck",
+      "--verbose",
+      "--lost-found",
+      "--name-objects",
+      "--no-reflogs",
+      "--connectivity-only",
+      "--dangling",
+      "HEAD"
+    ]
+  );
+}
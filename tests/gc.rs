@@ -0,0 +1,75 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_gc_auto() {
+  let dir = repo("git_gc");
+  let out = Git::gc()
+    .auto()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+}
+
+#[test]
+fn git_gc_prune() {
+  let dir = repo("git_gc");
+  commit(dir.path(), "a", "a", "first");
+  // An object nothing points to that only gets removed when pruning
+  std::fs::write(dir.path().join("loose"), "loose").unwrap();
+  let blob = stdout(&git(dir.path(), &["hash-object", "-w", "loose"]));
+  let blob = blob.trim();
+  let exists = |obj| {
+    std::process::Command::new("git")
+      .args(["cat-file", "-e", obj])
+      .current_dir(dir.path())
+      .status()
+      .unwrap()
+      .success()
+  };
+  assert!(exists(blob));
+
+  let out = Git::gc()
+    .quiet()
+    .prune("now")
+    .no_prune()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(exists(blob));
+
+  let out = Git::gc()
+    .quiet()
+    .no_prune()
+    .prune("now")
+    .force()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!exists(blob));
+  assert!(exists("HEAD"));
+}
+
+#[test]
+fn git_gc_aggressive() {
+  let dir = repo("git_gc");
+  commit(dir.path(), "a", "a", "first");
+  let out = Git::gc()
+    .quiet()
+    .aggressive()
+    .keep_largest_pack()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(out.stdout.is_empty());
+}
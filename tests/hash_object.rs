@@ -0,0 +1,117 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+use std::io::Write;
+use std::process::Stdio;
+
+/// The blob name of `"hello world\n"`
+const HELLO_WORLD: &str = "3b18e512dba79e4c8300dd08aeb37f8e728b8dad";
+
+#[test]
+fn git_hash_object() {
+  let dir = repo("git_hash_object");
+  fs::write(dir.path().join("file"), "hello world\n").unwrap();
+  let out = Git::hash_object()
+    .files(["file"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), format!("{}\n", HELLO_WORLD));
+
+  // Without `write` nothing is added to the object database
+  let out = Git::cat_file()
+    .mode(CatFileMode::Exists)
+    .object(HELLO_WORLD)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+
+  let out = Git::hash_object()
+    .write()
+    .files(["file"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  git(dir.path(), &["cat-file", "-e", HELLO_WORLD]);
+}
+
+#[test]
+fn git_hash_object_stdin() {
+  let dir = repo("git_hash_object");
+  let mut child = Git::hash_object()
+    .stdin()
+    .make_cmd()
+    .current_dir(dir.path())
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .unwrap();
+  child
+    .stdin
+    .take()
+    .unwrap()
+    .write_all(b"hello world\n")
+    .unwrap();
+  let out = child.wait_with_output().unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), format!("{}\n", HELLO_WORLD));
+
+  fs::write(dir.path().join("a"), "hello world\n").unwrap();
+  fs::write(dir.path().join("b"), "").unwrap();
+  let mut child = Git::hash_object()
+    .stdin_paths()
+    .make_cmd()
+    .current_dir(dir.path())
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .unwrap();
+  child.stdin.take().unwrap().write_all(b"a\nb\n").unwrap();
+  let out = child.wait_with_output().unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&out),
+    format!(
+      "{}\ne69de29bb2d1d6434b8b29ae775ad8c2e48c5391\n",
+      HELLO_WORLD
+    )
+  );
+}
+
+#[test]
+fn git_hash_object_type() {
+  let dir = repo("git_hash_object");
+  fs::write(dir.path().join("bad"), "not a commit").unwrap();
+  let out = Git::hash_object()
+    .object_type(ObjectType::Commit)
+    .files(["bad"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+
+  let out = Git::hash_object()
+    .object_type(ObjectType::Commit)
+    .literally()
+    .write()
+    .files(["bad"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let hash = stdout(&out);
+  assert_eq!(
+    stdout(&git(dir.path(), &["cat-file", "-t", hash.trim()])),
+    "commit\n"
+  );
+}
@@ -1,5 +1,6 @@
 use git_cmd::*;
 use tempdir::TempDir;
+use std::process::Command;
 use std::{fs, env};
 
 #[test]
@@ -171,3 +172,90 @@ fn git_init_shared() {
   init(Shared::Umask);
   init(Shared::World);
 }
+
+#[test]
+fn git_init_clone() {
+  let base = Git::init().bare().initial_branch("main");
+  let with_dir = base.clone().directory("/tmp/a");
+
+  let base_args = base
+    .make_cmd()
+    .get_args()
+    .map(ToOwned::to_owned)
+    .collect::<Vec<_>>();
+  let with_dir_args = with_dir
+    .make_cmd()
+    .get_args()
+    .map(ToOwned::to_owned)
+    .collect::<Vec<_>>();
+  assert_eq!(base_args, ["init", "--bare", "--initial-branch", "main"]);
+  assert_eq!(
+    with_dir_args,
+    ["init", "--bare", "--initial-branch", "main", "/tmp/a"]
+  );
+}
+
+#[test]
+fn git_init_to_args() {
+  let builder = Git::init().bare().initial_branch("main");
+  let args = builder.to_args();
+  assert_eq!(args[0], "init");
+  assert!(args.iter().any(|arg| arg == "--bare"));
+
+  // Echo the arguments back from a process to check they match what git sees
+  let out = Command::new("sh")
+    .arg("-c")
+    .arg(r#"printf '%s\n' "$@""#)
+    .arg("sh")
+    .args(builder.make_cmd().get_args())
+    .output()
+    .unwrap();
+  let seen = String::from_utf8(out.stdout).unwrap();
+  assert_eq!(seen.lines().collect::<Vec<_>>(), args);
+}
+
+#[test]
+fn git_init_display() {
+  let cmd = format!("{}", Git::init().bare().directory("/tmp/my repo"));
+  assert_eq!(cmd, "git init --bare '/tmp/my repo'");
+}
+
+#[test]
+fn git_init_object_format_custom() {
+  // git rejects this when run, but the name is passed through unchanged
+  let args = Git::init()
+    .object_format(Hash::Custom("sha512".into()))
+    .to_args();
+  assert_eq!(args, ["init", "--object-format", "sha512"]);
+}
+
+#[test]
+fn git_init_try_octal() {
+  assert!(matches!(Shared::try_octal(0o777), Ok(Shared::Octal(0o777))));
+  assert!(matches!(Shared::try_octal(0), Ok(Shared::Octal(0))));
+  let err = Shared::try_octal(0o1000).err().unwrap();
+  assert_eq!(err.perm(), 0o1000);
+  // 0o800 isn't a valid octal literal, so this is the same number written in
+  // decimal
+  assert!(Shared::try_octal(512).is_err());
+}
+
+#[test]
+fn git_init_try_make_cmd() {
+  assert!(Git::init()
+    .shared(Shared::Octal(0o640))
+    .try_make_cmd()
+    .is_ok());
+  let err = Git::init()
+    .shared(Shared::Octal(0o1000))
+    .try_make_cmd()
+    .err()
+    .unwrap();
+  assert!(matches!(err, GitError::InvalidOctal(_)));
+}
+
+#[test]
+#[should_panic(expected = "invalid shared permissions 0o1000")]
+fn git_init_make_cmd_invalid_octal() {
+  Git::init().shared(Shared::Octal(0o1000)).make_cmd();
+}
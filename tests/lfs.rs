@@ -0,0 +1,61 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_lfs_track() {
+  let args = Git::lfs()
+    .track(["*.psd"])
+    .make_cmd()
+    .get_args()
+    .map(ToOwned::to_owned)
+    .collect::<Vec<_>>();
+  assert_eq!(args, ["lfs", "track", "*.psd"]);
+  assert_eq!(
+    Git::lfs().untrack(["*.psd", "*.png"]).to_args(),
+    ["lfs", "untrack", "*.psd", "*.png"]
+  );
+}
+
+#[test]
+fn git_lfs_args() {
+  assert_eq!(
+    Git::lfs().install(true, false, true).to_args(),
+    ["lfs", "install", "--local", "--skip-smudge"]
+  );
+  assert_eq!(
+    Git::lfs().push("origin", ["main"], false).to_args(),
+    ["lfs", "push", "origin", "main"]
+  );
+  assert_eq!(
+    Git::lfs()
+      .push("origin", Vec::<String>::new(), true)
+      .to_args(),
+    ["lfs", "push", "--all", "origin"]
+  );
+  assert_eq!(
+    Git::lfs().fetch("origin", ["main", "dev"]).to_args(),
+    ["lfs", "fetch", "origin", "main", "dev"]
+  );
+  assert_eq!(Git::lfs().ls_files().env().to_args(), ["lfs", "env"]);
+}
+
+#[test]
+fn git_lfs_display() {
+  let dir = repo("git_lfs");
+  let builder = Git::builder()
+    .working_dir(dir.path())
+    .lfs()
+    .track(["*.psd"]);
+  assert_eq!(
+    builder.to_string(),
+    format!("git -C {} lfs track '*.psd'", dir.path().display())
+  );
+}
+
+#[test]
+#[should_panic(expected = "an operation must be set")]
+fn git_lfs_no_op() {
+  Git::lfs().make_cmd();
+}
@@ -0,0 +1,277 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_log() {
+  let dir = repo("git_log");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  commit(dir.path(), "c", "c", "third");
+  let out = Git::log()
+    .oneline()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out).lines().count(), 3);
+}
+
+#[test]
+fn git_log_max_count() {
+  let dir = repo("git_log");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  commit(dir.path(), "c", "c", "third");
+  let out = Git::log()
+    .format("%s")
+    .max_count(1)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "third\n");
+}
+
+#[test]
+fn git_log_skip() {
+  let dir = repo("git_log");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  commit(dir.path(), "c", "c", "third");
+  let out = Git::log()
+    .format("%s")
+    .skip(1)
+    .max_count(1)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "second\n");
+}
+
+#[test]
+fn git_log_pathspecs() {
+  let dir = repo("git_log");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  commit(dir.path(), "a", "aa", "third");
+  // A branch with the same name as the file makes sure the `--` separator is
+  // there to disambiguate it
+  git(dir.path(), &["branch", "a"]);
+  let out = Git::log()
+    .format("%s")
+    .pathspecs(["a"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "third\nfirst\n");
+}
+
+#[test]
+fn git_log_filters() {
+  let dir = repo("git_log");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "fix the thing");
+  let out = Git::log()
+    .format("%s")
+    .grep("fix")
+    .author("Test User")
+    .no_merges()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "fix the thing\n");
+}
+
+#[test]
+fn git_log_name_only() {
+  let dir = repo("git_log");
+  commit(dir.path(), "a", "a", "first");
+  let out = Git::log()
+    .format("%s")
+    .name_only()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "first\n\na\n");
+}
+
+#[test]
+fn git_log_decorate() {
+  let dir = repo("git_log");
+  commit(dir.path(), "a", "a", "first");
+  let out = Git::log()
+    .oneline()
+    .all()
+    .graph()
+    .decorate(DecorateMode::Full)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).contains("refs/heads/main"));
+}
+
+#[test]
+fn git_log_structured() {
+  let dir = repo("git_log");
+  commit(dir.path(), "a", "a", "first");
+  fs::write(dir.path().join("a"), "b").unwrap();
+  git(
+    dir.path(),
+    &[
+      "commit",
+      "--quiet",
+      "--all",
+      "--author=Other Person <other@example.com>",
+      "--date=@1600000000 +0000",
+      "-m",
+      "second\n\nA body that spans\nmultiple lines\n\nand paragraphs",
+    ],
+  );
+  let out = Git::log()
+    .structured()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let entries = GitLogEntry::parse_log_output(&out.stdout).unwrap();
+  assert_eq!(entries.len(), 2);
+
+  let entry = &entries[0];
+  let head = rev(dir.path(), "HEAD");
+  assert_eq!(entry.hash, head);
+  assert!(head.starts_with(&entry.abbreviated_hash));
+  assert_eq!(entry.author_name, "Other Person");
+  assert_eq!(entry.author_email, "other@example.com");
+  assert_eq!(entry.author_timestamp, 1600000000);
+  assert_eq!(entry.committer_name, "Test User");
+  assert_eq!(entry.committer_email, "test@example.com");
+  assert!(entry.committer_timestamp > 1600000000);
+  assert_eq!(entry.subject, "second");
+  assert_eq!(
+    entry.body,
+    "A body that spans\nmultiple lines\n\nand paragraphs"
+  );
+  assert_eq!(entry.parent_hashes, [rev(dir.path(), "HEAD~1")]);
+
+  assert_eq!(entries[1].subject, "first");
+  assert_eq!(entries[1].body, "");
+  assert!(entries[1].parent_hashes.is_empty());
+}
+
+#[test]
+fn git_log_parse_log_output_error() {
+  assert_eq!(GitLogEntry::parse_log_output(b"").unwrap(), []);
+  let err = GitLogEntry::parse_log_output(b"abc\0def\0").unwrap_err();
+  assert_eq!(
+    err.to_string(),
+    "unable to parse git output: expected 11 fields per commit but got 2 fields in total"
+  );
+}
+
+#[test]
+fn git_log_log_format() {
+  let args = Git::log()
+    .log_format(LogFormat::Format("%H %s".into()))
+    .to_args();
+  assert_eq!(args, ["log", "--format=%H %s"]);
+  assert_eq!(
+    Git::log().log_format(LogFormat::Oneline).to_args(),
+    ["log", "--oneline"]
+  );
+  assert_eq!(
+    Git::log().log_format(LogFormat::Fuller).to_args(),
+    ["log", "--format=fuller"]
+  );
+  assert_eq!(
+    Git::log()
+      .format("%h")
+      .log_format(LogFormat::Pretty("raw".into()))
+      .to_args(),
+    ["log", "--pretty=raw"]
+  );
+
+  let dir = repo("git_log");
+  commit(dir.path(), "file", "contents", "first");
+  commit(dir.path(), "file", "more contents", "second");
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .log()
+    .log_format(LogFormat::Format("%s".into()))
+    .execute()
+    .unwrap();
+  assert_eq!(stdout(&out), "second\nfirst\n");
+}
+
+#[test]
+fn git_log_make_child() {
+  use std::io::{BufRead, BufReader};
+
+  let dir = repo("git_log");
+  // Every patch rewrites all the lines of the file, so the output is far
+  // larger than the 64 KiB a pipe can buffer
+  for i in 0..4 {
+    let contents: String = (0..10_000)
+      .map(|line| format!("{} {}\n", i, line))
+      .collect();
+    commit(dir.path(), "file", &contents, &format!("commit {}", i));
+  }
+  let mut child = Git::builder()
+    .working_dir(dir.path())
+    .log()
+    .format("%s")
+    .patch()
+    .make_child()
+    .unwrap();
+  let mut line = String::new();
+  BufReader::new(child.stdout.take().unwrap())
+    .read_line(&mut line)
+    .unwrap();
+  assert_eq!(line, "commit 3\n");
+  // The pipe is closed by now, so git has to stop instead of blocking on the
+  // rest of the output
+  assert!(child.wait().is_ok());
+}
+
+#[test]
+fn git_log_simplify_by_decoration_source() {
+  let dir = repo("git_log");
+  commit(dir.path(), "file", "base", "base");
+  git(dir.path(), &["branch", "feature"]);
+  commit(dir.path(), "file", "main 1", "main 1");
+  commit(dir.path(), "file", "main 2", "main 2");
+  git(dir.path(), &["checkout", "--quiet", "feature"]);
+  commit(dir.path(), "other", "feature 1", "feature 1");
+  commit(dir.path(), "other", "feature 2", "feature 2");
+
+  let log = |builder: GitLogBuilder| {
+    let out = builder.execute().unwrap();
+    let mut lines: Vec<String> = stdout(&out).lines().map(String::from).collect();
+    lines.sort();
+    lines
+  };
+  let builder = Git::builder().working_dir(dir.path()).log().all();
+  // Root commits are always kept, but the ones in between the tips aren't
+  assert_eq!(
+    log(builder.clone().format("%s").simplify_by_decoration()),
+    ["base", "feature 2", "main 2"]
+  );
+  let lines = log(builder.format("%S %s").source());
+  assert!(lines.contains(&"refs/heads/feature feature 1".to_string()));
+  assert!(lines.contains(&"refs/heads/main main 1".to_string()));
+}
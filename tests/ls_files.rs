@@ -0,0 +1,123 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_ls_files_cached() {
+  let dir = repo("git_ls_files");
+  fs::create_dir(dir.path().join("dir")).unwrap();
+  for file in ["a", "b", "dir/c"] {
+    fs::write(dir.path().join(file), file).unwrap();
+  }
+  fs::write(dir.path().join("untracked"), "").unwrap();
+  git(dir.path(), &["add", "a", "b", "dir/c"]);
+  let out = Git::ls_files()
+    .cached()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "a\nb\ndir/c\n");
+
+  let out = Git::ls_files()
+    .pathspecs(["dir"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "dir/c\n");
+
+  let out = Git::ls_files()
+    .full_name()
+    .make_cmd()
+    .current_dir(dir.path().join("dir"))
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "dir/c\n");
+}
+
+#[test]
+fn git_ls_files_changes() {
+  let dir = repo("git_ls_files");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  fs::write(dir.path().join("a"), "changed").unwrap();
+  fs::remove_file(dir.path().join("b")).unwrap();
+  let out = Git::ls_files()
+    .deleted()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "b\n");
+
+  let out = Git::ls_files()
+    .modified()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "a\nb\n");
+
+  let out = Git::ls_files()
+    .unmerged()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "");
+}
+
+#[test]
+fn git_ls_files_others_ignored() {
+  let dir = repo("git_ls_files");
+  commit(dir.path(), ".gitignore", "*.log\n", "ignore logs");
+  fs::create_dir(dir.path().join("new")).unwrap();
+  fs::write(dir.path().join("new/file"), "").unwrap();
+  fs::write(dir.path().join("debug.log"), "").unwrap();
+  fs::write(dir.path().join("skip.tmp"), "").unwrap();
+  let out = Git::ls_files()
+    .others()
+    .exclude_standard()
+    .exclude("*.tmp")
+    .directory()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "new/\n");
+
+  let out = Git::ls_files()
+    .others()
+    .ignored()
+    .exclude_standard()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "debug.log\n");
+}
+
+#[test]
+fn git_ls_files_eol() {
+  let dir = repo("git_ls_files");
+  commit(dir.path(), "file", "line\r\n", "first");
+  let out = Git::ls_files()
+    .eol()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).starts_with("i/crlf"));
+}
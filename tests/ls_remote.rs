@@ -0,0 +1,126 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use tempdir::TempDir;
+
+/// Creates a bare repo with a `main` and a `feature` branch and a `v1.0` tag
+fn bare_repo() -> (TempDir, TempDir) {
+  let dir = repo("git_ls_remote");
+  commit(dir.path(), "file", "1", "first");
+  git(dir.path(), &["tag", "-a", "v1.0", "-m", "v1.0"]);
+  git(dir.path(), &["branch", "feature"]);
+  let bare = TempDir::new("git_ls_remote_bare").unwrap();
+  git(
+    dir.path(),
+    &[
+      "clone",
+      "--quiet",
+      "--bare",
+      ".",
+      bare.path().to_str().unwrap(),
+    ],
+  );
+  (dir, bare)
+}
+
+#[test]
+fn git_ls_remote() {
+  let (dir, bare) = bare_repo();
+  let head = rev(dir.path(), "HEAD");
+  let out = Git::ls_remote()
+    .remote(bare.path().to_str().unwrap())
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let entries = GitLsRemoteEntry::parse_output(&out.stdout).unwrap();
+  let refnames: Vec<_> = entries.iter().map(|entry| entry.refname.as_str()).collect();
+  assert_eq!(
+    refnames,
+    [
+      "HEAD",
+      "refs/heads/feature",
+      "refs/heads/main",
+      "refs/tags/v1.0",
+      "refs/tags/v1.0^{}",
+    ]
+  );
+  assert_eq!(entries[2].hash, head);
+
+  let out = Git::ls_remote()
+    .heads()
+    .remote(bare.path().to_str().unwrap())
+    .refs(["main"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    GitLsRemoteEntry::parse_output(&out.stdout).unwrap(),
+    [GitLsRemoteEntry {
+      hash: head,
+      refname: "refs/heads/main".into(),
+    }]
+  );
+}
+
+#[test]
+fn git_ls_remote_tags_refs() {
+  let (dir, bare) = bare_repo();
+  let out = Git::ls_remote()
+    .tags()
+    .refs_flag()
+    .quiet()
+    .remote(bare.path().to_str().unwrap())
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let entries = GitLsRemoteEntry::parse_output(&out.stdout).unwrap();
+  assert_eq!(entries.len(), 1);
+  assert_eq!(entries[0].refname, "refs/tags/v1.0");
+  assert_eq!(entries[0].hash, rev(dir.path(), "v1.0"));
+}
+
+#[test]
+fn git_ls_remote_exit_code() {
+  let (dir, bare) = bare_repo();
+  let out = Git::ls_remote()
+    .exit_code()
+    .upload_pack("git-upload-pack")
+    .remote(bare.path().to_str().unwrap())
+    .refs(["does-not-exist"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert_eq!(out.status.code(), Some(2));
+}
+
+#[test]
+#[should_panic(expected = "a remote must be set with `remote` when passing `refs`")]
+fn git_ls_remote_refs_without_remote() {
+  Git::ls_remote().refs(["main"]).make_cmd();
+}
+
+#[test]
+fn git_ls_remote_refs_without_remote_display() {
+  assert_eq!(
+    Git::ls_remote().heads().refs(["main"]).to_string(),
+    "git ls-remote --heads <remote?>"
+  );
+  assert_eq!(Git::ls_remote().heads().to_args(), ["ls-remote", "--heads"]);
+}
+
+#[test]
+fn git_ls_remote_parse_output_error() {
+  let err = GitLsRemoteEntry::parse_output(b"no tab here\n").unwrap_err();
+  assert_eq!(
+    err.to_string(),
+    "unable to parse git output: missing ref name in line: no tab here"
+  );
+}
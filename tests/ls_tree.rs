@@ -0,0 +1,115 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_ls_tree() {
+  let dir = repo("git_ls_tree");
+  commit(dir.path(), "top", "top", "first");
+  commit(dir.path(), "a/b/file", "nested", "second");
+  let out = Git::ls_tree()
+    .tree_ish("HEAD")
+    .name_only()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "a\ntop\n");
+
+  let out = Git::ls_tree()
+    .tree_ish("HEAD")
+    .recursive()
+    .name_only()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "a/b/file\ntop\n");
+
+  let out = Git::ls_tree()
+    .tree_ish("HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let listing = stdout(&out);
+  assert!(listing.starts_with("040000 tree "));
+  assert!(listing.contains("\ta\n"));
+}
+
+#[test]
+fn git_ls_tree_long_abbrev() {
+  let dir = repo("git_ls_tree");
+  commit(dir.path(), "file", "12345", "first");
+  let hash = rev(dir.path(), "HEAD:file");
+  let out = Git::ls_tree()
+    .tree_ish("HEAD")
+    .long()
+    .abbrev(Some(8))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&out),
+    format!("100644 blob {}       5\tfile\n", &hash[..8])
+  );
+}
+
+#[test]
+fn git_ls_tree_paths() {
+  let dir = repo("git_ls_tree");
+  commit(dir.path(), "top", "top", "first");
+  commit(dir.path(), "a/b/file", "nested", "second");
+  let sub = dir.path().join("a");
+  let out = Git::ls_tree()
+    .tree_ish("HEAD")
+    .recursive()
+    .name_only()
+    .make_cmd()
+    .current_dir(&sub)
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "b/file\n");
+
+  let out = Git::ls_tree()
+    .tree_ish("HEAD")
+    .recursive()
+    .name_only()
+    .full_name()
+    .make_cmd()
+    .current_dir(&sub)
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "a/b/file\n");
+
+  let out = Git::ls_tree()
+    .tree_ish("HEAD")
+    .name_only()
+    .full_tree()
+    .make_cmd()
+    .current_dir(&sub)
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "a\ntop\n");
+
+  let out = Git::ls_tree()
+    .tree_ish("HEAD")
+    .recursive()
+    .name_only()
+    .pathspecs(["a/b"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "a/b/file\n");
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_maintenance_run() {
+  let dir = repo("git_maintenance");
+  commit(dir.path(), "file", "contents", "first");
+  // The task writes an incremental commit-graph chain
+  let commit_graph = dir
+    .path()
+    .join(".git/objects/info/commit-graphs/commit-graph-chain");
+  assert!(!commit_graph.exists());
+  let out = Git::maintenance()
+    .op(MaintenanceOp::Run {
+      tasks: vec![MaintenanceTask::CommitGraph],
+      auto: false,
+      quiet: true,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(commit_graph.exists());
+}
+
+#[test]
+fn git_maintenance_run_args() {
+  let args = Git::maintenance()
+    .op(MaintenanceOp::Run {
+      tasks: vec![MaintenanceTask::CommitGraph],
+      auto: false,
+      quiet: true,
+    })
+    .to_args();
+  assert_eq!(
+    args,
+    ["maintenance", "run", "--task=commit-graph", "--quiet"]
+  );
+
+  let args = Git::maintenance()
+    .op(MaintenanceOp::Run {
+      tasks: vec![
+        MaintenanceTask::Gc,
+        MaintenanceTask::Fetch,
+        MaintenanceTask::LooseObjects,
+        MaintenanceTask::IncrementalRepack,
+        MaintenanceTask::PackRefs,
+        MaintenanceTask::WorktreePrune,
+        MaintenanceTask::Custom("reflog-expire".into()),
+      ],
+      auto: true,
+      quiet: false,
+    })
+    .to_args();
+  assert_eq!(
+    args,
+    [
+      "maintenance",
+      "run",
+      "--task=gc",
+      "--task=prefetch",
+      "--task=loose-objects",
+      "--task=incremental-repack",
+      "--task=pack-refs",
+      "--task=worktree-prune",
+      "--task=reflog-expire",
+      "--auto"
+    ]
+  );
+}
+
+#[test]
+fn git_maintenance_schedule_args() {
+  // These change the global config and the system scheduler, so only check
+  // the arguments
+  for (op, name) in [
+    (MaintenanceOp::Start, "start"),
+    (MaintenanceOp::Stop, "stop"),
+    (MaintenanceOp::Register, "register"),
+    (MaintenanceOp::Unregister, "unregister"),
+  ] {
+    assert_eq!(Git::maintenance().op(op).to_args(), ["maintenance", name]);
+  }
+}
+
+#[test]
+#[should_panic(expected = "an operation must be set with `op` before calling `make_cmd`")]
+fn git_maintenance_no_op() {
+  Git::maintenance().make_cmd();
+}
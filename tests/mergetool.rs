@@ -0,0 +1,87 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn git_mergetool() {
+  let dir = repo("git_mergetool");
+  commit(dir.path(), "file", "base\n", "base");
+  git(dir.path(), &["checkout", "-q", "-b", "topic"]);
+  commit(dir.path(), "file", "topic\n", "topic");
+  git(dir.path(), &["checkout", "-q", "main"]);
+  commit(dir.path(), "file", "main\n", "main");
+  let merge = Command::new("git")
+    .args(["merge", "topic"])
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!merge.status.success());
+
+  // A tool that resolves the conflict by taking the other side, so that the
+  // test doesn't depend on a real merge tool being installed
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .config_override("mergetool.theirs.cmd", r#"cp "$REMOTE" "$MERGED""#)
+    .config_override("mergetool.theirs.trustExitCode", "true")
+    .config_override("mergetool.keepBackup", "false")
+    .mergetool()
+    .tool("theirs")
+    .no_prompt()
+    .pathspecs(["file"])
+    .execute()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    fs::read_to_string(dir.path().join("file")).unwrap(),
+    "topic\n"
+  );
+  let status = stdout(&git(dir.path(), &["status", "--porcelain"]));
+  assert_eq!(status, "M  file\n");
+}
+
+#[test]
+fn git_mergetool_tool_help() {
+  let dir = repo("git_mergetool");
+  let out = Git::mergetool()
+    .tool_help()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  // The known tools are listed whether they're installed or not
+  assert!(stdout(&out).contains("vimdiff"));
+}
+
+#[test]
+fn git_mergetool_args() {
+  let args = Git::mergetool()
+    .tool("meld")
+    .prompt()
+    .no_prompt()
+    .no_gui()
+    .gui()
+    .order_file("order.txt")
+    .pathspecs(["a", "b"])
+    .to_args();
+  assert_eq!(
+    args,
+    [
+      "mergetool",
+      "--tool=meld",
+      "--no-prompt",
+      "--gui",
+      "-Oorder.txt",
+      "--",
+      "a",
+      "b"
+    ]
+  );
+  assert_eq!(
+    Git::mergetool().tool_help().to_args(),
+    ["mergetool", "--tool-help"]
+  );
+}
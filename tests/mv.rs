@@ -0,0 +1,94 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_mv() {
+  let dir = repo("git_mv");
+  commit(dir.path(), "old", "some contents\n", "first");
+  let out = Git::mv()
+    .source("old")
+    .destination("new")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("old").exists());
+  assert!(dir.path().join("new").exists());
+  git(dir.path(), &["commit", "--quiet", "-m", "rename"]);
+
+  let log = stdout(&git(
+    dir.path(),
+    &["log", "--follow", "--format=%s", "--", "new"],
+  ));
+  assert_eq!(log, "rename\nfirst\n");
+}
+
+#[test]
+fn git_mv_dry_run() {
+  let dir = repo("git_mv");
+  commit(dir.path(), "old", "some contents\n", "first");
+  let out = Git::mv()
+    .dry_run()
+    .source("old")
+    .destination("new")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&out),
+    "Checking rename of 'old' to 'new'\nRenaming old to new\n"
+  );
+  assert!(dir.path().join("old").exists());
+  assert!(!dir.path().join("new").exists());
+  assert!(stdout(&git(dir.path(), &["status", "--porcelain"])).is_empty());
+}
+
+#[test]
+fn git_mv_sources() {
+  let dir = repo("git_mv");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  fs::create_dir(dir.path().join("dir")).unwrap();
+  let out = Git::mv()
+    .verbose()
+    .sources(["a", "b"])
+    .destination("dir")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "Renaming a to dir/a\nRenaming b to dir/b\n");
+  assert_eq!(stdout(&git(dir.path(), &["ls-files"])), "dir/a\ndir/b\n");
+}
+
+#[test]
+fn git_mv_force() {
+  let dir = repo("git_mv");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  let out = Git::mv()
+    .source("a")
+    .destination("b")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  let out = Git::mv()
+    .force()
+    .source("a")
+    .destination("b")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(fs::read_to_string(dir.path().join("b")).unwrap(), "a");
+}
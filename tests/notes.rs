@@ -0,0 +1,180 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_notes() {
+  let dir = repo("git_notes");
+  commit(dir.path(), "file", "contents", "first");
+  let out = Git::notes()
+    .op(NotesOp::Add {
+      object: None,
+      message: Some("build: passed".into()),
+      file: None,
+      allow_empty: false,
+      force: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::notes()
+    .op(NotesOp::Show(Some("HEAD".into())))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "build: passed\n");
+
+  let out = Git::notes()
+    .op(NotesOp::Remove {
+      objects: vec!["HEAD".into()],
+      ignore_missing: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::notes()
+    .op(NotesOp::Show(None))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+
+  let out = Git::notes()
+    .op(NotesOp::Remove {
+      objects: vec!["HEAD".into()],
+      ignore_missing: true,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+}
+
+#[test]
+fn git_notes_file_append_force() {
+  let dir = repo("git_notes");
+  commit(dir.path(), "file", "contents", "first");
+  fs::write(dir.path().join("note.txt"), "from a file\n").unwrap();
+  let out = Git::notes()
+    .op(NotesOp::Add {
+      object: Some("HEAD".into()),
+      message: None,
+      file: Some(dir.path().join("note.txt")),
+      allow_empty: false,
+      force: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::notes()
+    .op(NotesOp::Append {
+      object: None,
+      message: Some("appended".into()),
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&git(dir.path(), &["notes", "show"])),
+    "from a file\n\nappended\n"
+  );
+
+  let add = |force| NotesOp::Add {
+    object: None,
+    message: Some("replaced".into()),
+    file: None,
+    allow_empty: false,
+    force,
+  };
+  let out = Git::notes()
+    .op(add(false))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  let out = Git::notes()
+    .op(add(true))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&git(dir.path(), &["notes", "show"])), "replaced\n");
+}
+
+#[test]
+fn git_notes_copy_list_prune() {
+  let dir = repo("git_notes");
+  commit(dir.path(), "file", "1", "first");
+  commit(dir.path(), "file", "2", "second");
+  git(dir.path(), &["notes", "add", "-m", "note", "HEAD~1"]);
+  let out = Git::notes()
+    .op(NotesOp::Copy {
+      from: "HEAD~1".into(),
+      to: "HEAD".into(),
+      force: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::notes()
+    .op(NotesOp::List(Some("HEAD".into())))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let note = stdout(&out);
+
+  let out = Git::notes()
+    .op(NotesOp::List(None))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  // Both commits share the same note blob
+  let head = rev(dir.path(), "HEAD");
+  let parent = rev(dir.path(), "HEAD~1");
+  let mut expected = vec![
+    format!("{} {}", note.trim(), head),
+    format!("{} {}", note.trim(), parent),
+  ];
+  expected.sort();
+  let mut listed: Vec<_> = stdout(&out).lines().map(String::from).collect();
+  listed.sort();
+  assert_eq!(listed, expected);
+
+  let out = Git::notes()
+    .op(NotesOp::Prune {
+      dry_run: true,
+      verbose: true,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "");
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_pack_refs_all() {
+  let dir = repo("git_pack_refs");
+  commit(dir.path(), "file", "contents", "first");
+  for branch in &["one", "two", "three"] {
+    git(dir.path(), &["branch", branch]);
+  }
+  let heads = dir.path().join(".git/refs/heads");
+  assert_eq!(fs::read_dir(&heads).unwrap().count(), 4);
+
+  let out = Git::pack_refs()
+    .all()
+    .prune()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(fs::read_dir(&heads).unwrap().count(), 0);
+  let packed = fs::read_to_string(dir.path().join(".git/packed-refs")).unwrap();
+  assert!(packed.contains("refs/heads/main"));
+  assert!(packed.contains("refs/heads/three"));
+}
+
+#[test]
+fn git_pack_refs_no_prune() {
+  let dir = repo("git_pack_refs");
+  commit(dir.path(), "file", "contents", "first");
+  Git::builder()
+    .working_dir(dir.path())
+    .pack_refs()
+    .all()
+    .no_prune()
+    .execute()
+    .unwrap();
+  assert!(dir.path().join(".git/refs/heads/main").exists());
+  let packed = fs::read_to_string(dir.path().join(".git/packed-refs")).unwrap();
+  assert!(packed.contains("refs/heads/main"));
+}
+
+#[test]
+fn git_pack_refs_args() {
+  assert_eq!(Git::pack_refs().to_args(), ["pack-refs"]);
+  assert_eq!(
+    Git::pack_refs().no_prune().all().prune().to_args(),
+    ["pack-refs", "--all", "--prune"]
+  );
+}
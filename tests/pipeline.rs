@@ -0,0 +1,69 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::process::Command;
+use tempdir::TempDir;
+
+/// A raw git command run in `dir`, for the steps that have no builder
+fn cmd(dir: &std::path::Path, args: &[&str]) -> Command {
+  let mut cmd = Command::new("git");
+  cmd.current_dir(dir).args(args);
+  cmd
+}
+
+#[test]
+fn git_pipeline_run() {
+  let dir = TempDir::new("git_pipeline").unwrap();
+  std::fs::write(dir.path().join("file"), "contents").unwrap();
+
+  let outputs = GitPipeline::new()
+    .step(
+      Git::init()
+        .quiet()
+        .initial_branch("main")
+        .directory(dir.path()),
+    )
+    .step(cmd(dir.path(), &["add", "file"]))
+    .step(cmd(
+      dir.path(),
+      &[
+        "-c",
+        "user.name=Test User",
+        "-c",
+        "user.email=test@example.com",
+        "commit",
+        "--quiet",
+        "-m",
+        "first",
+      ],
+    ))
+    .run()
+    .unwrap();
+  assert_eq!(outputs.len(), 3);
+  assert_eq!(stdout(&git(dir.path(), &["log", "--format=%s"])), "first\n");
+}
+
+#[test]
+fn git_pipeline_stops_at_failure() {
+  let dir = repo("git_pipeline");
+  commit(dir.path(), "file", "contents", "first");
+
+  let pipeline = || {
+    GitPipeline::new()
+      .step(Git::builder().working_dir(dir.path()).tag().name("v1"))
+      .step(Git::builder().working_dir(dir.path()).tag().name("v1"))
+      .step(Git::builder().working_dir(dir.path()).tag().name("v2"))
+  };
+  let (index, err) = pipeline().run().unwrap_err();
+  assert_eq!(index, 1);
+  assert!(matches!(err, GitError::NonZeroExit { .. }));
+  assert_eq!(stdout(&git(dir.path(), &["tag"])), "v1\n");
+
+  git(dir.path(), &["tag", "-d", "v1"]);
+  let results = pipeline().run_all();
+  assert!(results[0].is_ok());
+  assert!(results[1].is_err());
+  assert!(results[2].is_ok());
+  assert_eq!(stdout(&git(dir.path(), &["tag"])), "v1\nv2\n");
+}
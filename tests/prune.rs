@@ -0,0 +1,99 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+use std::process::Command;
+
+/// Write a blob that nothing points to and return its hash
+fn loose_object(dir: &std::path::Path) -> String {
+  fs::write(dir.join("loose"), "loose").unwrap();
+  let blob = stdout(&git(dir, &["hash-object", "-w", "loose"]));
+  fs::remove_file(dir.join("loose")).unwrap();
+  blob.trim().into()
+}
+
+fn exists(dir: &std::path::Path, obj: &str) -> bool {
+  Command::new("git")
+    .args(["cat-file", "-e", obj])
+    .current_dir(dir)
+    .status()
+    .unwrap()
+    .success()
+}
+
+#[test]
+fn git_prune_dry_run() {
+  let dir = repo("git_prune");
+  commit(dir.path(), "file", "contents", "first");
+  let blob = loose_object(dir.path());
+  let out = Git::prune()
+    .dry_run()
+    .expire("now")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), format!("{} blob\n", blob));
+  assert!(exists(dir.path(), &blob));
+}
+
+#[test]
+fn git_prune_expire() {
+  let dir = repo("git_prune");
+  commit(dir.path(), "file", "contents", "first");
+  let blob = loose_object(dir.path());
+  let out = Git::prune()
+    .expire("1.week.ago")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(exists(dir.path(), &blob));
+
+  Git::builder()
+    .working_dir(dir.path())
+    .prune()
+    .expire("now")
+    .execute()
+    .unwrap();
+  assert!(!exists(dir.path(), &blob));
+}
+
+#[test]
+fn git_prune_heads() {
+  let dir = repo("git_prune");
+  commit(dir.path(), "file", "contents", "first");
+  let blob = loose_object(dir.path());
+  Git::builder()
+    .working_dir(dir.path())
+    .prune()
+    .expire("now")
+    .heads([blob.as_str()])
+    .execute()
+    .unwrap();
+  assert!(exists(dir.path(), &blob));
+}
+
+#[test]
+fn git_prune_args() {
+  let args = Git::prune()
+    .dry_run()
+    .verbose()
+    .expire("now")
+    .heads(["main"])
+    .to_args();
+  assert_eq!(
+    args,
+    [
+      "prune",
+      "--dry-run",
+      "--verbose",
+      "--expire=now",
+      "--",
+      "main"
+    ]
+  );
+}
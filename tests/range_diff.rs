@@ -0,0 +1,78 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_range_diff() {
+  let dir = repo("git_range_diff");
+  commit(dir.path(), "base", "base\n", "base");
+  git(dir.path(), &["checkout", "-q", "-b", "old"]);
+  let b = "1\n2\n3\n4\n5\n6\n7\n8\n";
+  commit(dir.path(), "a", "a\n", "add a");
+  commit(dir.path(), "b", b, "add b");
+  // The same series rebased on top of another commit, with one patch changed
+  git(dir.path(), &["checkout", "-q", "-b", "new", "main"]);
+  commit(dir.path(), "other", "other\n", "other");
+  commit(dir.path(), "a", "a\n", "add a");
+  commit(dir.path(), "b", &format!("{}9\n", b), "add b");
+
+  let out = Git::range_diff()
+    .ranges("main..old", "main..new")
+    .no_dual_color()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let diff = stdout(&out);
+  let lines = diff.lines().collect::<Vec<_>>();
+  assert!(lines[0].starts_with("-:  -------"));
+  assert!(lines[0].ends_with(" other"));
+  assert!(lines[1].contains(" = "));
+  assert!(lines[1].ends_with(" add a"));
+  assert!(lines[2].contains(" ! "));
+  assert!(lines[2].ends_with(" add b"));
+}
+
+#[test]
+fn git_range_diff_right_only() {
+  let dir = repo("git_range_diff");
+  commit(dir.path(), "base", "base\n", "base");
+  git(dir.path(), &["checkout", "-q", "-b", "old"]);
+  commit(dir.path(), "a", "a\n", "add a");
+  git(dir.path(), &["checkout", "-q", "-b", "new", "main"]);
+  commit(dir.path(), "b", "b\n", "add b");
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .range_diff()
+    .ranges("main..old", "main..new")
+    .right_only()
+    .execute()
+    .unwrap();
+  let diff = stdout(&out);
+  assert!(diff.starts_with("-:  -------"));
+  assert!(!diff.contains("add a"));
+}
+
+#[test]
+fn git_range_diff_args() {
+  let args = Git::range_diff()
+    .ranges("a..b", "c..d")
+    .creation_factor(80)
+    .left_only()
+    .diff_opts("--stat")
+    .to_args();
+  assert_eq!(
+    args,
+    [
+      "range-diff",
+      "--creation-factor=80",
+      "--left-only",
+      "--stat",
+      "a..b",
+      "c..d"
+    ]
+  );
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_read_tree() {
+  let dir = repo("git_read_tree");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+
+  Git::builder()
+    .working_dir(dir.path())
+    .read_tree()
+    .tree_ish("HEAD~1")
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&git(dir.path(), &["diff", "--cached", "--name-status"])),
+    "D\tb\n"
+  );
+  assert!(dir.path().join("b").exists());
+
+  Git::builder()
+    .working_dir(dir.path())
+    .read_tree()
+    .prefix("old/")
+    .tree_ish("HEAD~1")
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&git(dir.path(), &["diff", "--cached", "--name-status"])),
+    "D\tb\nA\told/a\n"
+  );
+
+  Git::builder()
+    .working_dir(dir.path())
+    .read_tree()
+    .reset()
+    .update()
+    .tree_ish("HEAD")
+    .execute()
+    .unwrap();
+  assert!(stdout(&git(dir.path(), &["diff", "--cached"])).is_empty());
+
+  // A two way merge from HEAD to HEAD~1 also removes b from the working tree
+  Git::builder()
+    .working_dir(dir.path())
+    .read_tree()
+    .merge()
+    .update()
+    .tree_ish("HEAD")
+    .tree_ish("HEAD~1")
+    .execute()
+    .unwrap();
+  assert_eq!(stdout(&git(dir.path(), &["ls-files"])), "a\n");
+  assert!(!dir.path().join("b").exists());
+}
+
+#[test]
+fn git_read_tree_empty_index_output() {
+  let dir = repo("git_read_tree");
+  commit(dir.path(), "a", "a", "first");
+  let index = dir.path().join(".git").join("other-index");
+
+  Git::builder()
+    .working_dir(dir.path())
+    .read_tree()
+    .index_output(&index)
+    .empty()
+    .execute()
+    .unwrap();
+  assert!(index.exists());
+  assert_eq!(stdout(&git(dir.path(), &["ls-files"])), "a\n");
+
+  assert_eq!(
+    Git::read_tree()
+      .merge()
+      .dry_run()
+      .aggressive()
+      .tree_ish("base")
+      .tree_ish("ours")
+      .tree_ish("theirs")
+      .to_args(),
+    [
+      "read-tree",
+      "-m",
+      "--dry-run",
+      "--aggressive",
+      "base",
+      "ours",
+      "theirs"
+    ]
+  );
+}
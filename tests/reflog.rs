@@ -0,0 +1,91 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_reflog_exists() {
+  let dir = repo("git_reflog");
+  commit(dir.path(), "file", "contents", "first");
+  let out = Git::reflog()
+    .op(ReflogOp::Exists("HEAD".into()))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::reflog()
+    .op(ReflogOp::Exists("refs/heads/does-not-exist".into()))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+}
+
+#[test]
+fn git_reflog_show() {
+  let dir = repo("git_reflog");
+  commit(dir.path(), "file", "1", "first");
+  commit(dir.path(), "file", "2", "second");
+  let out = Git::reflog()
+    .op(ReflogOp::Show {
+      ref_name: None,
+      format: None,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!stdout(&out).is_empty());
+
+  let out = Git::reflog()
+    .op(ReflogOp::Show {
+      ref_name: Some("main".into()),
+      format: Some("%gs".into()),
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "commit: second\ncommit (initial): first\n");
+}
+
+#[test]
+fn git_reflog_delete_expire() {
+  let dir = repo("git_reflog");
+  commit(dir.path(), "file", "1", "first");
+  commit(dir.path(), "file", "2", "second");
+  commit(dir.path(), "file", "3", "third");
+  let out = Git::reflog()
+    .op(ReflogOp::Delete(vec!["main@{1}".into()]))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&git(
+      dir.path(),
+      &["reflog", "show", "--format=%gs", "main"]
+    )),
+    "commit: third\ncommit (initial): first\n"
+  );
+
+  let out = Git::reflog()
+    .op(ReflogOp::Expire {
+      all: true,
+      expire: Some("now".into()),
+      expire_unreachable: Some("now".into()),
+      refs: Vec::new(),
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&git(dir.path(), &["reflog", "show", "main"])), "");
+}
@@ -0,0 +1,202 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_remote_add_list_remove() {
+  let dir = repo("git_remote");
+  let out = Git::remote()
+    .add("origin", "https://example.com/repo.git")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::remote()
+    .verbose()
+    .list()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let entries = GitRemoteEntry::parse_verbose_output(&out.stdout).unwrap();
+  assert_eq!(
+    entries,
+    vec![GitRemoteEntry {
+      name: "origin".into(),
+      fetch_url: "https://example.com/repo.git".into(),
+      push_urls: vec!["https://example.com/repo.git".into()],
+    }]
+  );
+
+  let out = Git::remote()
+    .remove("origin")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let out = Git::remote()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(out.stdout.is_empty());
+}
+
+#[test]
+fn git_remote_rename_set_url() {
+  let dir = repo("git_remote");
+  git(
+    dir.path(),
+    &["remote", "add", "origin", "https://example.com/a.git"],
+  );
+  let out = Git::remote()
+    .rename("origin", "upstream")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let out = Git::remote()
+    .set_url("upstream", "https://example.com/b.git")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let out = Git::remote()
+    .get_url("upstream")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "https://example.com/b.git\n");
+}
+
+#[test]
+fn git_remote_show_prune() {
+  let dir = repo("git_remote");
+  let remote = repo("git_remote");
+  commit(remote.path(), "a", "a", "first");
+  let url = remote.path().to_str().unwrap();
+  git(dir.path(), &["remote", "add", "origin", url]);
+  git(dir.path(), &["fetch", "--quiet", "origin"]);
+
+  let out = Git::remote()
+    .show(Some("origin"))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).contains("* remote origin"));
+
+  let out = Git::remote()
+    .show(None::<String>)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "origin\n");
+
+  let out = Git::remote()
+    .prune("origin")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+}
+
+#[test]
+fn git_remote_parse_verbose_output_error() {
+  assert!(GitRemoteEntry::parse_verbose_output(b"origin https://example.com\n").is_err());
+  assert!(GitRemoteEntry::parse_verbose_output(b"origin\thttps://example.com (pull)\n").is_err());
+  assert!(GitRemoteEntry::parse_verbose_output(
+    b"origin\thttps://example.com/a (fetch)\norigin\thttps://example.com/b (fetch)\n"
+  )
+  .is_err());
+}
+
+#[test]
+fn git_remote_parse_verbose_output_push_urls() {
+  let dir = repo("git_remote");
+  for name in &["origin", "fork"] {
+    let fetch_url = format!("https://example.com/{}.git", name);
+    let push_url = format!("ssh://example.com/{}.git", name);
+    git(dir.path(), &["remote", "add", name, &fetch_url]);
+    git(
+      dir.path(),
+      &["remote", "set-url", "--push", name, &push_url],
+    );
+  }
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .remote()
+    .verbose()
+    .list()
+    .execute()
+    .unwrap();
+  let entries = GitRemoteEntry::parse_verbose_output(&out.stdout).unwrap();
+  assert_eq!(
+    entries,
+    vec![
+      GitRemoteEntry {
+        name: "fork".into(),
+        fetch_url: "https://example.com/fork.git".into(),
+        push_urls: vec!["ssh://example.com/fork.git".into()],
+      },
+      GitRemoteEntry {
+        name: "origin".into(),
+        fetch_url: "https://example.com/origin.git".into(),
+        push_urls: vec!["ssh://example.com/origin.git".into()],
+      },
+    ]
+  );
+}
+
+#[test]
+fn git_remote_parse_verbose_output_multiple_push_urls() {
+  let dir = repo("git_remote");
+  git(
+    dir.path(),
+    &["remote", "add", "origin", "https://example.com/repo.git"],
+  );
+  for url in &[
+    "ssh://one.example.com/repo.git",
+    "ssh://two.example.com/repo.git",
+  ] {
+    git(
+      dir.path(),
+      &["remote", "set-url", "--add", "--push", "origin", url],
+    );
+  }
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .remote()
+    .verbose()
+    .list()
+    .execute()
+    .unwrap();
+  let entries = GitRemoteEntry::parse_verbose_output(&out.stdout).unwrap();
+  assert_eq!(
+    entries,
+    vec![GitRemoteEntry {
+      name: "origin".into(),
+      fetch_url: "https://example.com/repo.git".into(),
+      push_urls: vec![
+        "ssh://one.example.com/repo.git".into(),
+        "ssh://two.example.com/repo.git".into(),
+      ],
+    }]
+  );
+}
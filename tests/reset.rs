@@ -0,0 +1,96 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_reset_soft() {
+  let dir = repo("git_reset");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  let first = rev(dir.path(), "HEAD~1");
+  let out = Git::reset()
+    .soft()
+    .commit("HEAD~1")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(rev(dir.path(), "HEAD"), first);
+  // The index still has the changes from the second commit
+  assert_eq!(
+    stdout(&git(dir.path(), &["diff", "--cached", "--name-only"])),
+    "b\n"
+  );
+  assert!(dir.path().join("b").exists());
+}
+
+#[test]
+fn git_reset_mixed() {
+  let dir = repo("git_reset");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  let out = Git::reset()
+    .mixed()
+    .commit("HEAD~1")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&git(dir.path(), &["diff", "--cached"])).is_empty());
+  assert_eq!(
+    stdout(&git(dir.path(), &["status", "--porcelain"])),
+    "?? b\n"
+  );
+}
+
+#[test]
+fn git_reset_hard() {
+  let dir = repo("git_reset");
+  commit(dir.path(), "a", "a", "first");
+  fs::write(dir.path().join("a"), "staged").unwrap();
+  git(dir.path(), &["add", "a"]);
+  fs::write(dir.path().join("a"), "unstaged").unwrap();
+  let out = Git::reset()
+    .hard()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(fs::read_to_string(dir.path().join("a")).unwrap(), "a");
+  assert!(stdout(&git(dir.path(), &["status", "--porcelain"])).is_empty());
+}
+
+#[test]
+fn git_reset_pathspecs() {
+  let dir = repo("git_reset");
+  commit(dir.path(), "a", "a", "first");
+  fs::write(dir.path().join("a"), "aa").unwrap();
+  fs::write(dir.path().join("b"), "b").unwrap();
+  git(dir.path(), &["add", "a", "b"]);
+  let out = Git::reset()
+    .pathspecs(["b"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&git(dir.path(), &["diff", "--cached", "--name-only"])),
+    "a\n"
+  );
+
+  // Modes can't be used with paths
+  let out = Git::reset()
+    .hard()
+    .pathspecs(["a"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_rev_list_count() {
+  let dir = repo("git_rev_list");
+  commit(dir.path(), "file", "1", "first");
+  git(dir.path(), &["tag", "v1"]);
+  for i in 2..5 {
+    commit(dir.path(), "file", &i.to_string(), &format!("commit {}", i));
+  }
+  git(dir.path(), &["tag", "v2"]);
+  commit(dir.path(), "file", "5", "after v2");
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .rev_list()
+    .count()
+    .commits(["v2", "^v1"])
+    .execute()
+    .unwrap();
+  let log = stdout(&git(dir.path(), &["log", "--oneline", "v1..v2"]));
+  assert_eq!(stdout(&out), format!("{}\n", log.lines().count()));
+  assert_eq!(stdout(&out), "3\n");
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .rev_list()
+    .commits(["v1..v2"])
+    .max_count(2)
+    .skip(1)
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&out),
+    format!("{}\n{}\n", rev(dir.path(), "v2~1"), rev(dir.path(), "v2~2"))
+  );
+}
+
+#[test]
+fn git_rev_list_objects_filter() {
+  let dir = repo("git_rev_list");
+  commit(dir.path(), "file", "contents", "first");
+  std::fs::create_dir(dir.path().join("dir")).unwrap();
+  commit(dir.path(), "dir/other", "other", "second");
+
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .rev_list()
+    .objects()
+    .filter(ObjectFilter::BlobNone)
+    .commits(["HEAD"])
+    .pathspecs(["dir"])
+    .execute()
+    .unwrap();
+  let out = stdout(&out);
+  assert_eq!(out.lines().next().unwrap(), rev(dir.path(), "HEAD"));
+  assert!(!out.contains(&rev(dir.path(), "HEAD:dir/other")));
+  assert!(out.contains(&format!("{} dir\n", rev(dir.path(), "HEAD:dir"))));
+
+  assert_eq!(
+    Git::rev_list()
+      .merges()
+      .no_merges()
+      .ancestry_path()
+      .all()
+      .to_args(),
+    ["rev-list", "--all", "--ancestry-path", "--no-merges"]
+  );
+}
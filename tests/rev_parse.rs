@@ -0,0 +1,132 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn git_rev_parse() {
+  let dir = repo("git_rev_parse");
+  commit(dir.path(), "file", "contents", "first");
+  let out = Git::rev_parse()
+    .revisions(["HEAD"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let hash = stdout(&out);
+  let hash = hash.trim();
+  assert_eq!(hash.len(), 40);
+  assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+
+  let out = Git::rev_parse()
+    .abbrev_ref(true)
+    .revisions(["HEAD"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "main\n");
+
+  let out = Git::rev_parse()
+    .short(Some(8))
+    .revisions(["HEAD"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), format!("{}\n", &hash[..8]));
+}
+
+#[test]
+fn git_rev_parse_verify_symbolic() {
+  let dir = repo("git_rev_parse");
+  commit(dir.path(), "file", "contents", "first");
+  let out = Git::rev_parse()
+    .verify()
+    .revisions(["does-not-exist"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+
+  let out = Git::rev_parse()
+    .symbolic_full_name()
+    .revisions(["HEAD"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "refs/heads/main\n");
+
+  let out = Git::rev_parse()
+    .symbolic()
+    .sq()
+    .revisions(["main", "HEAD"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "'main' 'HEAD' ");
+}
+
+#[test]
+fn git_rev_parse_paths() {
+  let dir = repo("git_rev_parse");
+  let sub = dir.path().join("sub");
+  fs::create_dir(&sub).unwrap();
+  let out = Git::rev_parse()
+    .show_toplevel()
+    .make_cmd()
+    .current_dir(&sub)
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    Path::new(stdout(&out).trim()).canonicalize().unwrap(),
+    dir.path().canonicalize().unwrap()
+  );
+
+  let out = Git::rev_parse()
+    .git_dir()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), ".git\n");
+
+  let out = Git::rev_parse()
+    .absolute_git_dir()
+    .make_cmd()
+    .current_dir(&sub)
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    Path::new(stdout(&out).trim()).canonicalize().unwrap(),
+    dir.path().join(".git").canonicalize().unwrap()
+  );
+}
+
+#[test]
+fn git_rev_parse_checks() {
+  let dir = repo("git_rev_parse");
+  let out = Git::rev_parse()
+    .is_inside_work_tree()
+    .is_inside_git_dir()
+    .is_bare_repository()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "true\nfalse\nfalse\n");
+}
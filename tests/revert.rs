@@ -0,0 +1,117 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_revert_no_commit() {
+  let dir = repo("git_revert");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  let out = Git::revert()
+    .no_commit()
+    .commits(["HEAD"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("b").exists());
+  assert_eq!(
+    stdout(&git(dir.path(), &["diff", "--cached", "--name-status"])),
+    "D\tb\n"
+  );
+
+  git(dir.path(), &["commit", "--quiet", "-m", "revert second"]);
+  assert!(stdout(&git(dir.path(), &["status", "--porcelain"])).is_empty());
+  assert_eq!(
+    stdout(&git(dir.path(), &["log", "--format=%s"])),
+    "revert second\nsecond\nfirst\n"
+  );
+}
+
+#[test]
+fn git_revert_signoff() {
+  let dir = repo("git_revert");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  let out = Git::revert()
+    .signoff()
+    .no_edit()
+    .commits(["HEAD"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let msg = stdout(&git(dir.path(), &["log", "-1", "--format=%B"]));
+  assert!(msg.starts_with("Revert \"second\""));
+  assert!(msg.contains("Signed-off-by: Test User <test@example.com>"));
+}
+
+#[test]
+fn git_revert_mainline() {
+  let dir = repo("git_revert");
+  commit(dir.path(), "a", "a", "first");
+  git(dir.path(), &["checkout", "--quiet", "-b", "feature"]);
+  commit(dir.path(), "b", "b", "second");
+  git(dir.path(), &["checkout", "--quiet", "main"]);
+  git(
+    dir.path(),
+    &["merge", "--quiet", "--no-ff", "--no-edit", "feature"],
+  );
+
+  let out = Git::revert()
+    .no_edit()
+    .commits(["HEAD"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  let out = Git::revert()
+    .quit()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::revert()
+    .mainline(1)
+    .no_edit()
+    .commits(["HEAD"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("b").exists());
+}
+
+#[test]
+fn git_revert_abort() {
+  let dir = repo("git_revert");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "a", "b", "second");
+  commit(dir.path(), "a", "c", "third");
+  let head = rev(dir.path(), "HEAD");
+  // Reverting the second commit conflicts with the third one
+  let out = Git::revert()
+    .no_edit()
+    .commits(["HEAD~1"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  let out = Git::revert()
+    .abort()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(rev(dir.path(), "HEAD"), head);
+  assert!(stdout(&git(dir.path(), &["status", "--porcelain"])).is_empty());
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+fn staged(dir: &std::path::Path) -> String {
+  stdout(&git(dir, &["ls-files", "--cached"]))
+}
+
+#[test]
+fn git_rm_cached() {
+  let dir = repo("git_rm");
+  fs::write(dir.path().join("file"), "file").unwrap();
+  git(dir.path(), &["add", "file"]);
+  let out = Git::rm()
+    .cached()
+    .pathspecs(["file"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(staged(dir.path()).is_empty());
+  assert!(dir.path().join("file").exists());
+}
+
+#[test]
+fn git_rm_dry_run() {
+  let dir = repo("git_rm");
+  commit(dir.path(), "file", "file", "first");
+  let out = Git::rm()
+    .dry_run()
+    .pathspecs(["file"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "rm 'file'\n");
+  assert_eq!(staged(dir.path()), "file\n");
+  assert!(dir.path().join("file").exists());
+}
+
+#[test]
+fn git_rm_recursive_quiet() {
+  let dir = repo("git_rm");
+  commit(dir.path(), "dir/a", "a", "first");
+  commit(dir.path(), "dir/b", "b", "second");
+  let out = Git::rm()
+    .pathspecs(["dir"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+
+  let out = Git::rm()
+    .recursive()
+    .quiet()
+    .pathspecs(["dir"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(out.stdout.is_empty());
+  assert!(staged(dir.path()).is_empty());
+  assert!(!dir.path().join("dir").exists());
+}
+
+#[test]
+fn git_rm_force_ignore_unmatch() {
+  let dir = repo("git_rm");
+  commit(dir.path(), "file", "file", "first");
+  fs::write(dir.path().join("file"), "changed").unwrap();
+  git(dir.path(), &["add", "file"]);
+  let out = Git::rm()
+    .pathspecs(["file"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  let out = Git::rm()
+    .force()
+    .pathspecs(["file"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("file").exists());
+
+  let out = Git::rm()
+    .ignore_unmatch()
+    .pathspecs(["missing"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+use std::path::Path;
+
+/// Makes a commit with the given author
+fn commit_as(dir: &Path, author: &str, file: &str, msg: &str) {
+  fs::write(dir.join(file), msg).unwrap();
+  git(dir, &["add", file]);
+  git(dir, &["commit", "--quiet", "--author", author, "-m", msg]);
+}
+
+/// Creates a repo with two commits from Alice and one from Bob
+fn authors_repo() -> tempdir::TempDir {
+  let dir = repo("git_shortlog");
+  commit_as(dir.path(), "Bob <bob@example.com>", "b", "bob's change");
+  commit_as(dir.path(), "Alice <alice@example.com>", "a1", "first alice");
+  commit_as(
+    dir.path(),
+    "Alice <alice@example.com>",
+    "a2",
+    "second alice",
+  );
+  dir
+}
+
+#[test]
+fn git_shortlog_summary() {
+  let dir = authors_repo();
+  let out = Git::shortlog()
+    .summary()
+    .numbered()
+    .revision_range("HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "     2\tAlice\n     1\tBob\n");
+
+  let out = Git::shortlog()
+    .summary()
+    .email()
+    .revision_range("HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&out),
+    "     2\tAlice <alice@example.com>\n     1\tBob <bob@example.com>\n"
+  );
+}
+
+#[test]
+fn git_shortlog_pathspecs() {
+  let dir = authors_repo();
+  let out = Git::shortlog()
+    .revision_range("HEAD")
+    .pathspecs(["b"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "Bob (1):\n      bob's change\n\n");
+}
+
+#[test]
+fn git_shortlog_group() {
+  let dir = authors_repo();
+  let out = Git::shortlog()
+    .summary()
+    .group(ShortlogGroup::Committer)
+    .revision_range("HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "     3\tTest User\n");
+
+  git(
+    dir.path(),
+    &[
+      "commit",
+      "--quiet",
+      "--allow-empty",
+      "-m",
+      "pair\n\nReviewed-by: Carol",
+    ],
+  );
+  let out = Git::shortlog()
+    .summary()
+    .group(ShortlogGroup::Trailer("Reviewed-by".into()))
+    .revision_range("HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "     1\tCarol\n");
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_show_stat() {
+  let dir = repo("git_show");
+  commit(dir.path(), "file.txt", "hello\n", "add a file");
+  let out = Git::show()
+    .stat()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let show = stdout(&out);
+  assert!(show.contains("add a file"));
+  assert!(show.contains("file.txt | 1 +"));
+}
+
+#[test]
+fn git_show_object() {
+  let dir = repo("git_show");
+  commit(dir.path(), "file.txt", "first\n", "first");
+  commit(dir.path(), "file.txt", "second\n", "second");
+  git(dir.path(), &["tag", "v1", "HEAD~1"]);
+
+  let show = |object: &str| {
+    let out = Git::show()
+      .object(object)
+      .make_cmd()
+      .current_dir(dir.path())
+      .output()
+      .unwrap();
+    assert!(out.status.success());
+    stdout(&out)
+  };
+  assert_eq!(show("HEAD~1:file.txt"), "first\n");
+  assert_eq!(show("main:file.txt"), "second\n");
+  assert_eq!(show("v1:file.txt"), "first\n");
+
+  let out = Git::show()
+    .oneline()
+    .no_patch()
+    .object("HEAD~1")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).ends_with(" first\n"));
+}
+
+#[test]
+fn git_show_format_name_status() {
+  let dir = repo("git_show");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "a", "aa", "second");
+  let out = Git::show()
+    .format("%s")
+    .name_status()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "second\n\nM\ta\n");
+}
+
+#[test]
+fn git_show_pathspecs() {
+  let dir = repo("git_show");
+  git(
+    dir.path(),
+    &["commit", "--quiet", "--allow-empty", "-m", "root"],
+  );
+  std::fs::write(dir.path().join("a"), "a\n").unwrap();
+  std::fs::write(dir.path().join("b"), "b\n").unwrap();
+  git(dir.path(), &["add", "a", "b"]);
+  git(dir.path(), &["commit", "--quiet", "-m", "two files"]);
+  let out = Git::show()
+    .format("")
+    .name_only()
+    .pathspecs(["b"])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "b\n");
+
+  let out = Git::show()
+    .format("")
+    .shortstat()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), " 2 files changed, 2 insertions(+)\n");
+}
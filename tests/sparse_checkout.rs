@@ -0,0 +1,109 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+/// Creates a repo with files in the `a`, `b` and `c` directories plus one at
+/// the top level
+fn dirs_repo() -> tempdir::TempDir {
+  let dir = repo("git_sparse_checkout");
+  commit(dir.path(), "top", "top", "top");
+  for name in ["a", "b", "c"] {
+    commit(dir.path(), &format!("{}/file", name), name, name);
+  }
+  dir
+}
+
+#[test]
+fn git_sparse_checkout_cone() {
+  let dir = dirs_repo();
+  let out = Git::sparse_checkout()
+    .op(SparseOp::Set {
+      patterns: vec!["a".into(), "b".into()],
+      cone: true,
+      stdin: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  // Cone mode always includes the files at the top level
+  assert!(dir.path().join("top").exists());
+  assert!(dir.path().join("a/file").exists());
+  assert!(dir.path().join("b/file").exists());
+  assert!(!dir.path().join("c/file").exists());
+
+  let out = Git::sparse_checkout()
+    .op(SparseOp::List)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "a\nb\n");
+
+  let out = Git::sparse_checkout()
+    .op(SparseOp::Add {
+      patterns: vec!["c".into()],
+      stdin: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(dir.path().join("c/file").exists());
+
+  let out = Git::sparse_checkout()
+    .op(SparseOp::Disable)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  for file in ["top", "a/file", "b/file", "c/file"] {
+    assert!(dir.path().join(file).exists());
+  }
+}
+
+#[test]
+fn git_sparse_checkout_no_cone() {
+  let dir = dirs_repo();
+  let out = Git::sparse_checkout()
+    .op(SparseOp::Init {
+      cone: false,
+      sparse_index: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(dir.path().join("top").exists());
+  assert!(!dir.path().join("a/file").exists());
+
+  let out = Git::sparse_checkout()
+    .op(SparseOp::Set {
+      patterns: vec!["/c/".into()],
+      cone: false,
+      stdin: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("top").exists());
+  assert!(dir.path().join("c/file").exists());
+
+  let out = Git::sparse_checkout()
+    .op(SparseOp::Reapply)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("a/file").exists());
+  assert!(dir.path().join("c/file").exists());
+}
@@ -0,0 +1,234 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+
+#[test]
+fn git_stash_push_pop() {
+  let dir = repo("git_stash");
+  commit(dir.path(), "file", "original", "first");
+  fs::write(dir.path().join("file"), "changed").unwrap();
+
+  let out = Git::stash()
+    .op(StashOp::Push {
+      message: Some("work in progress".into()),
+      include_untracked: false,
+      all: false,
+      pathspec_from_file: None,
+      pathspec_file_nul: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    fs::read_to_string(dir.path().join("file")).unwrap(),
+    "original"
+  );
+  assert!(stdout(&git(dir.path(), &["status", "--porcelain"])).is_empty());
+
+  let out = Git::stash()
+    .op(StashOp::List)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "stash@{0}: On main: work in progress\n");
+
+  let out = Git::stash()
+    .op(StashOp::Pop {
+      stash_ref: None,
+      index_flag: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    fs::read_to_string(dir.path().join("file")).unwrap(),
+    "changed"
+  );
+  assert!(stdout(&git(dir.path(), &["stash", "list"])).is_empty());
+}
+
+#[test]
+fn git_stash_untracked_apply_drop() {
+  let dir = repo("git_stash");
+  commit(dir.path(), "file", "original", "first");
+  fs::write(dir.path().join("new"), "untracked").unwrap();
+
+  let out = Git::stash()
+    .op(StashOp::Push {
+      message: None,
+      include_untracked: true,
+      all: false,
+      pathspec_from_file: None,
+      pathspec_file_nul: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("new").exists());
+
+  let out = Git::stash()
+    .op(StashOp::Apply {
+      stash_ref: Some("stash@{0}".into()),
+      index_flag: true,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(dir.path().join("new").exists());
+
+  let out = Git::stash()
+    .op(StashOp::Drop(None))
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&git(dir.path(), &["stash", "list"])).is_empty());
+}
+
+#[test]
+fn git_stash_show_branch_clear() {
+  let dir = repo("git_stash");
+  commit(dir.path(), "file", "original", "first");
+  fs::write(dir.path().join("file"), "changed").unwrap();
+  git(dir.path(), &["stash", "push", "--quiet"]);
+  fs::write(dir.path().join("file"), "changed again").unwrap();
+  git(dir.path(), &["stash", "push", "--quiet"]);
+
+  let out = Git::stash()
+    .op(StashOp::Show {
+      stash_ref: Some("stash@{1}".into()),
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).contains("file | 2 +-"));
+
+  let out = Git::stash()
+    .op(StashOp::Branch {
+      name: "from-stash".into(),
+      stash_ref: None,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&git(dir.path(), &["branch", "--show-current"])),
+    "from-stash\n"
+  );
+
+  let out = Git::stash()
+    .op(StashOp::Clear)
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&git(dir.path(), &["stash", "list"])).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn git_stash_no_op() {
+  Git::stash().make_cmd();
+}
+
+#[test]
+fn git_stash_push_pathspec_from_file() {
+  let dir = repo("git_stash");
+  commit(dir.path(), "a", "original", "first");
+  commit(dir.path(), "b", "original", "second");
+  commit(dir.path(), "c", "original", "third");
+  for file in &["a", "b", "c"] {
+    fs::write(dir.path().join(file), "changed").unwrap();
+  }
+  let list = dir.path().join(".git").join("pathspecs");
+  fs::write(&list, "a\0c\0").unwrap();
+
+  Git::builder()
+    .working_dir(dir.path())
+    .stash()
+    .op(StashOp::Push {
+      message: None,
+      include_untracked: false,
+      all: false,
+      pathspec_from_file: Some(list),
+      pathspec_file_nul: true,
+    })
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&git(dir.path(), &["status", "--porcelain"])),
+    " M b\n"
+  );
+  assert_eq!(
+    stdout(&git(dir.path(), &["stash", "show", "--name-only"])),
+    "a\nc\n"
+  );
+}
+
+#[test]
+fn git_stash_entries() {
+  let dir = repo("git_stash");
+  commit(dir.path(), "file", "original", "first");
+  for message in &["first change", "second change"] {
+    fs::write(dir.path().join("file"), message).unwrap();
+    git(
+      dir.path(),
+      &["stash", "push", "--quiet", "--message", message],
+    );
+  }
+
+  let entries = Git::builder()
+    .working_dir(dir.path())
+    .stash()
+    .entries()
+    .unwrap();
+  assert_eq!(
+    entries,
+    [
+      GitStashEntry {
+        index: 0,
+        branch: "main".into(),
+        message: "second change".into(),
+        commit_hash: rev(dir.path(), "stash@{0}"),
+      },
+      GitStashEntry {
+        index: 1,
+        branch: "main".into(),
+        message: "first change".into(),
+        commit_hash: rev(dir.path(), "stash@{1}"),
+      },
+    ]
+  );
+}
+
+#[test]
+fn git_stash_parse_stash_list() {
+  let output = b"stash@{0}\0abc\0WIP on main: 1234567 first\nstash@{1}\0def\0autostash\n";
+  let entries = GitStashEntry::parse_stash_list(output).unwrap();
+  assert_eq!(entries[0].branch, "main");
+  assert_eq!(entries[0].message, "1234567 first");
+  assert_eq!(entries[1].index, 1);
+  assert_eq!(entries[1].branch, "");
+  assert_eq!(entries[1].message, "autostash");
+
+  assert!(GitStashEntry::parse_stash_list(b"stash@{0}: On main: message\n").is_err());
+  assert!(GitStashEntry::parse_stash_list(b"stash@{x}\0abc\0On main: message\n").is_err());
+}
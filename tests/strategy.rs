@@ -0,0 +1,47 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn merge_strategy_flag_value() {
+  assert_eq!(MergeStrategy::Ort.to_flag_value(), "ort");
+  assert_eq!(MergeStrategy::Recursive.to_flag_value(), "recursive");
+  assert_eq!(MergeStrategy::Ours.to_flag_value(), "ours");
+  assert_eq!(
+    MergeStrategy::Custom("custom".into()).to_flag_value(),
+    "custom"
+  );
+  assert_eq!(
+    Git::revert()
+      .strategy(MergeStrategy::Resolve)
+      .commits(["HEAD"])
+      .to_args(),
+    ["revert", "--strategy=resolve", "HEAD"]
+  );
+}
+
+#[test]
+fn merge_strategy_option_ours() {
+  let dir = repo("git_strategy");
+  commit(dir.path(), "a", "a", "first");
+  git(dir.path(), &["checkout", "--quiet", "-b", "feature"]);
+  commit(dir.path(), "a", "feature", "change a on feature");
+  git(dir.path(), &["checkout", "--quiet", "main"]);
+  commit(dir.path(), "a", "main", "change a on main");
+
+  Git::builder()
+    .working_dir(dir.path())
+    .cherry_pick()
+    .strategy(MergeStrategy::Ort)
+    .strategy_option("ours")
+    .keep_redundant_commits()
+    .commits(["feature"])
+    .execute()
+    .unwrap();
+  assert_eq!(
+    std::fs::read_to_string(dir.path().join("a")).unwrap(),
+    "main"
+  );
+  assert!(stdout(&git(dir.path(), &["status", "--porcelain"])).is_empty());
+}
@@ -0,0 +1,201 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_submodule_add_update_deinit() {
+  let sub = repo("git_submodule");
+  commit(sub.path(), "lib", "lib", "first");
+  let dir = repo("git_submodule");
+  commit(dir.path(), "a", "a", "first");
+
+  let out = allow_file_protocol(
+    Git::submodule()
+      .op(SubmoduleOp::Add {
+        repository: sub.path().to_str().unwrap().into(),
+        path: Some("vendor/lib".into()),
+        branch: Some("main".into()),
+        depth: None,
+        force: false,
+      })
+      .make_cmd()
+      .current_dir(dir.path()),
+  )
+  .output()
+  .unwrap();
+  assert!(out.status.success());
+  assert!(dir.path().join("vendor/lib/lib").exists());
+  git(dir.path(), &["commit", "--quiet", "-m", "add submodule"]);
+
+  let out = Git::submodule()
+    .op(SubmoduleOp::Status {
+      recursive: true,
+      cached: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(stdout(&out).starts_with(&format!(" {} vendor/lib", rev(sub.path(), "HEAD"))));
+
+  let out = Git::submodule()
+    .op(SubmoduleOp::Deinit {
+      paths: vec!["vendor/lib".into()],
+      all: false,
+      force: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("vendor/lib/lib").exists());
+
+  let out = Git::submodule()
+    .op(SubmoduleOp::Init { paths: vec![] })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let out = allow_file_protocol(
+    Git::submodule()
+      .op(SubmoduleOp::Update {
+        paths: vec![],
+        init: false,
+        remote: false,
+        merge: false,
+        rebase: false,
+        recursive: true,
+        depth: None,
+        force: false,
+      })
+      .make_cmd()
+      .current_dir(dir.path()),
+  )
+  .output()
+  .unwrap();
+  assert!(out.status.success());
+  assert!(dir.path().join("vendor/lib/lib").exists());
+
+  let out = Git::submodule()
+    .op(SubmoduleOp::Deinit {
+      paths: vec![],
+      all: true,
+      force: true,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert!(!dir.path().join("vendor/lib/lib").exists());
+}
+
+#[test]
+fn git_submodule_sync_foreach() {
+  let sub = repo("git_submodule");
+  commit(sub.path(), "lib", "lib", "first");
+  let dir = repo("git_submodule");
+  let url = sub.path().to_str().unwrap();
+  git(
+    dir.path(),
+    &[
+      "-c",
+      "protocol.file.allow=always",
+      "submodule",
+      "add",
+      "--quiet",
+      url,
+      "lib",
+    ],
+  );
+
+  let out = Git::submodule()
+    .op(SubmoduleOp::Sync { recursive: false })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let out = Git::submodule()
+    .op(SubmoduleOp::Foreach {
+      command: "git rev-parse HEAD".into(),
+      recursive: false,
+    })
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    stdout(&out),
+    format!("Entering 'lib'\n{}\n", rev(sub.path(), "HEAD"))
+  );
+}
+
+#[test]
+fn git_submodule_set_branch_update_remote() {
+  let sub = repo("git_submodule");
+  commit(sub.path(), "lib", "lib", "first");
+  git(sub.path(), &["branch", "stable"]);
+  let dir = repo("git_submodule");
+  let url = sub.path().to_str().unwrap();
+  git(
+    dir.path(),
+    &[
+      "-c",
+      "protocol.file.allow=always",
+      "submodule",
+      "add",
+      "--quiet",
+      url,
+      "lib",
+    ],
+  );
+
+  Git::builder()
+    .working_dir(dir.path())
+    .submodule()
+    .op(SubmoduleOp::SetBranch {
+      path: "lib".into(),
+      branch: Some("stable".into()),
+    })
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&git(
+      dir.path(),
+      &["config", "--file", ".gitmodules", "submodule.lib.branch"]
+    )),
+    "stable\n"
+  );
+
+  git(sub.path(), &["checkout", "--quiet", "stable"]);
+  commit(sub.path(), "lib", "new lib", "second");
+  let out = allow_file_protocol(
+    Git::submodule()
+      .op(SubmoduleOp::Update {
+        paths: vec![],
+        init: false,
+        remote: true,
+        merge: false,
+        rebase: false,
+        recursive: false,
+        depth: None,
+        force: false,
+      })
+      .make_cmd()
+      .current_dir(dir.path()),
+  )
+  .output()
+  .unwrap();
+  assert!(out.status.success());
+  assert_eq!(
+    rev(&dir.path().join("lib"), "HEAD"),
+    rev(sub.path(), "stable")
+  );
+}
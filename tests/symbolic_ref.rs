@@ -0,0 +1,80 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_symbolic_ref() {
+  let dir = repo("git_symbolic_ref");
+  commit(dir.path(), "file", "contents", "first");
+  git(dir.path(), &["branch", "other"]);
+
+  let out = Git::symbolic_ref()
+    .ref_name("HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let original = stdout(&out);
+  assert_eq!(original, "refs/heads/main\n");
+
+  let symbolic_ref = Git::builder().working_dir(dir.path()).symbolic_ref();
+  symbolic_ref
+    .clone()
+    .ref_name("HEAD")
+    .target("refs/heads/other")
+    .execute()
+    .unwrap();
+  let out = symbolic_ref
+    .clone()
+    .ref_name("HEAD")
+    .short()
+    .execute()
+    .unwrap();
+  assert_eq!(stdout(&out), "other\n");
+
+  symbolic_ref
+    .ref_name("HEAD")
+    .target(original.trim())
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&git(dir.path(), &["symbolic-ref", "HEAD"])),
+    "refs/heads/main\n"
+  );
+}
+
+#[test]
+fn git_symbolic_ref_quiet() {
+  let dir = repo("git_symbolic_ref");
+  commit(dir.path(), "file", "contents", "first");
+  git(dir.path(), &["checkout", "-q", "--detach"]);
+  let out = Git::symbolic_ref()
+    .ref_name("HEAD")
+    .quiet()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert_eq!(out.status.code(), Some(1));
+  assert!(out.stderr.is_empty());
+}
+
+#[test]
+fn git_symbolic_ref_delete() {
+  let dir = repo("git_symbolic_ref");
+  commit(dir.path(), "file", "contents", "first");
+  git(
+    dir.path(),
+    &["symbolic-ref", "refs/heads/alias", "refs/heads/main"],
+  );
+  Git::builder()
+    .working_dir(dir.path())
+    .symbolic_ref()
+    .ref_name("refs/heads/alias")
+    .delete()
+    .execute()
+    .unwrap();
+  assert!(!dir.path().join(".git/refs/heads/alias").exists());
+}
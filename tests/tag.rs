@@ -0,0 +1,140 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_tag_create_list_delete() {
+  let dir = repo("git_tag");
+  commit(dir.path(), "a", "a", "first");
+  let out = Git::tag()
+    .name("v0.1.0")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let out = Git::tag()
+    .name("v0.2.0")
+    .message("Release v0.2.0")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+
+  let kind = |tag| stdout(&git(dir.path(), &["cat-file", "-t", tag]));
+  assert_eq!(kind("v0.1.0"), "commit\n");
+  assert_eq!(kind("v0.2.0"), "tag\n");
+
+  let out = Git::tag()
+    .list()
+    .sort("-refname")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "v0.2.0\nv0.1.0\n");
+
+  let out = Git::tag()
+    .delete()
+    .name("v0.1.0")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  let out = Git::tag()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "v0.2.0\n");
+}
+
+#[test]
+fn git_tag_commit_ish_force() {
+  let dir = repo("git_tag");
+  commit(dir.path(), "a", "a", "first");
+  commit(dir.path(), "b", "b", "second");
+  let out = Git::tag()
+    .name("v1")
+    .commit_ish("HEAD~1")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(rev(dir.path(), "v1"), rev(dir.path(), "HEAD~1"));
+
+  let out = Git::tag()
+    .name("v1")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+  let out = Git::tag()
+    .name("v1")
+    .force()
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(rev(dir.path(), "v1"), rev(dir.path(), "HEAD"));
+}
+
+#[test]
+fn git_tag_filters() {
+  let dir = repo("git_tag");
+  commit(dir.path(), "a", "a", "first");
+  git(dir.path(), &["tag", "old"]);
+  commit(dir.path(), "b", "b", "second");
+  git(dir.path(), &["tag", "new"]);
+  let out = Git::tag()
+    .contains("HEAD")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "new\n");
+
+  let out = Git::tag()
+    .points_at("HEAD~1")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "old\n");
+
+  let out = Git::tag()
+    .list()
+    .name("n*")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(stdout(&out), "new\n");
+}
+
+#[test]
+fn git_tag_verify() {
+  let dir = repo("git_tag");
+  commit(dir.path(), "a", "a", "first");
+  git(dir.path(), &["tag", "-a", "-m", "unsigned", "v1"]);
+  // The tag isn't signed so verification has to fail
+  let out = Git::tag()
+    .verify()
+    .name("v1")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(!out.status.success());
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn blob(dir: &std::path::Path, contents: &str) -> String {
+  fs::write(dir.join("blob"), contents).unwrap();
+  let hash = stdout(&git(dir, &["hash-object", "-w", "blob"]));
+  hash.trim().into()
+}
+
+fn ref_exists(dir: &std::path::Path, name: &str) -> bool {
+  Command::new("git")
+    .args(["rev-parse", "--verify", "--quiet", name])
+    .current_dir(dir)
+    .status()
+    .unwrap()
+    .success()
+}
+
+#[test]
+fn git_update_ref() {
+  let dir = repo("git_update_ref");
+  let hash = blob(dir.path(), "contents");
+  let out = Git::update_ref()
+    .ref_name("refs/test/blob")
+    .new_value(&hash)
+    .message("point at a blob")
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+  assert_eq!(rev(dir.path(), "refs/test/blob"), hash);
+}
+
+#[test]
+fn git_update_ref_old_value() {
+  let dir = repo("git_update_ref");
+  let first = blob(dir.path(), "first");
+  let second = blob(dir.path(), "second");
+  let update = Git::builder().working_dir(dir.path()).update_ref();
+  update
+    .clone()
+    .ref_name("refs/test/blob")
+    .new_value(&first)
+    .execute()
+    .unwrap();
+
+  // The ref doesn't point at `second`, so this must not change it
+  let result = update
+    .clone()
+    .ref_name("refs/test/blob")
+    .new_value(&first)
+    .old_value(&second)
+    .execute();
+  assert!(result.is_err());
+  update
+    .clone()
+    .ref_name("refs/test/blob")
+    .new_value(&second)
+    .old_value(&first)
+    .execute()
+    .unwrap();
+  assert_eq!(rev(dir.path(), "refs/test/blob"), second);
+
+  update
+    .ref_name("refs/test/blob")
+    .delete()
+    .old_value(&second)
+    .execute()
+    .unwrap();
+  assert!(!ref_exists(dir.path(), "refs/test/blob"));
+}
+
+#[test]
+fn git_update_ref_stdin() {
+  let dir = repo("git_update_ref");
+  let first = blob(dir.path(), "first");
+  let second = blob(dir.path(), "second");
+  let mut child = Git::update_ref()
+    .stdin()
+    .make_cmd()
+    .current_dir(dir.path())
+    .stdin(Stdio::piped())
+    .spawn()
+    .unwrap();
+  write!(
+    child.stdin.take().unwrap(),
+    "create refs/test/first {}\ncreate refs/test/second {}\n",
+    first,
+    second
+  )
+  .unwrap();
+  assert!(child.wait().unwrap().success());
+  assert_eq!(rev(dir.path(), "refs/test/first"), first);
+  assert_eq!(rev(dir.path(), "refs/test/second"), second);
+}
+
+#[test]
+fn git_update_ref_args() {
+  let args = Git::update_ref()
+    .ref_name("HEAD")
+    .new_value("abc")
+    .no_deref()
+    .create_reflog()
+    .to_args();
+  assert_eq!(
+    args,
+    ["update-ref", "--no-deref", "--create-reflog", "HEAD", "abc"]
+  );
+  let args = Git::update_ref()
+    .ref_name("refs/heads/old")
+    .new_value("ignored")
+    .delete()
+    .to_args();
+  assert_eq!(args, ["update-ref", "-d", "refs/heads/old"]);
+}
+
+#[test]
+#[should_panic(expected = "`old_value` needs `new_value` or `delete`")]
+fn git_update_ref_old_value_only() {
+  Git::update_ref()
+    .ref_name("refs/heads/main")
+    .old_value("0000000000000000000000000000000000000000")
+    .make_cmd();
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Pack the objects in the repository and return the path to the `.idx` file
+fn pack(dir: &Path) -> PathBuf {
+  commit(dir, "a", "a", "first");
+  commit(dir, "b", "b", "second");
+  git(dir, &["gc", "--quiet"]);
+  fs::read_dir(dir.join(".git/objects/pack"))
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .find(|path| path.extension().is_some_and(|ext| ext == "idx"))
+    .unwrap()
+}
+
+#[test]
+fn git_verify_pack() {
+  let dir = repo("git_verify_pack");
+  let idx = pack(dir.path());
+  let out = Git::verify_pack()
+    .idx_files([&idx])
+    .make_cmd()
+    .current_dir(dir.path())
+    .output()
+    .unwrap();
+  assert!(out.status.success());
+}
+
+#[test]
+fn git_verify_pack_verbose() {
+  let dir = repo("git_verify_pack");
+  let idx = pack(dir.path());
+  let out = Git::verify_pack()
+    .verbose()
+    .idx_files([&idx])
+    .execute()
+    .unwrap();
+  let listing = stdout(&out);
+  // Two commits, two trees, and two blobs
+  assert_eq!(listing.matches(" commit ").count(), 2);
+  assert_eq!(listing.matches(" blob ").count(), 2);
+  assert!(listing.contains(": ok\n"));
+}
+
+#[test]
+fn git_verify_pack_stat_only() {
+  let dir = repo("git_verify_pack");
+  let idx = pack(dir.path());
+  let out = Git::verify_pack()
+    .stat_only()
+    .idx_files([&idx])
+    .execute()
+    .unwrap();
+  let stats = stdout(&out);
+  assert!(stats.starts_with("non delta: 6 objects\n"));
+  assert!(!stats.contains(" commit "));
+}
+
+#[test]
+fn git_verify_pack_corrupt() {
+  let dir = repo("git_verify_pack");
+  let idx = pack(dir.path());
+  let pack = idx.with_extension("pack");
+  let mut contents = fs::read(&pack).unwrap();
+  let middle = contents.len() / 2;
+  contents[middle] ^= 0xff;
+  fs::remove_file(&pack).unwrap();
+  fs::write(&pack, contents).unwrap();
+  assert!(Git::verify_pack().idx_files([&idx]).execute().is_err());
+}
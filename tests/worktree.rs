@@ -0,0 +1,235 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+use tempdir::TempDir;
+
+fn worktree(dir: &std::path::Path, op: WorktreeOp) -> std::process::Output {
+  Git::worktree()
+    .op(op)
+    .make_cmd()
+    .current_dir(dir)
+    .output()
+    .unwrap()
+}
+
+#[test]
+fn git_worktree_add_list_remove() {
+  let dir = repo("git_worktree");
+  commit(dir.path(), "a", "a", "first");
+  let trees = TempDir::new("git_worktree").unwrap();
+  let path = trees.path().join("feature");
+
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Add {
+      path: path.clone(),
+      branch: Some("main".into()),
+      create_branch: Some("feature".into()),
+      force_create: false,
+      detach: false,
+      checkout: true,
+      lock: false,
+      lock_reason: None,
+    },
+  );
+  assert!(out.status.success());
+  assert!(path.join("a").exists());
+
+  let out = worktree(dir.path(), WorktreeOp::List { porcelain: true });
+  assert!(out.status.success());
+  let list = stdout(&out);
+  assert!(list.contains(&format!("worktree {}\n", path.display())));
+  assert!(list.contains("branch refs/heads/feature\n"));
+
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Remove {
+      path: path.clone(),
+      force: false,
+    },
+  );
+  assert!(out.status.success());
+  assert!(!path.exists());
+  let out = worktree(dir.path(), WorktreeOp::List { porcelain: false });
+  assert!(out.status.success());
+  assert_eq!(stdout(&out).lines().count(), 1);
+}
+
+#[test]
+fn git_worktree_lock_move_prune() {
+  let dir = repo("git_worktree");
+  commit(dir.path(), "a", "a", "first");
+  let trees = TempDir::new("git_worktree").unwrap();
+  let path = trees.path().join("detached");
+  let moved = trees.path().join("moved");
+
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Add {
+      path: path.clone(),
+      branch: Some("HEAD".into()),
+      create_branch: None,
+      force_create: false,
+      detach: true,
+      checkout: false,
+      lock: true,
+      lock_reason: Some("testing".into()),
+    },
+  );
+  assert!(out.status.success());
+  assert!(!path.join("a").exists());
+
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Move {
+      worktree: path.clone(),
+      new_path: moved.clone(),
+    },
+  );
+  assert!(!out.status.success());
+  assert!(worktree(dir.path(), WorktreeOp::Unlock(path.clone()))
+    .status
+    .success());
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Move {
+      worktree: path,
+      new_path: moved.clone(),
+    },
+  );
+  assert!(out.status.success());
+  assert!(moved.exists());
+
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Lock {
+      path: moved.clone(),
+      reason: None,
+    },
+  );
+  assert!(out.status.success());
+  assert!(worktree(dir.path(), WorktreeOp::Unlock(moved.clone()))
+    .status
+    .success());
+
+  std::fs::remove_dir_all(&moved).unwrap();
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Prune {
+      dry_run: true,
+      verbose: true,
+    },
+  );
+  assert!(out.status.success());
+  assert!(String::from_utf8_lossy(&out.stderr).starts_with("Removing worktrees/detached"));
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Prune {
+      dry_run: false,
+      verbose: false,
+    },
+  );
+  assert!(out.status.success());
+  let out = worktree(dir.path(), WorktreeOp::List { porcelain: false });
+  assert_eq!(stdout(&out).lines().count(), 1);
+}
+
+#[test]
+fn git_worktree_parse_porcelain() {
+  let dir = repo("git_worktree");
+  commit(dir.path(), "a", "a", "first");
+  let trees = TempDir::new("git_worktree").unwrap();
+  let path = trees.path().join("locked");
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Add {
+      path: path.clone(),
+      branch: None,
+      create_branch: Some("feature".into()),
+      force_create: false,
+      detach: false,
+      checkout: true,
+      lock: true,
+      lock_reason: Some("on a usb drive".into()),
+    },
+  );
+  assert!(out.status.success());
+
+  let out = worktree(dir.path(), WorktreeOp::List { porcelain: true });
+  assert!(out.status.success());
+  let entries = GitWorktreeEntry::parse_porcelain(&out.stdout).unwrap();
+  assert_eq!(entries.len(), 2);
+  assert_eq!(entries[0].branch.as_deref(), Some("refs/heads/main"));
+  assert!(!entries[0].is_locked);
+  assert_eq!(
+    entries[1],
+    GitWorktreeEntry {
+      worktree: path.canonicalize().unwrap(),
+      head_hash: Some(rev(dir.path(), "HEAD")),
+      branch: Some("refs/heads/feature".into()),
+      is_bare: false,
+      is_detached: false,
+      is_locked: true,
+      lock_reason: Some("on a usb drive".into()),
+      is_prunable: false,
+      prunable_reason: None,
+    }
+  );
+}
+
+#[test]
+fn git_worktree_parse_porcelain_attributes() {
+  let output = b"worktree /repo.git\nbare\n\n\
+    worktree /gone\nHEAD abc\ndetached\nlocked\n\
+    prunable gitdir file points to non-existent location\n\n";
+  let entries = GitWorktreeEntry::parse_porcelain(output).unwrap();
+  assert!(entries[0].is_bare);
+  assert_eq!(entries[0].head_hash, None);
+  assert!(entries[1].is_detached);
+  assert!(entries[1].is_locked);
+  assert_eq!(entries[1].lock_reason, None);
+  assert!(entries[1].is_prunable);
+  assert_eq!(
+    entries[1].prunable_reason.as_deref(),
+    Some("gitdir file points to non-existent location")
+  );
+
+  assert!(GitWorktreeEntry::parse_porcelain(b"HEAD abc\n").is_err());
+}
+
+#[test]
+fn git_worktree_add_lock_reason_without_lock() {
+  let dir = repo("git_worktree");
+  commit(dir.path(), "a", "a", "first");
+  let trees = TempDir::new("git_worktree").unwrap();
+  let path = trees.path().join("locked");
+
+  let out = worktree(
+    dir.path(),
+    WorktreeOp::Add {
+      path: path.clone(),
+      branch: Some("HEAD".into()),
+      create_branch: None,
+      force_create: false,
+      detach: true,
+      checkout: true,
+      lock: false,
+      lock_reason: Some("on a usb drive".into()),
+    },
+  );
+  assert!(
+    out.status.success(),
+    "{}",
+    String::from_utf8_lossy(&out.stderr)
+  );
+  let out = Git::builder()
+    .working_dir(dir.path())
+    .worktree()
+    .op(WorktreeOp::List { porcelain: true })
+    .execute()
+    .unwrap();
+  let entries = GitWorktreeEntry::parse_porcelain(&out.stdout).unwrap();
+  assert!(entries[1].is_locked);
+  assert_eq!(entries[1].lock_reason, Some("on a usb drive".into()));
+}
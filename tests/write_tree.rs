@@ -0,0 +1,68 @@
+mod common;
+
+use common::*;
+use git_cmd::*;
+
+#[test]
+fn git_write_tree() {
+  let dir = repo("git_write_tree");
+  std::fs::create_dir(dir.path().join("dir")).unwrap();
+  std::fs::write(dir.path().join("dir").join("file"), "contents").unwrap();
+  git(dir.path(), &["add", "dir"]);
+
+  let tree = Git::builder()
+    .working_dir(dir.path())
+    .write_tree()
+    .execute()
+    .unwrap();
+  let object_format = stdout(&git(dir.path(), &["rev-parse", "--show-object-format"]));
+  let len = if object_format.trim() == "sha256" {
+    64
+  } else {
+    40
+  };
+  assert_eq!(tree.len(), len);
+  assert!(tree.chars().all(|c| c.is_ascii_hexdigit()));
+
+  let subtree = Git::builder()
+    .working_dir(dir.path())
+    .write_tree()
+    .prefix("dir")
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&git(dir.path(), &["cat-file", "-p", &tree])),
+    format!("040000 tree {}\tdir\n", subtree)
+  );
+  assert!(stdout(&git(dir.path(), &["cat-file", "-p", &subtree])).ends_with("\tfile\n"));
+}
+
+#[test]
+fn git_write_tree_missing_ok() {
+  let dir = repo("git_write_tree");
+  let missing = "0123456789012345678901234567890123456789";
+  git(
+    dir.path(),
+    &[
+      "update-index",
+      "--add",
+      "--cacheinfo",
+      &format!("100644,{},file", missing),
+    ],
+  );
+  assert!(Git::builder()
+    .working_dir(dir.path())
+    .write_tree()
+    .execute()
+    .is_err());
+  let tree = Git::builder()
+    .working_dir(dir.path())
+    .write_tree()
+    .missing_ok()
+    .execute()
+    .unwrap();
+  assert_eq!(
+    stdout(&git(dir.path(), &["ls-tree", &tree])),
+    format!("100644 blob {}\tfile\n", missing)
+  );
+}